@@ -0,0 +1,399 @@
+//! Derive macros for [`pxlfmt`](https://docs.rs/pxlfmt).
+//!
+//! This crate is not meant to be used directly; enable the `derive` feature of `pxlfmt` and use
+//! the re-exported macros from there.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Ident, LitInt, parse_macro_input};
+
+/// Derives a packed RGBA pixel format, along with the raw pixel type that stores it.
+///
+/// The derive is applied to a variant-less `enum`, which must also derive `Copy`, `Clone`,
+/// `Debug`, `Eq`, `PartialEq`, `Ord`, and `PartialOrd`, as every built-in format does. The layout
+/// is described with two attributes:
+///
+/// - `#[pixel(storage = u16)]`, the unsigned integer type (`u8`, `u16`, `u32`, or `u64`) that
+///   holds the whole pixel.
+/// - `#[channel(red, bits = 5, shift = 11)]`, once for each of `red`, `green`, `blue`, and
+///   `alpha`: the channel occupies `bits` bits, starting `shift` bits from the least significant
+///   bit of the storage.
+///
+/// A raw pixel type named after the format, with a `Raw` suffix, is generated with the same
+/// visibility as the format. Its channels are accessed as the storage type; values written to a
+/// channel are truncated to its width. Like the built-in raw pixel types, it implements `Hash`,
+/// `LowerHex`, and `UpperHex`, formatting the storage.
+///
+/// The layout is validated when the macro is expanded: every channel must be present once, be at
+/// least one bit wide, fit within the storage, and not overlap any other channel.
+///
+/// ## Example
+///
+/// ```rust
+/// use pxlfmt::{PixelFormat, pixel::{Format, Pixel, raw::{ChannelDescriptor, RawPixel}}};
+///
+/// /// A 16-bit pixel format with a 1-bit alpha channel and three 5-bit color channels.
+/// #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, PixelFormat)]
+/// #[pixel(storage = u16)]
+/// #[channel(alpha, bits = 1, shift = 15)]
+/// #[channel(red, bits = 5, shift = 10)]
+/// #[channel(green, bits = 5, shift = 5)]
+/// #[channel(blue, bits = 5, shift = 0)]
+/// pub enum Argb1555 {}
+///
+/// let pixel = Pixel::<Argb1555>::with_rgba(0x1F, 0x00, 0x01, 1);
+/// assert_eq!(pixel.as_raw().into_inner(), 0xFC01);
+/// assert_eq!(pixel.red(), 0x1F);
+/// assert_eq!(format!("{pixel:x}"), "fc01");
+/// assert!(std::collections::HashSet::from([pixel]).contains(&pixel));
+///
+/// // The layout can be inspected at runtime; channels are in red, green, blue, alpha order.
+/// assert_eq!((Argb1555::NAME, Argb1555::BITS_PER_PIXEL), ("Argb1555", 16));
+/// assert_eq!(Argb1555::channel_descriptor(3), ChannelDescriptor { shift: 15, bits: 1 });
+/// ```
+///
+/// Overlapping channels fail to compile:
+///
+/// ```rust,compile_fail
+/// use pxlfmt::PixelFormat;
+///
+/// #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, PixelFormat)]
+/// #[pixel(storage = u8)]
+/// #[channel(red, bits = 2, shift = 0)]
+/// #[channel(green, bits = 2, shift = 1)]
+/// #[channel(blue, bits = 2, shift = 4)]
+/// #[channel(alpha, bits = 2, shift = 6)]
+/// pub enum Broken {}
+/// ```
+#[proc_macro_derive(PixelFormat, attributes(pixel, channel))]
+pub fn derive_pixel_format(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The names of the channels, in the order of their offsets in the generated raw pixel.
+const CHANNEL_NAMES: [&str; 4] = ["red", "green", "blue", "alpha"];
+
+/// The storage types that can be used, and their width in bits.
+const STORAGE_TYPES: [(&str, u32); 4] = [("u8", 8), ("u16", 16), ("u32", 32), ("u64", 64)];
+
+/// The position of a single channel within the storage.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Channel {
+    bits: u32,
+    shift: u32,
+}
+
+impl Channel {
+    /// Returns the mask of the channel, before shifting.
+    fn mask(self) -> u64 {
+        u64::MAX >> (64 - self.bits)
+    }
+}
+
+/// Checks that the channels (in the order of [`CHANNEL_NAMES`]) fit in `storage_bits` bits.
+fn validate(storage_bits: u32, channels: &[Channel; 4]) -> Result<(), String> {
+    for (name, channel) in CHANNEL_NAMES.iter().zip(channels) {
+        if channel.bits == 0 {
+            return Err(format!("channel `{name}` must be at least 1 bit wide"));
+        }
+        if channel.bits.saturating_add(channel.shift) > storage_bits {
+            return Err(format!(
+                "channel `{name}` does not fit in {storage_bits} bits of storage"
+            ));
+        }
+    }
+    for (i, a) in channels.iter().enumerate() {
+        for (j, b) in channels.iter().enumerate().skip(i + 1) {
+            if a.shift < b.shift + b.bits && b.shift < a.shift + a.bits {
+                return Err(format!(
+                    "channels `{}` and `{}` overlap",
+                    CHANNEL_NAMES[i], CHANNEL_NAMES[j]
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parses the `#[pixel(..)]` and `#[channel(..)]` attributes of the input.
+fn parse_layout(input: &DeriveInput) -> syn::Result<(Ident, [Channel; 4])> {
+    let mut storage = None;
+    let mut channels = [None; 4];
+
+    for attr in &input.attrs {
+        if attr.path().is_ident("pixel") {
+            attr.parse_nested_meta(|meta| {
+                if !meta.path.is_ident("storage") {
+                    return Err(meta.error("expected `storage`"));
+                }
+                let ty: Ident = meta.value()?.parse()?;
+                let Some(&(_, bits)) = STORAGE_TYPES.iter().find(|(name, _)| ty == name) else {
+                    return Err(Error::new(
+                        ty.span(),
+                        "expected `u8`, `u16`, `u32`, or `u64`",
+                    ));
+                };
+                storage = Some((ty, bits));
+                Ok(())
+            })?;
+        } else if attr.path().is_ident("channel") {
+            let mut index = None;
+            let mut bits = None;
+            let mut shift = None;
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("bits") {
+                    bits = Some(meta.value()?.parse::<LitInt>()?.base10_parse::<u32>()?);
+                } else if meta.path.is_ident("shift") {
+                    shift = Some(meta.value()?.parse::<LitInt>()?.base10_parse::<u32>()?);
+                } else if let Some(i) = CHANNEL_NAMES.iter().position(|n| meta.path.is_ident(n)) {
+                    index = Some(i);
+                } else {
+                    return Err(
+                        meta.error("expected `red`, `green`, `blue`, `alpha`, `bits`, or `shift`")
+                    );
+                }
+                Ok(())
+            })?;
+            let (Some(index), Some(bits), Some(shift)) = (index, bits, shift) else {
+                return Err(Error::new_spanned(
+                    attr,
+                    "expected a channel name, `bits`, and `shift`",
+                ));
+            };
+            if channels[index].replace(Channel { bits, shift }).is_some() {
+                return Err(Error::new_spanned(
+                    attr,
+                    format!("duplicate `{}` channel", CHANNEL_NAMES[index]),
+                ));
+            }
+        }
+    }
+
+    let Some((storage, storage_bits)) = storage else {
+        return Err(Error::new(
+            Span::call_site(),
+            "missing `#[pixel(storage = ..)]` attribute",
+        ));
+    };
+    let mut resolved = [Channel { bits: 0, shift: 0 }; 4];
+    for (i, channel) in channels.into_iter().enumerate() {
+        let Some(channel) = channel else {
+            return Err(Error::new(
+                Span::call_site(),
+                format!("missing `#[channel({}, ..)]` attribute", CHANNEL_NAMES[i]),
+            ));
+        };
+        resolved[i] = channel;
+    }
+    validate(storage_bits, &resolved).map_err(|message| Error::new(Span::call_site(), message))?;
+    Ok((storage, resolved))
+}
+
+/// Implements `UpperHex` and `LowerHex` for the raw pixel type, formatting its storage.
+fn expand_hex(raw: &Ident) -> TokenStream2 {
+    quote! {
+        #[automatically_derived]
+        impl ::core::fmt::UpperHex for #raw {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::UpperHex::fmt(&self.0, f)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::fmt::LowerHex for #raw {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    match &input.data {
+        Data::Enum(data) if data.variants.is_empty() && input.generics.params.is_empty() => {}
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`PixelFormat` can only be derived for an enum without variants or generics",
+            ));
+        }
+    }
+
+    let (storage, channels) = parse_layout(input)?;
+    let vis = &input.vis;
+    let name = &input.ident;
+    let raw = format_ident!("{}Raw", name);
+    let raw_doc = format!("The raw pixel value of [`{name}`], generated by `PixelFormat`.");
+    let shifts = channels.iter().map(|c| c.shift);
+    let masks = channels.iter().map(|c| Literal::u64_unsuffixed(c.mask()));
+    let hex = expand_hex(&raw);
+
+    Ok(quote! {
+        #[doc = #raw_doc]
+        #[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+        #[repr(transparent)]
+        #vis struct #raw(#storage);
+
+        #[automatically_derived]
+        impl #raw {
+            const SHIFTS: [u32; 4] = [#(#shifts),*];
+            const MASKS: [#storage; 4] = [#(#masks),*];
+        }
+
+        #hex
+
+        #[automatically_derived]
+        impl ::core::convert::From<#storage> for #raw {
+            fn from(value: #storage) -> Self {
+                Self(value)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::pxlfmt::pixel::raw::RawPixel for #raw {
+            const DEFAULT: Self = Self(0);
+            const CHANNELS: usize = 4;
+            type Storage = #storage;
+            type Channel = #storage;
+            type Bytes = [u8; ::core::mem::size_of::<#storage>()];
+
+            unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
+                (self.0 >> Self::SHIFTS[offset]) & Self::MASKS[offset]
+            }
+
+            unsafe fn set_channel_unchecked(
+                &mut self,
+                offset: usize,
+                value: Self::Channel,
+            ) -> &mut Self {
+                let shift = Self::SHIFTS[offset];
+                let mask = Self::MASKS[offset];
+                self.0 = (self.0 & !(mask << shift)) | ((value & mask) << shift);
+                self
+            }
+
+            fn channel_descriptor(offset: usize) -> ::pxlfmt::pixel::raw::ChannelDescriptor {
+                ::pxlfmt::pixel::raw::ChannelDescriptor {
+                    shift: Self::SHIFTS[offset],
+                    bits: Self::MASKS[offset].count_ones(),
+                }
+            }
+
+            fn as_inner(&self) -> &Self::Storage {
+                &self.0
+            }
+
+            fn into_inner(self) -> Self::Storage {
+                self.0
+            }
+
+            fn to_le_bytes(self) -> Self::Bytes {
+                self.0.to_le_bytes()
+            }
+
+            fn to_be_bytes(self) -> Self::Bytes {
+                self.0.to_be_bytes()
+            }
+
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                Self(#storage::from_le_bytes(bytes))
+            }
+
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                Self(#storage::from_be_bytes(bytes))
+            }
+        }
+
+        #[automatically_derived]
+        impl ::pxlfmt::__private::Sealed for #name {}
+
+        #[automatically_derived]
+        impl ::pxlfmt::pixel::Format for #name {
+            type RawPixel = #raw;
+            type Channels = ::pxlfmt::formats::rgba::Rgba;
+            const NAME: &'static str = ::core::stringify!(#name);
+        }
+
+        #[automatically_derived]
+        impl ::pxlfmt::formats::rgba::RgbaFormat for #name {
+            const RED_OFFSET: usize = 0;
+            const GREEN_OFFSET: usize = 1;
+            const BLUE_OFFSET: usize = 2;
+        }
+
+        #[automatically_derived]
+        impl ::pxlfmt::alpha::AlphaFormat for #name {
+            const ALPHA_OFFSET: usize = 3;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channels(layout: [(u32, u32); 4]) -> [Channel; 4] {
+        layout.map(|(bits, shift)| Channel { bits, shift })
+    }
+
+    #[test]
+    fn validate_ok() {
+        let rgb565a = channels([(5, 11), (6, 5), (5, 0), (16, 16)]);
+        assert_eq!(validate(32, &rgb565a), Ok(()));
+
+        let full = channels([(16, 0), (16, 16), (16, 32), (16, 48)]);
+        assert_eq!(validate(64, &full), Ok(()));
+        assert_eq!(full[3].mask(), 0xFFFF);
+    }
+
+    #[test]
+    fn validate_errors() {
+        let empty = channels([(0, 0), (1, 1), (1, 2), (1, 3)]);
+        assert_eq!(
+            validate(8, &empty),
+            Err("channel `red` must be at least 1 bit wide".into())
+        );
+
+        let too_wide = channels([(2, 0), (2, 2), (2, 4), (2, 7)]);
+        assert_eq!(
+            validate(8, &too_wide),
+            Err("channel `alpha` does not fit in 8 bits of storage".into())
+        );
+
+        let overlap = channels([(2, 0), (2, 2), (2, 3), (2, 6)]);
+        assert_eq!(
+            validate(8, &overlap),
+            Err("channels `green` and `blue` overlap".into())
+        );
+    }
+
+    #[test]
+    fn expand_rejects_variants() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[pixel(storage = u8)]
+            enum Format { A }
+        };
+        let error = expand(&input).unwrap_err();
+        assert!(error.to_string().contains("enum without variants"));
+    }
+
+    #[test]
+    fn expand_rejects_missing_channel() {
+        let input: DeriveInput = syn::parse_quote! {
+            #[pixel(storage = u8)]
+            #[channel(red, bits = 2, shift = 0)]
+            #[channel(green, bits = 2, shift = 2)]
+            #[channel(blue, bits = 2, shift = 4)]
+            enum Format {}
+        };
+        let error = expand(&input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "missing `#[channel(alpha, ..)]` attribute"
+        );
+    }
+}
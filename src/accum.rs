@@ -0,0 +1,280 @@
+//! Wide accumulators for combining many pixels into one.
+//!
+//! Filters such as blurs, resizes, and averages sum many weighted pixels before dividing by the
+//! total weight. Doing that in the pixel's own channel type overflows (for integers) or loses
+//! precision (for floats), so the accumulators in this module use wider types for the running sums.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pxlfmt::{accum::U32x4Accum, formats::rgba::Rgba8888, pixel::Pixel};
+//!
+//! let black = Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0x00, 0xFF);
+//! let white = Pixel::<Rgba8888>::with_rgba(0xFF, 0xFF, 0xFF, 0xFF);
+//!
+//! let mut accum = U32x4Accum::new();
+//! accum.add_weighted(black, 1).add_weighted(white, 3);
+//!
+//! let average: Pixel<Rgba8888> = accum.resolve();
+//! assert_eq!(average.red(), 0xBF);
+//! ```
+
+use crate::{
+    formats::rgba::RgbaFormat,
+    pixel::{Pixel, raw::RawPixel},
+};
+
+/// An accumulator of 8-bit RGBA pixels with integer weights.
+///
+/// Channels are summed in `R, G, B, A` order as `u32`, so the total weight may be at most
+/// [`U32x4Accum::MAX_WEIGHT`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct U32x4Accum {
+    sums: [u32; 4],
+    weight: u32,
+}
+
+impl U32x4Accum {
+    /// The maximum total weight that can be accumulated without overflowing.
+    pub const MAX_WEIGHT: u32 = u32::MAX / 0xFF;
+
+    /// Creates a new, empty accumulator.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            sums: [0; 4],
+            weight: 0,
+        }
+    }
+
+    /// Adds a pixel to the accumulator with the given weight.
+    ///
+    /// ## Panics
+    ///
+    /// If the total weight would exceed [`U32x4Accum::MAX_WEIGHT`].
+    pub fn add_weighted<F>(&mut self, pixel: Pixel<F>, weight: u32) -> &mut Self
+    where
+        F: RgbaFormat,
+        F::RawPixel: RawPixel<Channel = u8>,
+    {
+        self.weight = self
+            .weight
+            .checked_add(weight)
+            .filter(|&total| total <= Self::MAX_WEIGHT)
+            .expect("Total weight overflow");
+        let channels = [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()];
+        for (sum, channel) in self.sums.iter_mut().zip(channels) {
+            *sum += u32::from(channel) * weight;
+        }
+        self
+    }
+
+    /// Returns the total weight accumulated so far.
+    #[must_use]
+    pub const fn weight(&self) -> u32 {
+        self.weight
+    }
+
+    /// Returns the weighted average of the accumulated pixels, rounded to the nearest value.
+    ///
+    /// If nothing (or only zero weights) were accumulated, returns [`Pixel::zeroed`].
+    #[must_use]
+    pub fn resolve<F>(&self) -> Pixel<F>
+    where
+        F: RgbaFormat,
+        F::RawPixel: RawPixel<Channel = u8>,
+    {
+        if self.weight == 0 {
+            return Pixel::zeroed();
+        }
+        let weight = u64::from(self.weight);
+        let [r, g, b, a] = self.sums.map(|sum| {
+            let average = (u64::from(sum) * 2 + weight) / (weight * 2);
+            u8::try_from(average).unwrap_or(u8::MAX)
+        });
+        Pixel::with_rgba(r, g, b, a)
+    }
+
+    /// Resets the accumulator to its empty state.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<F> Extend<(Pixel<F>, u32)> for U32x4Accum
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = u8>,
+{
+    fn extend<I: IntoIterator<Item = (Pixel<F>, u32)>>(&mut self, iter: I) {
+        for (pixel, weight) in iter {
+            self.add_weighted(pixel, weight);
+        }
+    }
+}
+
+impl<F> FromIterator<(Pixel<F>, u32)> for U32x4Accum
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = u8>,
+{
+    fn from_iter<I: IntoIterator<Item = (Pixel<F>, u32)>>(iter: I) -> Self {
+        let mut accum = Self::new();
+        accum.extend(iter);
+        accum
+    }
+}
+
+/// An accumulator of 32-bit floating point RGBA pixels with floating point weights.
+///
+/// Channels are summed in `R, G, B, A` order as `f64`, which keeps the average of millions of
+/// pixels precise, and allows negative weights (e.g. for sharpening kernels).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct F64x4Accum {
+    sums: [f64; 4],
+    weight: f64,
+}
+
+impl F64x4Accum {
+    /// Creates a new, empty accumulator.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            sums: [0.0; 4],
+            weight: 0.0,
+        }
+    }
+
+    /// Adds a pixel to the accumulator with the given weight.
+    pub fn add_weighted<F>(&mut self, pixel: Pixel<F>, weight: f64) -> &mut Self
+    where
+        F: RgbaFormat,
+        F::RawPixel: RawPixel<Channel = f32>,
+    {
+        self.weight += weight;
+        let channels = [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()];
+        for (sum, channel) in self.sums.iter_mut().zip(channels) {
+            *sum += f64::from(channel) * weight;
+        }
+        self
+    }
+
+    /// Returns the total weight accumulated so far.
+    #[must_use]
+    pub const fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Returns the weighted average of the accumulated pixels.
+    ///
+    /// If the total weight is zero, returns [`Pixel::zeroed`].
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn resolve<F>(&self) -> Pixel<F>
+    where
+        F: RgbaFormat,
+        F::RawPixel: RawPixel<Channel = f32>,
+    {
+        if self.weight == 0.0 {
+            return Pixel::zeroed();
+        }
+        let [r, g, b, a] = self.sums.map(|sum| (sum / self.weight) as f32);
+        Pixel::with_rgba(r, g, b, a)
+    }
+
+    /// Resets the accumulator to its empty state.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl<F> Extend<(Pixel<F>, f64)> for F64x4Accum
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = f32>,
+{
+    fn extend<I: IntoIterator<Item = (Pixel<F>, f64)>>(&mut self, iter: I) {
+        for (pixel, weight) in iter {
+            self.add_weighted(pixel, weight);
+        }
+    }
+}
+
+impl<F> FromIterator<(Pixel<F>, f64)> for F64x4Accum
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = f32>,
+{
+    fn from_iter<I: IntoIterator<Item = (Pixel<F>, f64)>>(iter: I) -> Self {
+        let mut accum = Self::new();
+        accum.extend(iter);
+        accum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{Abgr8888, FloatRgba, Rgba8888};
+
+    use super::*;
+
+    #[test]
+    fn u32x4_average_rounds_to_nearest() {
+        let accum: U32x4Accum = [
+            (Pixel::<Rgba8888>::with_rgba(0x00, 0x01, 0x02, 0xFF), 1),
+            (Pixel::<Rgba8888>::with_rgba(0x01, 0x02, 0x02, 0xFF), 1),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(accum.weight(), 2);
+
+        let average = accum.resolve::<Abgr8888>();
+        assert_eq!(average, Pixel::with_rgba(0x01, 0x02, 0x02, 0xFF));
+    }
+
+    #[test]
+    fn u32x4_empty_resolves_to_zeroed() {
+        let mut accum = U32x4Accum::new();
+        accum.add_weighted(Pixel::<Rgba8888>::with_rgba(0xFF, 0xFF, 0xFF, 0xFF), 0);
+        assert_eq!(accum.resolve::<Rgba8888>(), Pixel::zeroed());
+    }
+
+    #[test]
+    fn u32x4_max_weight() {
+        let mut accum = U32x4Accum::new();
+        let white = Pixel::<Rgba8888>::with_rgba(0xFF, 0xFF, 0xFF, 0xFF);
+        accum.add_weighted(white, U32x4Accum::MAX_WEIGHT);
+        assert_eq!(accum.resolve::<Rgba8888>(), white);
+
+        accum.clear();
+        assert_eq!(accum, U32x4Accum::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "Total weight overflow")]
+    fn u32x4_overflow() {
+        let mut accum = U32x4Accum::new();
+        let pixel = Pixel::<Rgba8888>::zeroed();
+        accum.add_weighted(pixel, U32x4Accum::MAX_WEIGHT);
+        accum.add_weighted(pixel, 1);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn f64x4_weighted_average() {
+        let mut accum = F64x4Accum::new();
+        accum
+            .add_weighted(Pixel::<FloatRgba>::with_rgba(0.0, 0.0, 1.0, 1.0), 1.0)
+            .add_weighted(Pixel::<FloatRgba>::with_rgba(1.0, 0.5, 0.0, 1.0), 3.0);
+        assert_eq!(accum.weight(), 4.0);
+
+        let average = accum.resolve::<FloatRgba>();
+        assert_eq!(average.as_raw().into_inner(), [0.75, 0.375, 0.25, 1.0]);
+    }
+
+    #[test]
+    fn f64x4_empty_resolves_to_zeroed() {
+        let accum = F64x4Accum::new();
+        assert_eq!(accum.resolve::<FloatRgba>(), Pixel::zeroed());
+    }
+}
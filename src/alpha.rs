@@ -0,0 +1,255 @@
+//! Alpha channel utilities.
+//!
+//! ## Premultiplied alpha
+//!
+//! Pixels normally store _straight_ alpha, where the color channels are independent of alpha.
+//! Compositing (see [`blend`](crate::blend)) instead expects _premultiplied_ alpha, where the color
+//! channels have already been scaled by alpha. [`Pixel::premultiply`] and
+//! [`Pixel::unpremultiply`] convert between the two:
+//!
+//! ```rust
+//! use pxlfmt::{formats::rgba::Rgba8888, pixel::Pixel};
+//!
+//! let straight = Pixel::<Rgba8888>::with_rgba(0xFF, 0x80, 0x00, 0x80);
+//! let premultiplied = straight.premultiply();
+//! assert_eq!(premultiplied, Pixel::with_rgba(0x80, 0x40, 0x00, 0x80));
+//! assert_eq!(premultiplied.unpremultiply(), Pixel::with_rgba(0xFF, 0x80, 0x00, 0x80));
+//! ```
+
+use crate::{
+    dither::BAYER_4X4,
+    formats::rgba::RgbaFormat,
+    norm::Normalized,
+    pixel::{Format, Pixel, raw::RawPixel},
+};
+
+/// A pixel format with an alpha channel.
+///
+/// Shared by every format family with an alpha channel (such as [`RgbaFormat`][]), so that alpha
+/// can be accessed the same way regardless of how the color is represented.
+///
+/// [`RgbaFormat`]: crate::formats::rgba::RgbaFormat
+pub trait AlphaFormat: Format {
+    /// The offset of the alpha channel in the pixel's raw representation.
+    const ALPHA_OFFSET: usize;
+
+    /// Returns the alpha channel value of a pixel.
+    fn get_alpha(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::ALPHA_OFFSET)
+    }
+
+    /// Sets the alpha channel value of a pixel.
+    fn set_alpha(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::ALPHA_OFFSET, value);
+    }
+}
+
+impl<F: AlphaFormat> Pixel<F> {
+    /// Returns the alpha channel value of the pixel.
+    pub fn alpha(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_alpha(self.as_raw())
+    }
+
+    /// Sets the alpha channel value of the pixel.
+    pub fn set_alpha(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_alpha(self.as_raw_mut(), value);
+        self
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    /// Returns the pixel with its color channels multiplied by its alpha channel.
+    ///
+    /// Integer channels are rounded to the nearest value; for 8-bit channels, each color channel
+    /// becomes `round(c * a / 255)`. The alpha channel is unchanged.
+    #[must_use]
+    pub fn premultiply(&self) -> Self {
+        let alpha = self.alpha().to_f32();
+        let scale =
+            |c: <F::RawPixel as RawPixel>::Channel| Normalized::from_f32(c.to_f32() * alpha);
+        Pixel::with_rgba(
+            scale(self.red()),
+            scale(self.green()),
+            scale(self.blue()),
+            self.alpha(),
+        )
+    }
+
+    /// Returns the pixel with its color channels divided by its alpha channel.
+    ///
+    /// This is the inverse of [`premultiply`](Pixel::premultiply). Integer channels are rounded to
+    /// the nearest value and clamped to their range; for 8-bit channels, each color channel becomes
+    /// `min(round(c * 255 / a), 255)`. Since premultiplying discards precision, the round trip is
+    /// only exact for opaque pixels.
+    ///
+    /// A fully transparent pixel has no color to recover, so its color channels become zero.
+    #[must_use]
+    pub fn unpremultiply(&self) -> Self {
+        let alpha = self.alpha().to_f32();
+        if alpha <= 0.0 {
+            let zero = Normalized::from_f32(0.0);
+            return Pixel::with_rgba(zero, zero, zero, self.alpha());
+        }
+        let scale =
+            |c: <F::RawPixel as RawPixel>::Channel| Normalized::from_f32(c.to_f32() / alpha);
+        Pixel::with_rgba(
+            scale(self.red()),
+            scale(self.green()),
+            scale(self.blue()),
+            self.alpha(),
+        )
+    }
+}
+
+/// Converts an alpha value to a multisample coverage mask (alpha-to-coverage).
+///
+/// The returned mask has one bit per sample, with `alpha / 255` of the `sample_count` samples
+/// covered on average. The fractional remainder is resolved with an ordered dither keyed on the
+/// pixel's screen position (`x`, `y`), and which samples are covered is rotated by a hash of the
+/// position, so that neighboring pixels with the same alpha do not cover the same samples.
+///
+/// This allows order-independent "cutout" transparency without sorting or blending: each sample is
+/// either fully covered or not, and resolving the samples produces the intended opacity.
+///
+/// A fully opaque alpha (`255`) always covers every sample, and a fully transparent alpha (`0`)
+/// never covers any.
+///
+/// ## Panics
+///
+/// If `sample_count` is `0` or greater than `8`.
+///
+/// ## Example
+///
+/// ```rust
+/// use pxlfmt::alpha::to_coverage;
+///
+/// assert_eq!(to_coverage(0xFF, 4, 0, 0), 0b1111);
+/// assert_eq!(to_coverage(0x00, 4, 0, 0), 0b0000);
+/// assert_eq!(to_coverage(0x80, 4, 0, 0).count_ones(), 2);
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+pub fn to_coverage(alpha: u8, sample_count: u32, x: u32, y: u32) -> u8 {
+    assert!(
+        (1..=8).contains(&sample_count),
+        "Sample count must be between 1 and 8"
+    );
+
+    // Number of covered samples: floor(alpha * samples / 255 + (threshold + 0.5) / 16).
+    let threshold = u32::from(BAYER_4X4[(y & 3) as usize][(x & 3) as usize]);
+    let covered = (u32::from(alpha) * sample_count * 32 + (2 * threshold + 1) * 255) / (255 * 32);
+
+    let all = (1u16 << sample_count) - 1;
+    let bits = (1u16 << covered) - 1;
+    let rotation = position_hash(x, y) % sample_count;
+    let rotated = (bits << rotation) | (bits >> (sample_count - rotation));
+    (rotated & all) as u8
+}
+
+/// Hashes a screen position into a well-mixed 32-bit value.
+const fn position_hash(x: u32, y: u32) -> u32 {
+    let mut hash = x.wrapping_mul(0x9E37_79B1) ^ y.wrapping_mul(0x85EB_CA77);
+    hash ^= hash >> 15;
+    hash = hash.wrapping_mul(0x2C1B_3C6D);
+    hash ^ (hash >> 13)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{FloatRgba, Rgba8888};
+
+    use super::*;
+
+    #[test]
+    fn premultiply_rounds_to_nearest() {
+        let pixel = Pixel::<Rgba8888>::with_rgba(0xFF, 0x01, 0x02, 0x80);
+        assert_eq!(
+            pixel.premultiply(),
+            Pixel::with_rgba(0x80, 0x01, 0x01, 0x80)
+        );
+
+        let opaque = Pixel::<Rgba8888>::with_rgba(0x12, 0x34, 0x56, 0xFF);
+        assert_eq!(opaque.premultiply(), opaque);
+        assert_eq!(opaque.unpremultiply(), opaque);
+    }
+
+    #[test]
+    fn unpremultiply_clamps_and_handles_zero_alpha() {
+        let invalid = Pixel::<Rgba8888>::with_rgba(0x80, 0x40, 0x00, 0x40);
+        assert_eq!(
+            invalid.unpremultiply(),
+            Pixel::with_rgba(0xFF, 0xFF, 0x00, 0x40)
+        );
+
+        let transparent = Pixel::<Rgba8888>::with_rgba(0x80, 0x40, 0x20, 0x00);
+        assert_eq!(transparent.unpremultiply(), Pixel::zeroed());
+
+        let float = Pixel::<FloatRgba>::with_rgba(0.5, 0.25, 1.0, 0.0);
+        assert_eq!(float.unpremultiply(), Pixel::zeroed());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn float_round_trip() {
+        let pixel = Pixel::<FloatRgba>::with_rgba(1.0, 0.5, 0.25, 0.5);
+        let premultiplied = pixel.premultiply();
+        assert_eq!(premultiplied.as_raw().into_inner(), [0.5, 0.25, 0.125, 0.5]);
+        assert_eq!(premultiplied.unpremultiply(), pixel);
+    }
+
+    #[test]
+    fn opaque_covers_all_samples() {
+        for samples in 1..=8 {
+            for (x, y) in [(0, 0), (1, 2), (3, 3), (1000, 17)] {
+                assert_eq!(
+                    u32::from(to_coverage(0xFF, samples, x, y)),
+                    (1 << samples) - 1
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn transparent_covers_no_samples() {
+        for samples in 1..=8 {
+            for (x, y) in [(0, 0), (1, 2), (3, 3), (1000, 17)] {
+                assert_eq!(to_coverage(0x00, samples, x, y), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn average_coverage_matches_alpha() {
+        for samples in [1, 2, 4, 8] {
+            for alpha in [0x20, 0x40, 0x80, 0xC0] {
+                let mut total = 0;
+                for y in 0..4 {
+                    for x in 0..4 {
+                        let mask = to_coverage(alpha, samples, x, y);
+                        assert!(u32::from(mask) < (1 << samples));
+                        total += mask.count_ones();
+                    }
+                }
+                // Within one sample of the exact (rounded) coverage of the 4x4 tile.
+                let expected = (u32::from(alpha) * 16 * samples + 127) / 255;
+                assert!(total.abs_diff(expected) <= 1, "{samples} {alpha}");
+            }
+        }
+    }
+
+    #[test]
+    fn coverage_varies_by_position() {
+        let masks = [0, 1, 2, 3].map(|x| to_coverage(0x80, 4, x, 0));
+        assert!(masks.iter().any(|&mask| mask != masks[0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Sample count must be between 1 and 8")]
+    fn too_many_samples() {
+        let _ = to_coverage(0x80, 16, 0, 0);
+    }
+}
@@ -0,0 +1,329 @@
+//! Compositing and blending of RGBA pixels.
+//!
+//! ## Porter-Duff compositing
+//!
+//! [`Pixel::composite`] combines a source pixel (`self`) with a destination pixel using one of the
+//! [`PorterDuff`] operators, of which [`PorterDuff::Over`] (also available as [`Pixel::over`]) is
+//! by far the most common:
+//!
+//! ```rust
+//! use pxlfmt::{blend::PorterDuff, formats::rgba::Rgba8888, pixel::Pixel};
+//!
+//! // Half-transparent red, premultiplied, over opaque blue.
+//! let src = Pixel::<Rgba8888>::with_rgba(0x80, 0x00, 0x00, 0x80);
+//! let dst = Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0xFF, 0xFF);
+//! assert_eq!(src.over(&dst), Pixel::with_rgba(0x80, 0x00, 0x7F, 0xFF));
+//!
+//! // Only the part of the source that is inside of the destination.
+//! assert_eq!(src.composite(&dst, PorterDuff::In), src);
+//! ```
+//!
+//! Both pixels are expected to have _premultiplied_ alpha, where the color channels have already
+//! been scaled by the alpha channel (see [`Pixel::premultiply`]);
+//! this is what makes the operators simple linear combinations.
+//! Any RGBA format with [`Normalized`] channels is supported, so 8-bit and floating point pixels
+//! are composited with the same methods.
+//!
+//! ## Blend modes
+//!
+//! [`Pixel::blend`] mixes the colors of a source pixel (`self`) with a destination (the backdrop)
+//! using one of the separable [`BlendMode`]s, and then places the result over the destination:
+//!
+//! ```rust
+//! use pxlfmt::{blend::BlendMode, formats::rgba::Rgba8888, pixel::Pixel};
+//!
+//! let src = Pixel::<Rgba8888>::with_rgba(0x80, 0xFF, 0x00, 0xFF);
+//! let dst = Pixel::<Rgba8888>::with_rgba(0x40, 0x40, 0x40, 0xFF);
+//! assert_eq!(src.blend(&dst, BlendMode::Multiply), Pixel::with_rgba(0x20, 0x40, 0x00, 0xFF));
+//! assert_eq!(src.blend(&dst, BlendMode::Screen), Pixel::with_rgba(0xA0, 0xFF, 0x40, 0xFF));
+//! ```
+//!
+//! ## Interpolation
+//!
+//! [`Pixel::lerp`] linearly interpolates between two pixels, for gradients and anti-aliasing:
+//!
+//! ```rust
+//! use pxlfmt::{formats::rgba::Rgba8888, pixel::Pixel};
+//!
+//! let black = Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0x00, 0xFF);
+//! let white = Pixel::<Rgba8888>::with_rgba(0xFF, 0xFF, 0xFF, 0xFF);
+//! assert_eq!(black.lerp(&white, 0.5), Pixel::with_rgba(0x80, 0x80, 0x80, 0xFF));
+//! ```
+//!
+//! Unlike compositing, blending operates on _straight_ (not premultiplied) alpha, following the
+//! [W3C compositing and blending](https://www.w3.org/TR/compositing-1/#blending) model.
+
+use crate::{
+    formats::rgba::RgbaFormat,
+    norm::Normalized,
+    pixel::{Pixel, raw::RawPixel},
+};
+
+/// A Porter-Duff compositing operator.
+///
+/// Each operator computes `source * Fa + destination * Fb` for every channel (including alpha),
+/// where the factors `Fa` and `Fb` depend on the source alpha (`αs`) and destination alpha (`αd`).
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub enum PorterDuff {
+    /// The source is placed over the destination (`Fa = 1`, `Fb = 1 - αs`).
+    #[default]
+    Over,
+
+    /// The part of the source inside of the destination replaces it (`Fa = αd`, `Fb = 0`).
+    In,
+
+    /// The part of the source outside of the destination replaces it (`Fa = 1 - αd`, `Fb = 0`).
+    Out,
+
+    /// The part of the source inside of the destination is placed over it (`Fa = αd`,
+    /// `Fb = 1 - αs`).
+    Atop,
+
+    /// The parts of the source and destination outside of each other are kept (`Fa = 1 - αd`,
+    /// `Fb = 1 - αs`).
+    Xor,
+}
+
+impl PorterDuff {
+    /// Returns the source and destination factors (`Fa` and `Fb`) of the operator.
+    #[must_use]
+    pub fn factors(self, src_alpha: f32, dst_alpha: f32) -> (f32, f32) {
+        match self {
+            Self::Over => (1.0, 1.0 - src_alpha),
+            Self::In => (dst_alpha, 0.0),
+            Self::Out => (1.0 - dst_alpha, 0.0),
+            Self::Atop => (dst_alpha, 1.0 - src_alpha),
+            Self::Xor => (1.0 - dst_alpha, 1.0 - src_alpha),
+        }
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    /// Composites the pixel (the source) with `dst` (the destination) using the given operator.
+    ///
+    /// Both pixels must have premultiplied alpha, and so does the result.
+    #[must_use]
+    pub fn composite(&self, dst: &Self, op: PorterDuff) -> Self {
+        let (fa, fb) = op.factors(self.alpha().to_f32(), dst.alpha().to_f32());
+        let mix = |s: <F::RawPixel as RawPixel>::Channel, d: <F::RawPixel as RawPixel>::Channel| {
+            Normalized::from_f32(s.to_f32() * fa + d.to_f32() * fb)
+        };
+        Pixel::with_rgba(
+            mix(self.red(), dst.red()),
+            mix(self.green(), dst.green()),
+            mix(self.blue(), dst.blue()),
+            mix(self.alpha(), dst.alpha()),
+        )
+    }
+
+    /// Composites the pixel over `dst`, as with [`PorterDuff::Over`].
+    ///
+    /// Both pixels must have premultiplied alpha, and so does the result.
+    #[must_use]
+    pub fn over(&self, dst: &Self) -> Self {
+        self.composite(dst, PorterDuff::Over)
+    }
+}
+
+/// A separable blend mode, which combines each color channel independently.
+///
+/// Each mode is described as a function `B(Cb, Cs)` of the backdrop (destination) color `Cb` and
+/// the source color `Cs`, with both channels normalized to `0.0..=1.0`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub enum BlendMode {
+    /// The source color is used as is (`Cs`).
+    #[default]
+    Normal,
+
+    /// The colors are multiplied, which always darkens (`Cb * Cs`).
+    Multiply,
+
+    /// The complements of the colors are multiplied, which always lightens
+    /// (`Cb + Cs - Cb * Cs`).
+    Screen,
+
+    /// Multiplies or screens the colors depending on the backdrop, preserving its highlights and
+    /// shadows.
+    Overlay,
+
+    /// The colors are added, saturating at the maximum value (`min(1, Cb + Cs)`).
+    Add,
+
+    /// The source is subtracted from the backdrop, saturating at zero (`max(0, Cb - Cs)`).
+    Subtract,
+}
+
+impl BlendMode {
+    /// Returns the blended color of a backdrop and source channel.
+    #[must_use]
+    pub fn apply(self, backdrop: f32, source: f32) -> f32 {
+        match self {
+            Self::Normal => source,
+            Self::Multiply => backdrop * source,
+            Self::Screen => backdrop + source - backdrop * source,
+            Self::Overlay => {
+                if backdrop <= 0.5 {
+                    2.0 * backdrop * source
+                } else {
+                    1.0 - 2.0 * (1.0 - backdrop) * (1.0 - source)
+                }
+            }
+            Self::Add => (backdrop + source).min(1.0),
+            Self::Subtract => (backdrop - source).max(0.0),
+        }
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    /// Blends the pixel (the source) with `dst` (the backdrop) using the given mode.
+    ///
+    /// Both pixels must have straight alpha, and so does the result. Where the backdrop is
+    /// transparent the source color is kept, and the blended color is then composited over the
+    /// backdrop, so an opaque source over an opaque backdrop yields the blend mode's color as is.
+    #[must_use]
+    pub fn blend(&self, dst: &Self, mode: BlendMode) -> Self {
+        let src_alpha = self.alpha().to_f32();
+        let dst_alpha = dst.alpha().to_f32();
+        let out_alpha = src_alpha + dst_alpha * (1.0 - src_alpha);
+        let mix = |s: <F::RawPixel as RawPixel>::Channel, d: <F::RawPixel as RawPixel>::Channel| {
+            if out_alpha <= 0.0 {
+                return Normalized::from_f32(0.0);
+            }
+            let (s, d) = (s.to_f32(), d.to_f32());
+            let blended = (1.0 - dst_alpha) * s + dst_alpha * mode.apply(d, s);
+            Normalized::from_f32(
+                (src_alpha * blended + dst_alpha * d * (1.0 - src_alpha)) / out_alpha,
+            )
+        };
+        Pixel::with_rgba(
+            mix(self.red(), dst.red()),
+            mix(self.green(), dst.green()),
+            mix(self.blue(), dst.blue()),
+            Normalized::from_f32(out_alpha),
+        )
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    /// Linearly interpolates every channel between the pixel and `other`.
+    ///
+    /// A `t` of `0.0` returns the pixel and `1.0` returns `other`; values outside of that range are
+    /// clamped. Integer channels are rounded to the nearest value.
+    #[must_use]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: <F::RawPixel as RawPixel>::Channel, b: <F::RawPixel as RawPixel>::Channel| {
+            Normalized::from_f32(a.to_f32() * (1.0 - t) + b.to_f32() * t)
+        };
+        Pixel::with_rgba(
+            mix(self.red(), other.red()),
+            mix(self.green(), other.green()),
+            mix(self.blue(), other.blue()),
+            mix(self.alpha(), other.alpha()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{FloatRgba, Rgba8888};
+
+    use super::*;
+
+    #[test]
+    fn opaque_and_transparent_sources() {
+        let opaque = Pixel::<Rgba8888>::with_rgba(0x10, 0x20, 0x30, 0xFF);
+        let clear = Pixel::<Rgba8888>::zeroed();
+        let dst = Pixel::<Rgba8888>::with_rgba(0x40, 0x50, 0x60, 0xFF);
+
+        assert_eq!(opaque.over(&dst), opaque);
+        assert_eq!(clear.over(&dst), dst);
+        assert_eq!(opaque.composite(&dst, PorterDuff::Out), clear);
+        assert_eq!(opaque.composite(&dst, PorterDuff::Atop), opaque);
+        assert_eq!(opaque.composite(&dst, PorterDuff::Xor), clear);
+        assert_eq!(opaque.composite(&clear, PorterDuff::In), clear);
+        assert_eq!(opaque.composite(&clear, PorterDuff::Xor), opaque);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn float_partial_alpha() {
+        let src = Pixel::<FloatRgba>::with_rgba(0.5, 0.0, 0.0, 0.5);
+        let dst = Pixel::<FloatRgba>::with_rgba(0.0, 0.25, 0.0, 0.5);
+
+        let over = src.over(&dst);
+        assert_eq!(over.as_raw().into_inner(), [0.5, 0.125, 0.0, 0.75]);
+
+        let atop = src.composite(&dst, PorterDuff::Atop);
+        assert_eq!(atop.as_raw().into_inner(), [0.25, 0.125, 0.0, 0.5]);
+
+        let xor = src.composite(&dst, PorterDuff::Xor);
+        assert_eq!(xor.as_raw().into_inner(), [0.25, 0.125, 0.0, 0.5]);
+    }
+
+    #[test]
+    fn blend_modes_opaque() {
+        let src = Pixel::<Rgba8888>::with_rgba(0x80, 0x80, 0x40, 0xFF);
+        let dst = Pixel::<Rgba8888>::with_rgba(0x40, 0x40, 0x80, 0xFF);
+        let blend = |mode| src.blend(&dst, mode).as_raw().into_inner();
+
+        assert_eq!(blend(BlendMode::Normal), src.as_raw().into_inner());
+        assert_eq!(blend(BlendMode::Multiply), 0xFF20_2020);
+        assert_eq!(blend(BlendMode::Screen), 0xFFA0_A0A0);
+        assert_eq!(blend(BlendMode::Overlay), 0xFF41_4040);
+        assert_eq!(blend(BlendMode::Add), 0xFFC0_C0C0);
+        assert_eq!(blend(BlendMode::Subtract), 0xFF40_0000);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn blend_partial_alpha() {
+        let black = Pixel::<FloatRgba>::with_rgba(0.0, 0.0, 0.0, 0.5);
+        let white = Pixel::<FloatRgba>::with_rgba(1.0, 1.0, 1.0, 0.5);
+        let gray = Pixel::<FloatRgba>::with_rgba(0.5, 0.5, 0.5, 1.0);
+        let clear = Pixel::<FloatRgba>::zeroed();
+
+        let multiply = black.blend(&gray, BlendMode::Multiply);
+        assert_eq!(multiply.as_raw().into_inner(), [0.25, 0.25, 0.25, 1.0]);
+
+        let screen = white.blend(&gray, BlendMode::Screen);
+        assert_eq!(screen.as_raw().into_inner(), [0.75, 0.75, 0.75, 1.0]);
+
+        let onto_clear = white.blend(&clear, BlendMode::Multiply);
+        assert_eq!(onto_clear, white);
+        assert_eq!(clear.blend(&clear, BlendMode::Screen), clear);
+    }
+
+    #[test]
+    fn lerp_endpoints_and_rounding() {
+        let a = Pixel::<Rgba8888>::with_rgba(0x00, 0x10, 0xFF, 0x01);
+        let b = Pixel::<Rgba8888>::with_rgba(0xFF, 0x11, 0x00, 0xFE);
+
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, -1.0), a);
+        assert_eq!(a.lerp(&b, 2.0), b);
+        assert_eq!(a.lerp(&b, 0.25), Pixel::with_rgba(0x40, 0x10, 0xBF, 0x40));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn lerp_float() {
+        let a = Pixel::<FloatRgba>::with_rgba(0.0, 1.0, 0.5, 0.0);
+        let b = Pixel::<FloatRgba>::with_rgba(1.0, 0.0, 0.5, 1.0);
+        let mid = a.lerp(&b, 0.5);
+        assert_eq!(mid.as_raw().into_inner(), [0.5, 0.5, 0.5, 0.5]);
+    }
+}
@@ -0,0 +1,541 @@
+//! Named colors.
+//!
+//! Provides the [CSS named colors](https://www.w3.org/TR/css-color-4/#named-colors) as
+//! constructors of a pixel in any RGBA format with 8-bit channels, backed by the channel values in
+//! [`channels`]:
+//!
+//! ```rust
+//! use pxlfmt::{colors, formats::rgba::Abgr8888};
+//!
+//! let pixel = colors::REBECCA_PURPLE::<Abgr8888>();
+//! assert_eq!(pixel.channels(), [0x66, 0x33, 0x99, 0xFF]);
+//! assert_eq!(colors::from_name("RebeccaPurple"), Some(colors::channels::REBECCA_PURPLE));
+//! ```
+//!
+//! As in CSS, `gray` and `grey` (and their variants) are interchangeable, and `aqua`/`cyan` and
+//! `fuchsia`/`magenta` are the same color.
+
+use crate::{
+    formats::rgba::RgbaFormat,
+    pixel::{Pixel, raw::RawPixel},
+};
+
+pub mod channels;
+
+macro_rules! named_colors {
+  ($($(#[$doc:meta])* $name:ident;)*) => {
+    $(
+      $(#[$doc])*
+      #[must_use]
+      #[allow(non_snake_case)]
+      pub fn $name<F>() -> Pixel<F>
+      where
+        F: RgbaFormat<RawPixel: RawPixel<Channel = u8>>,
+      {
+        Pixel::from_channels(channels::$name)
+      }
+    )*
+  };
+}
+
+named_colors! {
+    /// CSS `transparent`, transparent black.
+    TRANSPARENT;
+
+    /// CSS `aliceblue` (`#F0F8FF`).
+    ALICE_BLUE;
+
+    /// CSS `antiquewhite` (`#FAEBD7`).
+    ANTIQUE_WHITE;
+
+    /// CSS `aqua` (`#00FFFF`).
+    AQUA;
+
+    /// CSS `aquamarine` (`#7FFFD4`).
+    AQUAMARINE;
+
+    /// CSS `azure` (`#F0FFFF`).
+    AZURE;
+
+    /// CSS `beige` (`#F5F5DC`).
+    BEIGE;
+
+    /// CSS `bisque` (`#FFE4C4`).
+    BISQUE;
+
+    /// CSS `black` (`#000000`).
+    BLACK;
+
+    /// CSS `blanchedalmond` (`#FFEBCD`).
+    BLANCHED_ALMOND;
+
+    /// CSS `blue` (`#0000FF`).
+    BLUE;
+
+    /// CSS `blueviolet` (`#8A2BE2`).
+    BLUE_VIOLET;
+
+    /// CSS `brown` (`#A52A2A`).
+    BROWN;
+
+    /// CSS `burlywood` (`#DEB887`).
+    BURLYWOOD;
+
+    /// CSS `cadetblue` (`#5F9EA0`).
+    CADET_BLUE;
+
+    /// CSS `chartreuse` (`#7FFF00`).
+    CHARTREUSE;
+
+    /// CSS `chocolate` (`#D2691E`).
+    CHOCOLATE;
+
+    /// CSS `coral` (`#FF7F50`).
+    CORAL;
+
+    /// CSS `cornflowerblue` (`#6495ED`).
+    CORNFLOWER_BLUE;
+
+    /// CSS `cornsilk` (`#FFF8DC`).
+    CORNSILK;
+
+    /// CSS `crimson` (`#DC143C`).
+    CRIMSON;
+
+    /// CSS `cyan` (`#00FFFF`).
+    CYAN;
+
+    /// CSS `darkblue` (`#00008B`).
+    DARK_BLUE;
+
+    /// CSS `darkcyan` (`#008B8B`).
+    DARK_CYAN;
+
+    /// CSS `darkgoldenrod` (`#B8860B`).
+    DARK_GOLDENROD;
+
+    /// CSS `darkgray` (`#A9A9A9`).
+    DARK_GRAY;
+
+    /// CSS `darkgreen` (`#006400`).
+    DARK_GREEN;
+
+    /// CSS `darkgrey` (`#A9A9A9`).
+    DARK_GREY;
+
+    /// CSS `darkkhaki` (`#BDB76B`).
+    DARK_KHAKI;
+
+    /// CSS `darkmagenta` (`#8B008B`).
+    DARK_MAGENTA;
+
+    /// CSS `darkolivegreen` (`#556B2F`).
+    DARK_OLIVE_GREEN;
+
+    /// CSS `darkorange` (`#FF8C00`).
+    DARK_ORANGE;
+
+    /// CSS `darkorchid` (`#9932CC`).
+    DARK_ORCHID;
+
+    /// CSS `darkred` (`#8B0000`).
+    DARK_RED;
+
+    /// CSS `darksalmon` (`#E9967A`).
+    DARK_SALMON;
+
+    /// CSS `darkseagreen` (`#8FBC8F`).
+    DARK_SEA_GREEN;
+
+    /// CSS `darkslateblue` (`#483D8B`).
+    DARK_SLATE_BLUE;
+
+    /// CSS `darkslategray` (`#2F4F4F`).
+    DARK_SLATE_GRAY;
+
+    /// CSS `darkslategrey` (`#2F4F4F`).
+    DARK_SLATE_GREY;
+
+    /// CSS `darkturquoise` (`#00CED1`).
+    DARK_TURQUOISE;
+
+    /// CSS `darkviolet` (`#9400D3`).
+    DARK_VIOLET;
+
+    /// CSS `deeppink` (`#FF1493`).
+    DEEP_PINK;
+
+    /// CSS `deepskyblue` (`#00BFFF`).
+    DEEP_SKY_BLUE;
+
+    /// CSS `dimgray` (`#696969`).
+    DIM_GRAY;
+
+    /// CSS `dimgrey` (`#696969`).
+    DIM_GREY;
+
+    /// CSS `dodgerblue` (`#1E90FF`).
+    DODGER_BLUE;
+
+    /// CSS `firebrick` (`#B22222`).
+    FIREBRICK;
+
+    /// CSS `floralwhite` (`#FFFAF0`).
+    FLORAL_WHITE;
+
+    /// CSS `forestgreen` (`#228B22`).
+    FOREST_GREEN;
+
+    /// CSS `fuchsia` (`#FF00FF`).
+    FUCHSIA;
+
+    /// CSS `gainsboro` (`#DCDCDC`).
+    GAINSBORO;
+
+    /// CSS `ghostwhite` (`#F8F8FF`).
+    GHOST_WHITE;
+
+    /// CSS `gold` (`#FFD700`).
+    GOLD;
+
+    /// CSS `goldenrod` (`#DAA520`).
+    GOLDENROD;
+
+    /// CSS `gray` (`#808080`).
+    GRAY;
+
+    /// CSS `green` (`#008000`).
+    GREEN;
+
+    /// CSS `greenyellow` (`#ADFF2F`).
+    GREEN_YELLOW;
+
+    /// CSS `grey` (`#808080`).
+    GREY;
+
+    /// CSS `honeydew` (`#F0FFF0`).
+    HONEYDEW;
+
+    /// CSS `hotpink` (`#FF69B4`).
+    HOT_PINK;
+
+    /// CSS `indianred` (`#CD5C5C`).
+    INDIAN_RED;
+
+    /// CSS `indigo` (`#4B0082`).
+    INDIGO;
+
+    /// CSS `ivory` (`#FFFFF0`).
+    IVORY;
+
+    /// CSS `khaki` (`#F0E68C`).
+    KHAKI;
+
+    /// CSS `lavender` (`#E6E6FA`).
+    LAVENDER;
+
+    /// CSS `lavenderblush` (`#FFF0F5`).
+    LAVENDER_BLUSH;
+
+    /// CSS `lawngreen` (`#7CFC00`).
+    LAWN_GREEN;
+
+    /// CSS `lemonchiffon` (`#FFFACD`).
+    LEMON_CHIFFON;
+
+    /// CSS `lightblue` (`#ADD8E6`).
+    LIGHT_BLUE;
+
+    /// CSS `lightcoral` (`#F08080`).
+    LIGHT_CORAL;
+
+    /// CSS `lightcyan` (`#E0FFFF`).
+    LIGHT_CYAN;
+
+    /// CSS `lightgoldenrodyellow` (`#FAFAD2`).
+    LIGHT_GOLDENROD_YELLOW;
+
+    /// CSS `lightgray` (`#D3D3D3`).
+    LIGHT_GRAY;
+
+    /// CSS `lightgreen` (`#90EE90`).
+    LIGHT_GREEN;
+
+    /// CSS `lightgrey` (`#D3D3D3`).
+    LIGHT_GREY;
+
+    /// CSS `lightpink` (`#FFB6C1`).
+    LIGHT_PINK;
+
+    /// CSS `lightsalmon` (`#FFA07A`).
+    LIGHT_SALMON;
+
+    /// CSS `lightseagreen` (`#20B2AA`).
+    LIGHT_SEA_GREEN;
+
+    /// CSS `lightskyblue` (`#87CEFA`).
+    LIGHT_SKY_BLUE;
+
+    /// CSS `lightslategray` (`#778899`).
+    LIGHT_SLATE_GRAY;
+
+    /// CSS `lightslategrey` (`#778899`).
+    LIGHT_SLATE_GREY;
+
+    /// CSS `lightsteelblue` (`#B0C4DE`).
+    LIGHT_STEEL_BLUE;
+
+    /// CSS `lightyellow` (`#FFFFE0`).
+    LIGHT_YELLOW;
+
+    /// CSS `lime` (`#00FF00`).
+    LIME;
+
+    /// CSS `limegreen` (`#32CD32`).
+    LIME_GREEN;
+
+    /// CSS `linen` (`#FAF0E6`).
+    LINEN;
+
+    /// CSS `magenta` (`#FF00FF`).
+    MAGENTA;
+
+    /// CSS `maroon` (`#800000`).
+    MAROON;
+
+    /// CSS `mediumaquamarine` (`#66CDAA`).
+    MEDIUM_AQUAMARINE;
+
+    /// CSS `mediumblue` (`#0000CD`).
+    MEDIUM_BLUE;
+
+    /// CSS `mediumorchid` (`#BA55D3`).
+    MEDIUM_ORCHID;
+
+    /// CSS `mediumpurple` (`#9370DB`).
+    MEDIUM_PURPLE;
+
+    /// CSS `mediumseagreen` (`#3CB371`).
+    MEDIUM_SEA_GREEN;
+
+    /// CSS `mediumslateblue` (`#7B68EE`).
+    MEDIUM_SLATE_BLUE;
+
+    /// CSS `mediumspringgreen` (`#00FA9A`).
+    MEDIUM_SPRING_GREEN;
+
+    /// CSS `mediumturquoise` (`#48D1CC`).
+    MEDIUM_TURQUOISE;
+
+    /// CSS `mediumvioletred` (`#C71585`).
+    MEDIUM_VIOLET_RED;
+
+    /// CSS `midnightblue` (`#191970`).
+    MIDNIGHT_BLUE;
+
+    /// CSS `mintcream` (`#F5FFFA`).
+    MINT_CREAM;
+
+    /// CSS `mistyrose` (`#FFE4E1`).
+    MISTY_ROSE;
+
+    /// CSS `moccasin` (`#FFE4B5`).
+    MOCCASIN;
+
+    /// CSS `navajowhite` (`#FFDEAD`).
+    NAVAJO_WHITE;
+
+    /// CSS `navy` (`#000080`).
+    NAVY;
+
+    /// CSS `oldlace` (`#FDF5E6`).
+    OLD_LACE;
+
+    /// CSS `olive` (`#808000`).
+    OLIVE;
+
+    /// CSS `olivedrab` (`#6B8E23`).
+    OLIVE_DRAB;
+
+    /// CSS `orange` (`#FFA500`).
+    ORANGE;
+
+    /// CSS `orangered` (`#FF4500`).
+    ORANGE_RED;
+
+    /// CSS `orchid` (`#DA70D6`).
+    ORCHID;
+
+    /// CSS `palegoldenrod` (`#EEE8AA`).
+    PALE_GOLDENROD;
+
+    /// CSS `palegreen` (`#98FB98`).
+    PALE_GREEN;
+
+    /// CSS `paleturquoise` (`#AFEEEE`).
+    PALE_TURQUOISE;
+
+    /// CSS `palevioletred` (`#DB7093`).
+    PALE_VIOLET_RED;
+
+    /// CSS `papayawhip` (`#FFEFD5`).
+    PAPAYA_WHIP;
+
+    /// CSS `peachpuff` (`#FFDAB9`).
+    PEACH_PUFF;
+
+    /// CSS `peru` (`#CD853F`).
+    PERU;
+
+    /// CSS `pink` (`#FFC0CB`).
+    PINK;
+
+    /// CSS `plum` (`#DDA0DD`).
+    PLUM;
+
+    /// CSS `powderblue` (`#B0E0E6`).
+    POWDER_BLUE;
+
+    /// CSS `purple` (`#800080`).
+    PURPLE;
+
+    /// CSS `rebeccapurple` (`#663399`).
+    REBECCA_PURPLE;
+
+    /// CSS `red` (`#FF0000`).
+    RED;
+
+    /// CSS `rosybrown` (`#BC8F8F`).
+    ROSY_BROWN;
+
+    /// CSS `royalblue` (`#4169E1`).
+    ROYAL_BLUE;
+
+    /// CSS `saddlebrown` (`#8B4513`).
+    SADDLE_BROWN;
+
+    /// CSS `salmon` (`#FA8072`).
+    SALMON;
+
+    /// CSS `sandybrown` (`#F4A460`).
+    SANDY_BROWN;
+
+    /// CSS `seagreen` (`#2E8B57`).
+    SEA_GREEN;
+
+    /// CSS `seashell` (`#FFF5EE`).
+    SEASHELL;
+
+    /// CSS `sienna` (`#A0522D`).
+    SIENNA;
+
+    /// CSS `silver` (`#C0C0C0`).
+    SILVER;
+
+    /// CSS `skyblue` (`#87CEEB`).
+    SKY_BLUE;
+
+    /// CSS `slateblue` (`#6A5ACD`).
+    SLATE_BLUE;
+
+    /// CSS `slategray` (`#708090`).
+    SLATE_GRAY;
+
+    /// CSS `slategrey` (`#708090`).
+    SLATE_GREY;
+
+    /// CSS `snow` (`#FFFAFA`).
+    SNOW;
+
+    /// CSS `springgreen` (`#00FF7F`).
+    SPRING_GREEN;
+
+    /// CSS `steelblue` (`#4682B4`).
+    STEEL_BLUE;
+
+    /// CSS `tan` (`#D2B48C`).
+    TAN;
+
+    /// CSS `teal` (`#008080`).
+    TEAL;
+
+    /// CSS `thistle` (`#D8BFD8`).
+    THISTLE;
+
+    /// CSS `tomato` (`#FF6347`).
+    TOMATO;
+
+    /// CSS `turquoise` (`#40E0D0`).
+    TURQUOISE;
+
+    /// CSS `violet` (`#EE82EE`).
+    VIOLET;
+
+    /// CSS `wheat` (`#F5DEB3`).
+    WHEAT;
+
+    /// CSS `white` (`#FFFFFF`).
+    WHITE;
+
+    /// CSS `whitesmoke` (`#F5F5F5`).
+    WHITE_SMOKE;
+
+    /// CSS `yellow` (`#FFFF00`).
+    YELLOW;
+
+    /// CSS `yellowgreen` (`#9ACD32`).
+    YELLOW_GREEN;
+}
+
+/// Returns the color with the given CSS name, ignoring ASCII case.
+///
+/// Returns `None` if the name is not a CSS named color.
+#[must_use]
+pub fn from_name(name: &str) -> Option<[u8; 4]> {
+    channels::NAMED
+        .iter()
+        .find(|(named, _)| named.eq_ignore_ascii_case(name))
+        .map(|&(_, color)| color)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{Abgr8888, Rgba8888};
+
+    use super::*;
+
+    #[test]
+    fn constructors_match_channels() {
+        assert_eq!(RED::<Rgba8888>().channels(), channels::RED);
+        assert_eq!(ALICE_BLUE::<Abgr8888>().channels(), channels::ALICE_BLUE);
+        assert_eq!(TRANSPARENT::<Rgba8888>().alpha(), 0x00);
+    }
+
+    #[test]
+    fn from_name_ignores_case() {
+        assert_eq!(from_name("red"), Some(channels::RED));
+        assert_eq!(from_name("AliceBlue"), Some(channels::ALICE_BLUE));
+        assert_eq!(from_name("TRANSPARENT"), Some(channels::TRANSPARENT));
+        assert_eq!(from_name("reddish"), None);
+    }
+
+    #[test]
+    fn css_values_differ_from_x11() {
+        assert_eq!(channels::GRAY, [0x80, 0x80, 0x80, 0xFF]);
+        assert_eq!(channels::GREEN, [0x00, 0x80, 0x00, 0xFF]);
+        assert_eq!(channels::MAROON, [0x80, 0x00, 0x00, 0xFF]);
+        assert_eq!(channels::PURPLE, [0x80, 0x00, 0x80, 0xFF]);
+    }
+
+    #[test]
+    fn names_are_unique() {
+        for (i, (name, _)) in channels::NAMED.iter().enumerate() {
+            assert!(
+                channels::NAMED[i + 1..]
+                    .iter()
+                    .all(|(other, _)| other != name),
+                "{name}"
+            );
+        }
+    }
+}
@@ -0,0 +1,604 @@
+//! The CSS named colors as 8-bit channel values in RGBA order.
+//!
+//! These are the values behind the constructors in [`colors`](super), for use where an array is
+//! needed, such as in a `const` context with `with_rgba_const`.
+
+/// CSS `transparent`, transparent black.
+pub const TRANSPARENT: [u8; 4] = [0x00, 0x00, 0x00, 0x00];
+
+/// CSS `aliceblue` (`#F0F8FF`).
+pub const ALICE_BLUE: [u8; 4] = [0xF0, 0xF8, 0xFF, 0xFF];
+
+/// CSS `antiquewhite` (`#FAEBD7`).
+pub const ANTIQUE_WHITE: [u8; 4] = [0xFA, 0xEB, 0xD7, 0xFF];
+
+/// CSS `aqua` (`#00FFFF`).
+pub const AQUA: [u8; 4] = [0x00, 0xFF, 0xFF, 0xFF];
+
+/// CSS `aquamarine` (`#7FFFD4`).
+pub const AQUAMARINE: [u8; 4] = [0x7F, 0xFF, 0xD4, 0xFF];
+
+/// CSS `azure` (`#F0FFFF`).
+pub const AZURE: [u8; 4] = [0xF0, 0xFF, 0xFF, 0xFF];
+
+/// CSS `beige` (`#F5F5DC`).
+pub const BEIGE: [u8; 4] = [0xF5, 0xF5, 0xDC, 0xFF];
+
+/// CSS `bisque` (`#FFE4C4`).
+pub const BISQUE: [u8; 4] = [0xFF, 0xE4, 0xC4, 0xFF];
+
+/// CSS `black` (`#000000`).
+pub const BLACK: [u8; 4] = [0x00, 0x00, 0x00, 0xFF];
+
+/// CSS `blanchedalmond` (`#FFEBCD`).
+pub const BLANCHED_ALMOND: [u8; 4] = [0xFF, 0xEB, 0xCD, 0xFF];
+
+/// CSS `blue` (`#0000FF`).
+pub const BLUE: [u8; 4] = [0x00, 0x00, 0xFF, 0xFF];
+
+/// CSS `blueviolet` (`#8A2BE2`).
+pub const BLUE_VIOLET: [u8; 4] = [0x8A, 0x2B, 0xE2, 0xFF];
+
+/// CSS `brown` (`#A52A2A`).
+pub const BROWN: [u8; 4] = [0xA5, 0x2A, 0x2A, 0xFF];
+
+/// CSS `burlywood` (`#DEB887`).
+pub const BURLYWOOD: [u8; 4] = [0xDE, 0xB8, 0x87, 0xFF];
+
+/// CSS `cadetblue` (`#5F9EA0`).
+pub const CADET_BLUE: [u8; 4] = [0x5F, 0x9E, 0xA0, 0xFF];
+
+/// CSS `chartreuse` (`#7FFF00`).
+pub const CHARTREUSE: [u8; 4] = [0x7F, 0xFF, 0x00, 0xFF];
+
+/// CSS `chocolate` (`#D2691E`).
+pub const CHOCOLATE: [u8; 4] = [0xD2, 0x69, 0x1E, 0xFF];
+
+/// CSS `coral` (`#FF7F50`).
+pub const CORAL: [u8; 4] = [0xFF, 0x7F, 0x50, 0xFF];
+
+/// CSS `cornflowerblue` (`#6495ED`).
+pub const CORNFLOWER_BLUE: [u8; 4] = [0x64, 0x95, 0xED, 0xFF];
+
+/// CSS `cornsilk` (`#FFF8DC`).
+pub const CORNSILK: [u8; 4] = [0xFF, 0xF8, 0xDC, 0xFF];
+
+/// CSS `crimson` (`#DC143C`).
+pub const CRIMSON: [u8; 4] = [0xDC, 0x14, 0x3C, 0xFF];
+
+/// CSS `cyan` (`#00FFFF`).
+pub const CYAN: [u8; 4] = [0x00, 0xFF, 0xFF, 0xFF];
+
+/// CSS `darkblue` (`#00008B`).
+pub const DARK_BLUE: [u8; 4] = [0x00, 0x00, 0x8B, 0xFF];
+
+/// CSS `darkcyan` (`#008B8B`).
+pub const DARK_CYAN: [u8; 4] = [0x00, 0x8B, 0x8B, 0xFF];
+
+/// CSS `darkgoldenrod` (`#B8860B`).
+pub const DARK_GOLDENROD: [u8; 4] = [0xB8, 0x86, 0x0B, 0xFF];
+
+/// CSS `darkgray` (`#A9A9A9`).
+pub const DARK_GRAY: [u8; 4] = [0xA9, 0xA9, 0xA9, 0xFF];
+
+/// CSS `darkgreen` (`#006400`).
+pub const DARK_GREEN: [u8; 4] = [0x00, 0x64, 0x00, 0xFF];
+
+/// CSS `darkgrey` (`#A9A9A9`).
+pub const DARK_GREY: [u8; 4] = [0xA9, 0xA9, 0xA9, 0xFF];
+
+/// CSS `darkkhaki` (`#BDB76B`).
+pub const DARK_KHAKI: [u8; 4] = [0xBD, 0xB7, 0x6B, 0xFF];
+
+/// CSS `darkmagenta` (`#8B008B`).
+pub const DARK_MAGENTA: [u8; 4] = [0x8B, 0x00, 0x8B, 0xFF];
+
+/// CSS `darkolivegreen` (`#556B2F`).
+pub const DARK_OLIVE_GREEN: [u8; 4] = [0x55, 0x6B, 0x2F, 0xFF];
+
+/// CSS `darkorange` (`#FF8C00`).
+pub const DARK_ORANGE: [u8; 4] = [0xFF, 0x8C, 0x00, 0xFF];
+
+/// CSS `darkorchid` (`#9932CC`).
+pub const DARK_ORCHID: [u8; 4] = [0x99, 0x32, 0xCC, 0xFF];
+
+/// CSS `darkred` (`#8B0000`).
+pub const DARK_RED: [u8; 4] = [0x8B, 0x00, 0x00, 0xFF];
+
+/// CSS `darksalmon` (`#E9967A`).
+pub const DARK_SALMON: [u8; 4] = [0xE9, 0x96, 0x7A, 0xFF];
+
+/// CSS `darkseagreen` (`#8FBC8F`).
+pub const DARK_SEA_GREEN: [u8; 4] = [0x8F, 0xBC, 0x8F, 0xFF];
+
+/// CSS `darkslateblue` (`#483D8B`).
+pub const DARK_SLATE_BLUE: [u8; 4] = [0x48, 0x3D, 0x8B, 0xFF];
+
+/// CSS `darkslategray` (`#2F4F4F`).
+pub const DARK_SLATE_GRAY: [u8; 4] = [0x2F, 0x4F, 0x4F, 0xFF];
+
+/// CSS `darkslategrey` (`#2F4F4F`).
+pub const DARK_SLATE_GREY: [u8; 4] = [0x2F, 0x4F, 0x4F, 0xFF];
+
+/// CSS `darkturquoise` (`#00CED1`).
+pub const DARK_TURQUOISE: [u8; 4] = [0x00, 0xCE, 0xD1, 0xFF];
+
+/// CSS `darkviolet` (`#9400D3`).
+pub const DARK_VIOLET: [u8; 4] = [0x94, 0x00, 0xD3, 0xFF];
+
+/// CSS `deeppink` (`#FF1493`).
+pub const DEEP_PINK: [u8; 4] = [0xFF, 0x14, 0x93, 0xFF];
+
+/// CSS `deepskyblue` (`#00BFFF`).
+pub const DEEP_SKY_BLUE: [u8; 4] = [0x00, 0xBF, 0xFF, 0xFF];
+
+/// CSS `dimgray` (`#696969`).
+pub const DIM_GRAY: [u8; 4] = [0x69, 0x69, 0x69, 0xFF];
+
+/// CSS `dimgrey` (`#696969`).
+pub const DIM_GREY: [u8; 4] = [0x69, 0x69, 0x69, 0xFF];
+
+/// CSS `dodgerblue` (`#1E90FF`).
+pub const DODGER_BLUE: [u8; 4] = [0x1E, 0x90, 0xFF, 0xFF];
+
+/// CSS `firebrick` (`#B22222`).
+pub const FIREBRICK: [u8; 4] = [0xB2, 0x22, 0x22, 0xFF];
+
+/// CSS `floralwhite` (`#FFFAF0`).
+pub const FLORAL_WHITE: [u8; 4] = [0xFF, 0xFA, 0xF0, 0xFF];
+
+/// CSS `forestgreen` (`#228B22`).
+pub const FOREST_GREEN: [u8; 4] = [0x22, 0x8B, 0x22, 0xFF];
+
+/// CSS `fuchsia` (`#FF00FF`).
+pub const FUCHSIA: [u8; 4] = [0xFF, 0x00, 0xFF, 0xFF];
+
+/// CSS `gainsboro` (`#DCDCDC`).
+pub const GAINSBORO: [u8; 4] = [0xDC, 0xDC, 0xDC, 0xFF];
+
+/// CSS `ghostwhite` (`#F8F8FF`).
+pub const GHOST_WHITE: [u8; 4] = [0xF8, 0xF8, 0xFF, 0xFF];
+
+/// CSS `gold` (`#FFD700`).
+pub const GOLD: [u8; 4] = [0xFF, 0xD7, 0x00, 0xFF];
+
+/// CSS `goldenrod` (`#DAA520`).
+pub const GOLDENROD: [u8; 4] = [0xDA, 0xA5, 0x20, 0xFF];
+
+/// CSS `gray` (`#808080`).
+pub const GRAY: [u8; 4] = [0x80, 0x80, 0x80, 0xFF];
+
+/// CSS `green` (`#008000`).
+pub const GREEN: [u8; 4] = [0x00, 0x80, 0x00, 0xFF];
+
+/// CSS `greenyellow` (`#ADFF2F`).
+pub const GREEN_YELLOW: [u8; 4] = [0xAD, 0xFF, 0x2F, 0xFF];
+
+/// CSS `grey` (`#808080`).
+pub const GREY: [u8; 4] = [0x80, 0x80, 0x80, 0xFF];
+
+/// CSS `honeydew` (`#F0FFF0`).
+pub const HONEYDEW: [u8; 4] = [0xF0, 0xFF, 0xF0, 0xFF];
+
+/// CSS `hotpink` (`#FF69B4`).
+pub const HOT_PINK: [u8; 4] = [0xFF, 0x69, 0xB4, 0xFF];
+
+/// CSS `indianred` (`#CD5C5C`).
+pub const INDIAN_RED: [u8; 4] = [0xCD, 0x5C, 0x5C, 0xFF];
+
+/// CSS `indigo` (`#4B0082`).
+pub const INDIGO: [u8; 4] = [0x4B, 0x00, 0x82, 0xFF];
+
+/// CSS `ivory` (`#FFFFF0`).
+pub const IVORY: [u8; 4] = [0xFF, 0xFF, 0xF0, 0xFF];
+
+/// CSS `khaki` (`#F0E68C`).
+pub const KHAKI: [u8; 4] = [0xF0, 0xE6, 0x8C, 0xFF];
+
+/// CSS `lavender` (`#E6E6FA`).
+pub const LAVENDER: [u8; 4] = [0xE6, 0xE6, 0xFA, 0xFF];
+
+/// CSS `lavenderblush` (`#FFF0F5`).
+pub const LAVENDER_BLUSH: [u8; 4] = [0xFF, 0xF0, 0xF5, 0xFF];
+
+/// CSS `lawngreen` (`#7CFC00`).
+pub const LAWN_GREEN: [u8; 4] = [0x7C, 0xFC, 0x00, 0xFF];
+
+/// CSS `lemonchiffon` (`#FFFACD`).
+pub const LEMON_CHIFFON: [u8; 4] = [0xFF, 0xFA, 0xCD, 0xFF];
+
+/// CSS `lightblue` (`#ADD8E6`).
+pub const LIGHT_BLUE: [u8; 4] = [0xAD, 0xD8, 0xE6, 0xFF];
+
+/// CSS `lightcoral` (`#F08080`).
+pub const LIGHT_CORAL: [u8; 4] = [0xF0, 0x80, 0x80, 0xFF];
+
+/// CSS `lightcyan` (`#E0FFFF`).
+pub const LIGHT_CYAN: [u8; 4] = [0xE0, 0xFF, 0xFF, 0xFF];
+
+/// CSS `lightgoldenrodyellow` (`#FAFAD2`).
+pub const LIGHT_GOLDENROD_YELLOW: [u8; 4] = [0xFA, 0xFA, 0xD2, 0xFF];
+
+/// CSS `lightgray` (`#D3D3D3`).
+pub const LIGHT_GRAY: [u8; 4] = [0xD3, 0xD3, 0xD3, 0xFF];
+
+/// CSS `lightgreen` (`#90EE90`).
+pub const LIGHT_GREEN: [u8; 4] = [0x90, 0xEE, 0x90, 0xFF];
+
+/// CSS `lightgrey` (`#D3D3D3`).
+pub const LIGHT_GREY: [u8; 4] = [0xD3, 0xD3, 0xD3, 0xFF];
+
+/// CSS `lightpink` (`#FFB6C1`).
+pub const LIGHT_PINK: [u8; 4] = [0xFF, 0xB6, 0xC1, 0xFF];
+
+/// CSS `lightsalmon` (`#FFA07A`).
+pub const LIGHT_SALMON: [u8; 4] = [0xFF, 0xA0, 0x7A, 0xFF];
+
+/// CSS `lightseagreen` (`#20B2AA`).
+pub const LIGHT_SEA_GREEN: [u8; 4] = [0x20, 0xB2, 0xAA, 0xFF];
+
+/// CSS `lightskyblue` (`#87CEFA`).
+pub const LIGHT_SKY_BLUE: [u8; 4] = [0x87, 0xCE, 0xFA, 0xFF];
+
+/// CSS `lightslategray` (`#778899`).
+pub const LIGHT_SLATE_GRAY: [u8; 4] = [0x77, 0x88, 0x99, 0xFF];
+
+/// CSS `lightslategrey` (`#778899`).
+pub const LIGHT_SLATE_GREY: [u8; 4] = [0x77, 0x88, 0x99, 0xFF];
+
+/// CSS `lightsteelblue` (`#B0C4DE`).
+pub const LIGHT_STEEL_BLUE: [u8; 4] = [0xB0, 0xC4, 0xDE, 0xFF];
+
+/// CSS `lightyellow` (`#FFFFE0`).
+pub const LIGHT_YELLOW: [u8; 4] = [0xFF, 0xFF, 0xE0, 0xFF];
+
+/// CSS `lime` (`#00FF00`).
+pub const LIME: [u8; 4] = [0x00, 0xFF, 0x00, 0xFF];
+
+/// CSS `limegreen` (`#32CD32`).
+pub const LIME_GREEN: [u8; 4] = [0x32, 0xCD, 0x32, 0xFF];
+
+/// CSS `linen` (`#FAF0E6`).
+pub const LINEN: [u8; 4] = [0xFA, 0xF0, 0xE6, 0xFF];
+
+/// CSS `magenta` (`#FF00FF`).
+pub const MAGENTA: [u8; 4] = [0xFF, 0x00, 0xFF, 0xFF];
+
+/// CSS `maroon` (`#800000`).
+pub const MAROON: [u8; 4] = [0x80, 0x00, 0x00, 0xFF];
+
+/// CSS `mediumaquamarine` (`#66CDAA`).
+pub const MEDIUM_AQUAMARINE: [u8; 4] = [0x66, 0xCD, 0xAA, 0xFF];
+
+/// CSS `mediumblue` (`#0000CD`).
+pub const MEDIUM_BLUE: [u8; 4] = [0x00, 0x00, 0xCD, 0xFF];
+
+/// CSS `mediumorchid` (`#BA55D3`).
+pub const MEDIUM_ORCHID: [u8; 4] = [0xBA, 0x55, 0xD3, 0xFF];
+
+/// CSS `mediumpurple` (`#9370DB`).
+pub const MEDIUM_PURPLE: [u8; 4] = [0x93, 0x70, 0xDB, 0xFF];
+
+/// CSS `mediumseagreen` (`#3CB371`).
+pub const MEDIUM_SEA_GREEN: [u8; 4] = [0x3C, 0xB3, 0x71, 0xFF];
+
+/// CSS `mediumslateblue` (`#7B68EE`).
+pub const MEDIUM_SLATE_BLUE: [u8; 4] = [0x7B, 0x68, 0xEE, 0xFF];
+
+/// CSS `mediumspringgreen` (`#00FA9A`).
+pub const MEDIUM_SPRING_GREEN: [u8; 4] = [0x00, 0xFA, 0x9A, 0xFF];
+
+/// CSS `mediumturquoise` (`#48D1CC`).
+pub const MEDIUM_TURQUOISE: [u8; 4] = [0x48, 0xD1, 0xCC, 0xFF];
+
+/// CSS `mediumvioletred` (`#C71585`).
+pub const MEDIUM_VIOLET_RED: [u8; 4] = [0xC7, 0x15, 0x85, 0xFF];
+
+/// CSS `midnightblue` (`#191970`).
+pub const MIDNIGHT_BLUE: [u8; 4] = [0x19, 0x19, 0x70, 0xFF];
+
+/// CSS `mintcream` (`#F5FFFA`).
+pub const MINT_CREAM: [u8; 4] = [0xF5, 0xFF, 0xFA, 0xFF];
+
+/// CSS `mistyrose` (`#FFE4E1`).
+pub const MISTY_ROSE: [u8; 4] = [0xFF, 0xE4, 0xE1, 0xFF];
+
+/// CSS `moccasin` (`#FFE4B5`).
+pub const MOCCASIN: [u8; 4] = [0xFF, 0xE4, 0xB5, 0xFF];
+
+/// CSS `navajowhite` (`#FFDEAD`).
+pub const NAVAJO_WHITE: [u8; 4] = [0xFF, 0xDE, 0xAD, 0xFF];
+
+/// CSS `navy` (`#000080`).
+pub const NAVY: [u8; 4] = [0x00, 0x00, 0x80, 0xFF];
+
+/// CSS `oldlace` (`#FDF5E6`).
+pub const OLD_LACE: [u8; 4] = [0xFD, 0xF5, 0xE6, 0xFF];
+
+/// CSS `olive` (`#808000`).
+pub const OLIVE: [u8; 4] = [0x80, 0x80, 0x00, 0xFF];
+
+/// CSS `olivedrab` (`#6B8E23`).
+pub const OLIVE_DRAB: [u8; 4] = [0x6B, 0x8E, 0x23, 0xFF];
+
+/// CSS `orange` (`#FFA500`).
+pub const ORANGE: [u8; 4] = [0xFF, 0xA5, 0x00, 0xFF];
+
+/// CSS `orangered` (`#FF4500`).
+pub const ORANGE_RED: [u8; 4] = [0xFF, 0x45, 0x00, 0xFF];
+
+/// CSS `orchid` (`#DA70D6`).
+pub const ORCHID: [u8; 4] = [0xDA, 0x70, 0xD6, 0xFF];
+
+/// CSS `palegoldenrod` (`#EEE8AA`).
+pub const PALE_GOLDENROD: [u8; 4] = [0xEE, 0xE8, 0xAA, 0xFF];
+
+/// CSS `palegreen` (`#98FB98`).
+pub const PALE_GREEN: [u8; 4] = [0x98, 0xFB, 0x98, 0xFF];
+
+/// CSS `paleturquoise` (`#AFEEEE`).
+pub const PALE_TURQUOISE: [u8; 4] = [0xAF, 0xEE, 0xEE, 0xFF];
+
+/// CSS `palevioletred` (`#DB7093`).
+pub const PALE_VIOLET_RED: [u8; 4] = [0xDB, 0x70, 0x93, 0xFF];
+
+/// CSS `papayawhip` (`#FFEFD5`).
+pub const PAPAYA_WHIP: [u8; 4] = [0xFF, 0xEF, 0xD5, 0xFF];
+
+/// CSS `peachpuff` (`#FFDAB9`).
+pub const PEACH_PUFF: [u8; 4] = [0xFF, 0xDA, 0xB9, 0xFF];
+
+/// CSS `peru` (`#CD853F`).
+pub const PERU: [u8; 4] = [0xCD, 0x85, 0x3F, 0xFF];
+
+/// CSS `pink` (`#FFC0CB`).
+pub const PINK: [u8; 4] = [0xFF, 0xC0, 0xCB, 0xFF];
+
+/// CSS `plum` (`#DDA0DD`).
+pub const PLUM: [u8; 4] = [0xDD, 0xA0, 0xDD, 0xFF];
+
+/// CSS `powderblue` (`#B0E0E6`).
+pub const POWDER_BLUE: [u8; 4] = [0xB0, 0xE0, 0xE6, 0xFF];
+
+/// CSS `purple` (`#800080`).
+pub const PURPLE: [u8; 4] = [0x80, 0x00, 0x80, 0xFF];
+
+/// CSS `rebeccapurple` (`#663399`).
+pub const REBECCA_PURPLE: [u8; 4] = [0x66, 0x33, 0x99, 0xFF];
+
+/// CSS `red` (`#FF0000`).
+pub const RED: [u8; 4] = [0xFF, 0x00, 0x00, 0xFF];
+
+/// CSS `rosybrown` (`#BC8F8F`).
+pub const ROSY_BROWN: [u8; 4] = [0xBC, 0x8F, 0x8F, 0xFF];
+
+/// CSS `royalblue` (`#4169E1`).
+pub const ROYAL_BLUE: [u8; 4] = [0x41, 0x69, 0xE1, 0xFF];
+
+/// CSS `saddlebrown` (`#8B4513`).
+pub const SADDLE_BROWN: [u8; 4] = [0x8B, 0x45, 0x13, 0xFF];
+
+/// CSS `salmon` (`#FA8072`).
+pub const SALMON: [u8; 4] = [0xFA, 0x80, 0x72, 0xFF];
+
+/// CSS `sandybrown` (`#F4A460`).
+pub const SANDY_BROWN: [u8; 4] = [0xF4, 0xA4, 0x60, 0xFF];
+
+/// CSS `seagreen` (`#2E8B57`).
+pub const SEA_GREEN: [u8; 4] = [0x2E, 0x8B, 0x57, 0xFF];
+
+/// CSS `seashell` (`#FFF5EE`).
+pub const SEASHELL: [u8; 4] = [0xFF, 0xF5, 0xEE, 0xFF];
+
+/// CSS `sienna` (`#A0522D`).
+pub const SIENNA: [u8; 4] = [0xA0, 0x52, 0x2D, 0xFF];
+
+/// CSS `silver` (`#C0C0C0`).
+pub const SILVER: [u8; 4] = [0xC0, 0xC0, 0xC0, 0xFF];
+
+/// CSS `skyblue` (`#87CEEB`).
+pub const SKY_BLUE: [u8; 4] = [0x87, 0xCE, 0xEB, 0xFF];
+
+/// CSS `slateblue` (`#6A5ACD`).
+pub const SLATE_BLUE: [u8; 4] = [0x6A, 0x5A, 0xCD, 0xFF];
+
+/// CSS `slategray` (`#708090`).
+pub const SLATE_GRAY: [u8; 4] = [0x70, 0x80, 0x90, 0xFF];
+
+/// CSS `slategrey` (`#708090`).
+pub const SLATE_GREY: [u8; 4] = [0x70, 0x80, 0x90, 0xFF];
+
+/// CSS `snow` (`#FFFAFA`).
+pub const SNOW: [u8; 4] = [0xFF, 0xFA, 0xFA, 0xFF];
+
+/// CSS `springgreen` (`#00FF7F`).
+pub const SPRING_GREEN: [u8; 4] = [0x00, 0xFF, 0x7F, 0xFF];
+
+/// CSS `steelblue` (`#4682B4`).
+pub const STEEL_BLUE: [u8; 4] = [0x46, 0x82, 0xB4, 0xFF];
+
+/// CSS `tan` (`#D2B48C`).
+pub const TAN: [u8; 4] = [0xD2, 0xB4, 0x8C, 0xFF];
+
+/// CSS `teal` (`#008080`).
+pub const TEAL: [u8; 4] = [0x00, 0x80, 0x80, 0xFF];
+
+/// CSS `thistle` (`#D8BFD8`).
+pub const THISTLE: [u8; 4] = [0xD8, 0xBF, 0xD8, 0xFF];
+
+/// CSS `tomato` (`#FF6347`).
+pub const TOMATO: [u8; 4] = [0xFF, 0x63, 0x47, 0xFF];
+
+/// CSS `turquoise` (`#40E0D0`).
+pub const TURQUOISE: [u8; 4] = [0x40, 0xE0, 0xD0, 0xFF];
+
+/// CSS `violet` (`#EE82EE`).
+pub const VIOLET: [u8; 4] = [0xEE, 0x82, 0xEE, 0xFF];
+
+/// CSS `wheat` (`#F5DEB3`).
+pub const WHEAT: [u8; 4] = [0xF5, 0xDE, 0xB3, 0xFF];
+
+/// CSS `white` (`#FFFFFF`).
+pub const WHITE: [u8; 4] = [0xFF, 0xFF, 0xFF, 0xFF];
+
+/// CSS `whitesmoke` (`#F5F5F5`).
+pub const WHITE_SMOKE: [u8; 4] = [0xF5, 0xF5, 0xF5, 0xFF];
+
+/// CSS `yellow` (`#FFFF00`).
+pub const YELLOW: [u8; 4] = [0xFF, 0xFF, 0x00, 0xFF];
+
+/// CSS `yellowgreen` (`#9ACD32`).
+pub const YELLOW_GREEN: [u8; 4] = [0x9A, 0xCD, 0x32, 0xFF];
+
+/// Every named color, by its lowercase CSS name.
+pub(super) const NAMED: &[(&str, [u8; 4])] = &[
+    ("transparent", TRANSPARENT),
+    ("aliceblue", ALICE_BLUE),
+    ("antiquewhite", ANTIQUE_WHITE),
+    ("aqua", AQUA),
+    ("aquamarine", AQUAMARINE),
+    ("azure", AZURE),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHED_ALMOND),
+    ("blue", BLUE),
+    ("blueviolet", BLUE_VIOLET),
+    ("brown", BROWN),
+    ("burlywood", BURLYWOOD),
+    ("cadetblue", CADET_BLUE),
+    ("chartreuse", CHARTREUSE),
+    ("chocolate", CHOCOLATE),
+    ("coral", CORAL),
+    ("cornflowerblue", CORNFLOWER_BLUE),
+    ("cornsilk", CORNSILK),
+    ("crimson", CRIMSON),
+    ("cyan", CYAN),
+    ("darkblue", DARK_BLUE),
+    ("darkcyan", DARK_CYAN),
+    ("darkgoldenrod", DARK_GOLDENROD),
+    ("darkgray", DARK_GRAY),
+    ("darkgreen", DARK_GREEN),
+    ("darkgrey", DARK_GREY),
+    ("darkkhaki", DARK_KHAKI),
+    ("darkmagenta", DARK_MAGENTA),
+    ("darkolivegreen", DARK_OLIVE_GREEN),
+    ("darkorange", DARK_ORANGE),
+    ("darkorchid", DARK_ORCHID),
+    ("darkred", DARK_RED),
+    ("darksalmon", DARK_SALMON),
+    ("darkseagreen", DARK_SEA_GREEN),
+    ("darkslateblue", DARK_SLATE_BLUE),
+    ("darkslategray", DARK_SLATE_GRAY),
+    ("darkslategrey", DARK_SLATE_GREY),
+    ("darkturquoise", DARK_TURQUOISE),
+    ("darkviolet", DARK_VIOLET),
+    ("deeppink", DEEP_PINK),
+    ("deepskyblue", DEEP_SKY_BLUE),
+    ("dimgray", DIM_GRAY),
+    ("dimgrey", DIM_GREY),
+    ("dodgerblue", DODGER_BLUE),
+    ("firebrick", FIREBRICK),
+    ("floralwhite", FLORAL_WHITE),
+    ("forestgreen", FOREST_GREEN),
+    ("fuchsia", FUCHSIA),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOST_WHITE),
+    ("gold", GOLD),
+    ("goldenrod", GOLDENROD),
+    ("gray", GRAY),
+    ("green", GREEN),
+    ("greenyellow", GREEN_YELLOW),
+    ("grey", GREY),
+    ("honeydew", HONEYDEW),
+    ("hotpink", HOT_PINK),
+    ("indianred", INDIAN_RED),
+    ("indigo", INDIGO),
+    ("ivory", IVORY),
+    ("khaki", KHAKI),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDER_BLUSH),
+    ("lawngreen", LAWN_GREEN),
+    ("lemonchiffon", LEMON_CHIFFON),
+    ("lightblue", LIGHT_BLUE),
+    ("lightcoral", LIGHT_CORAL),
+    ("lightcyan", LIGHT_CYAN),
+    ("lightgoldenrodyellow", LIGHT_GOLDENROD_YELLOW),
+    ("lightgray", LIGHT_GRAY),
+    ("lightgreen", LIGHT_GREEN),
+    ("lightgrey", LIGHT_GREY),
+    ("lightpink", LIGHT_PINK),
+    ("lightsalmon", LIGHT_SALMON),
+    ("lightseagreen", LIGHT_SEA_GREEN),
+    ("lightskyblue", LIGHT_SKY_BLUE),
+    ("lightslategray", LIGHT_SLATE_GRAY),
+    ("lightslategrey", LIGHT_SLATE_GREY),
+    ("lightsteelblue", LIGHT_STEEL_BLUE),
+    ("lightyellow", LIGHT_YELLOW),
+    ("lime", LIME),
+    ("limegreen", LIME_GREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("maroon", MAROON),
+    ("mediumaquamarine", MEDIUM_AQUAMARINE),
+    ("mediumblue", MEDIUM_BLUE),
+    ("mediumorchid", MEDIUM_ORCHID),
+    ("mediumpurple", MEDIUM_PURPLE),
+    ("mediumseagreen", MEDIUM_SEA_GREEN),
+    ("mediumslateblue", MEDIUM_SLATE_BLUE),
+    ("mediumspringgreen", MEDIUM_SPRING_GREEN),
+    ("mediumturquoise", MEDIUM_TURQUOISE),
+    ("mediumvioletred", MEDIUM_VIOLET_RED),
+    ("midnightblue", MIDNIGHT_BLUE),
+    ("mintcream", MINT_CREAM),
+    ("mistyrose", MISTY_ROSE),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJO_WHITE),
+    ("navy", NAVY),
+    ("oldlace", OLD_LACE),
+    ("olive", OLIVE),
+    ("olivedrab", OLIVE_DRAB),
+    ("orange", ORANGE),
+    ("orangered", ORANGE_RED),
+    ("orchid", ORCHID),
+    ("palegoldenrod", PALE_GOLDENROD),
+    ("palegreen", PALE_GREEN),
+    ("paleturquoise", PALE_TURQUOISE),
+    ("palevioletred", PALE_VIOLET_RED),
+    ("papayawhip", PAPAYA_WHIP),
+    ("peachpuff", PEACH_PUFF),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("plum", PLUM),
+    ("powderblue", POWDER_BLUE),
+    ("purple", PURPLE),
+    ("rebeccapurple", REBECCA_PURPLE),
+    ("red", RED),
+    ("rosybrown", ROSY_BROWN),
+    ("royalblue", ROYAL_BLUE),
+    ("saddlebrown", SADDLE_BROWN),
+    ("salmon", SALMON),
+    ("sandybrown", SANDY_BROWN),
+    ("seagreen", SEA_GREEN),
+    ("seashell", SEASHELL),
+    ("sienna", SIENNA),
+    ("silver", SILVER),
+    ("skyblue", SKY_BLUE),
+    ("slateblue", SLATE_BLUE),
+    ("slategray", SLATE_GRAY),
+    ("slategrey", SLATE_GREY),
+    ("snow", SNOW),
+    ("springgreen", SPRING_GREEN),
+    ("steelblue", STEEL_BLUE),
+    ("tan", TAN),
+    ("teal", TEAL),
+    ("thistle", THISTLE),
+    ("tomato", TOMATO),
+    ("turquoise", TURQUOISE),
+    ("violet", VIOLET),
+    ("wheat", WHEAT),
+    ("white", WHITE),
+    ("whitesmoke", WHITE_SMOKE),
+    ("yellow", YELLOW),
+    ("yellowgreen", YELLOW_GREEN),
+];
@@ -0,0 +1,516 @@
+//! Conversions between pixel formats.
+//!
+//! [`From`] cannot be implemented generically between two pixels of the same family (it would
+//! conflict with the blanket `impl<T> From<T> for T`), so cross-format conversions are provided by
+//! [`ConvertFrom`] and [`ConvertInto`] instead, and most conveniently used through
+//! [`Pixel::convert`]:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::rgba::{Abgr8888, FloatRgba, Rgba8888},
+//!     pixel::Pixel,
+//! };
+//!
+//! let pixel = Pixel::<Rgba8888>::with_rgba(0xFF, 0x80, 0x00, 0xFF);
+//!
+//! // Channels are matched by name, regardless of where the format stores them.
+//! let abgr = pixel.convert::<Abgr8888>();
+//! assert_eq!((abgr.red(), abgr.green(), abgr.blue()), (0xFF, 0x80, 0x00));
+//!
+//! // Channel depths are rescaled through the normalized `0.0..=1.0` range.
+//! let float = pixel.convert::<FloatRgba>();
+//! assert_eq!(float.red(), 1.0);
+//! assert_eq!(float.convert::<Rgba8888>(), pixel);
+//! ```
+//!
+//! Any two RGBA formats with [`Normalized`] channels can be converted between each other; integer
+//! channels are rounded to the nearest value, and out-of-range floating point channels are clamped.
+//!
+//! Whole slices of pixels can be converted with [`convert_slice`], or, when both formats have the
+//! same size, without a second buffer using [`convert_slice_in_place`]. Iterators of pixels can be
+//! converted lazily with [`ConvertIterExt::map_format`].
+//!
+//! Formats that only reorder the bytes of a [`U32x8888`] can be converted with
+//! [`Pixel::reorder`] instead, which compiles to a single byte swap or rotation where possible.
+
+use core::{fmt::Debug, iter::FusedIterator, marker::PhantomData, mem};
+
+use crate::{
+    formats::rgba::RgbaFormat,
+    norm::Normalized,
+    pixel::{
+        Format, Pixel,
+        raw::{RawPixel, U32x8888},
+    },
+};
+
+/// A value that can be created by converting a value of type `T`.
+///
+/// This is the counterpart of [`From`] for conversions between pixel formats; prefer implementing
+/// this trait, as [`ConvertInto`] is implemented automatically.
+pub trait ConvertFrom<T>: Sized {
+    /// Converts `value` to this type.
+    #[must_use]
+    fn convert_from(value: T) -> Self;
+}
+
+/// A value that can be converted into a value of type `T`.
+///
+/// This is the counterpart of [`Into`] for conversions between pixel formats, and is implemented
+/// for every type where `T` implements [`ConvertFrom`].
+pub trait ConvertInto<T> {
+    /// Converts this value to `T`.
+    #[must_use]
+    fn convert_into(self) -> T;
+}
+
+impl<T, U: ConvertFrom<T>> ConvertInto<U> for T {
+    fn convert_into(self) -> U {
+        U::convert_from(self)
+    }
+}
+
+impl<S, T> ConvertFrom<Pixel<S>> for Pixel<T>
+where
+    S: RgbaFormat,
+    S::RawPixel: RawPixel<Channel: Normalized>,
+    T: RgbaFormat,
+    T::RawPixel: RawPixel<Channel: Normalized>,
+{
+    fn convert_from(value: Pixel<S>) -> Self {
+        let convert = |channel: <S::RawPixel as RawPixel>::Channel| {
+            <T::RawPixel as RawPixel>::Channel::from_f32(channel.to_f32())
+        };
+        Pixel::with_rgba(
+            convert(value.red()),
+            convert(value.green()),
+            convert(value.blue()),
+            convert(value.alpha()),
+        )
+    }
+}
+
+impl<F: Format> Pixel<F> {
+    /// Converts the pixel to another format.
+    ///
+    /// See the [`convert`](crate::convert) module for which conversions are available.
+    #[must_use]
+    pub fn convert<T: Format>(self) -> Pixel<T>
+    where
+        Pixel<T>: ConvertFrom<Self>,
+    {
+        Pixel::convert_from(self)
+    }
+}
+
+/// How the bytes of a [`U32x8888`] move when reordering between two formats.
+#[derive(Copy, Clone)]
+enum Reorder {
+    Identity,
+    SwapBytes,
+    RotateRight(u32),
+    Shuffle([u32; 4]),
+}
+
+impl Reorder {
+    /// Returns the reordering from channels at `source` offsets to channels at `target` offsets.
+    #[allow(clippy::cast_possible_truncation)]
+    const fn between(source: [usize; 4], target: [usize; 4]) -> Self {
+        // The source byte of each target byte.
+        let mut from = [0; 4];
+        let mut i = 0;
+        while i < 4 {
+            from[target[i]] = source[i] as u32;
+            i += 1;
+        }
+
+        let mut rotation = true;
+        let mut swap = true;
+        let mut i = 0;
+        while i < 4 {
+            rotation &= from[i] == (i as u32 + from[0]) % 4;
+            swap &= from[i] == 3 - i as u32;
+            i += 1;
+        }
+        match (rotation, swap, from[0]) {
+            (true, _, 0) => Self::Identity,
+            (true, _, bytes) => Self::RotateRight(bytes * 8),
+            (_, true, _) => Self::SwapBytes,
+            _ => Self::Shuffle(from),
+        }
+    }
+
+    /// Applies the reordering to a value.
+    #[inline]
+    const fn apply(self, value: u32) -> u32 {
+        match self {
+            Self::Identity => value,
+            Self::SwapBytes => value.swap_bytes(),
+            Self::RotateRight(bits) => value.rotate_right(bits),
+            Self::Shuffle(from) => {
+                (value >> (from[0] * 8) & 0xFF)
+                    | (value >> (from[1] * 8) & 0xFF) << 8
+                    | (value >> (from[2] * 8) & 0xFF) << 16
+                    | (value >> (from[3] * 8) & 0xFF) << 24
+            }
+        }
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat<RawPixel = U32x8888>,
+{
+    /// Converts the pixel to another format with the same [`U32x8888`] storage.
+    ///
+    /// This is equivalent to [`convert`](Pixel::convert), but the reordering is computed from the
+    /// channel offsets of both formats at compile time, so that it is a single
+    /// [`swap_bytes`](u32::swap_bytes) or [`rotate_right`](u32::rotate_right) when the formats are
+    /// reversed or rotated, and nothing at all when they share a layout.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{
+    ///     formats::rgba::{Rgba8888, Swizzled},
+    ///     pixel::{Pixel, raw::RawPixel},
+    /// };
+    ///
+    /// type Abgr = Swizzled<Rgba8888, 3, 2, 1, 0>;
+    ///
+    /// let pixel = Pixel::<Rgba8888>::with_rgba(0x11, 0x22, 0x33, 0x44);
+    /// let abgr = pixel.reorder::<Abgr>();
+    /// assert_eq!(abgr.as_raw().into_inner(), pixel.as_raw().into_inner().swap_bytes());
+    /// assert_eq!(abgr, pixel.convert());
+    /// ```
+    #[must_use]
+    pub fn reorder<T: RgbaFormat<RawPixel = U32x8888>>(self) -> Pixel<T> {
+        let reorder = const {
+            Reorder::between(
+                [
+                    F::RED_OFFSET,
+                    F::GREEN_OFFSET,
+                    F::BLUE_OFFSET,
+                    F::ALPHA_OFFSET,
+                ],
+                [
+                    T::RED_OFFSET,
+                    T::GREEN_OFFSET,
+                    T::BLUE_OFFSET,
+                    T::ALPHA_OFFSET,
+                ],
+            )
+        };
+        Pixel::from_raw(U32x8888::from_u32(
+            reorder.apply(self.into_raw().into_inner()),
+        ))
+    }
+}
+
+/// Converts every pixel in `src` to the format of `dst`.
+///
+/// ## Panics
+///
+/// If the slices have different lengths.
+pub fn convert_slice<S: Format, T: Format>(src: &[Pixel<S>], dst: &mut [Pixel<T>])
+where
+    Pixel<T>: ConvertFrom<Pixel<S>>,
+{
+    assert_eq!(src.len(), dst.len(), "Slices must have the same length");
+    for (dst, src) in dst.iter_mut().zip(src) {
+        *dst = Pixel::convert_from(*src);
+    }
+}
+
+/// An extension trait for iterators of pixels, converting each pixel lazily.
+///
+/// This is implemented for every iterator, and is in the [prelude](crate::prelude).
+pub trait ConvertIterExt: Iterator + Sized {
+    /// Returns an iterator that converts each pixel to the format `T` as it is yielded.
+    ///
+    /// Unlike [`convert_slice`], no intermediate buffer is needed, so pixels can be streamed
+    /// from one format to another.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{
+    ///     convert::ConvertIterExt,
+    ///     formats::rgba::{FloatRgba, Rgba8888},
+    ///     pixel::Pixel,
+    /// };
+    ///
+    /// let pixels = [Pixel::<Rgba8888>::with_rgba(0xFF, 0x00, 0x00, 0xFF); 4];
+    /// let mut floats = pixels.into_iter().map_format::<FloatRgba>();
+    /// assert_eq!(floats.len(), 4);
+    /// assert_eq!(floats.next().map(|p| p.red()), Some(1.0));
+    /// ```
+    fn map_format<T: Format>(self) -> ConvertIter<Self, T>
+    where
+        Pixel<T>: ConvertFrom<Self::Item>,
+    {
+        ConvertIter {
+            iter: self,
+            format: PhantomData,
+        }
+    }
+}
+
+impl<I: Iterator> ConvertIterExt for I {}
+
+/// An iterator that converts each pixel of another iterator to the format `T`.
+///
+/// Created by [`ConvertIterExt::map_format`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ConvertIter<I, T: Format> {
+    iter: I,
+    format: PhantomData<T>,
+}
+
+impl<I, T> Iterator for ConvertIter<I, T>
+where
+    I: Iterator,
+    T: Format,
+    Pixel<T>: ConvertFrom<I::Item>,
+{
+    type Item = Pixel<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Pixel::convert_from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> DoubleEndedIterator for ConvertIter<I, T>
+where
+    I: DoubleEndedIterator,
+    T: Format,
+    Pixel<T>: ConvertFrom<I::Item>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Pixel::convert_from)
+    }
+}
+
+impl<I, T> ExactSizeIterator for ConvertIter<I, T>
+where
+    I: ExactSizeIterator,
+    T: Format,
+    Pixel<T>: ConvertFrom<I::Item>,
+{
+}
+
+impl<I, T> FusedIterator for ConvertIter<I, T>
+where
+    I: FusedIterator,
+    T: Format,
+    Pixel<T>: ConvertFrom<I::Item>,
+{
+}
+
+impl<I: Clone, T: Format> Clone for ConvertIter<I, T> {
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            format: PhantomData,
+        }
+    }
+}
+
+impl<I: Debug, T: Format> Debug for ConvertIter<I, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConvertIter")
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A guard that panics when dropped; dropping it while already unwinding aborts.
+struct AbortOnUnwind;
+
+impl Drop for AbortOnUnwind {
+    fn drop(&mut self) {
+        panic!("Pixel conversion panicked while converting in place");
+    }
+}
+
+/// Converts every pixel in `pixels` to the format `T`, reusing the same memory.
+///
+/// Both formats must have the same size, and `T` must not require a stricter alignment than `S`;
+/// otherwise this function fails to compile:
+///
+/// ```rust,compile_fail
+/// use pxlfmt::{convert::convert_slice_in_place, formats::rgba::{FloatRgba, Rgba8888}, pixel::Pixel};
+///
+/// let mut pixels = [Pixel::<Rgba8888>::zeroed(); 4];
+/// let _ = convert_slice_in_place::<_, FloatRgba>(&mut pixels);
+/// ```
+///
+/// If converting a pixel panics, the process is aborted, as the slice would otherwise be left
+/// holding a mix of both formats.
+///
+/// ## Example
+///
+/// ```rust
+/// use pxlfmt::{
+///     convert::convert_slice_in_place,
+///     formats::rgba::{Abgr8888, Rgba8888, Swizzled},
+///     pixel::Pixel,
+/// };
+///
+/// let mut pixels = [Pixel::<Rgba8888>::with_rgba(0x11, 0x22, 0x33, 0x44); 2];
+/// let bgra = convert_slice_in_place::<_, Swizzled<Rgba8888, 2, 1, 0, 3>>(&mut pixels);
+/// assert_eq!(bgra[1].red(), 0x11);
+/// assert_eq!(pixels[1].red(), 0x33);
+/// ```
+pub fn convert_slice_in_place<S: Format, T: Format>(pixels: &mut [Pixel<S>]) -> &mut [Pixel<T>]
+where
+    Pixel<T>: ConvertFrom<Pixel<S>>,
+{
+    const {
+        assert!(
+            mem::size_of::<Pixel<S>>() == mem::size_of::<Pixel<T>>(),
+            "Formats must have the same size"
+        );
+        assert!(
+            mem::align_of::<Pixel<T>>() <= mem::align_of::<Pixel<S>>(),
+            "Target format must not have a stricter alignment"
+        );
+    }
+
+    let guard = AbortOnUnwind;
+    let len = pixels.len();
+    let src = pixels.as_mut_ptr();
+    let dst = src.cast::<Pixel<T>>();
+    for i in 0..len {
+        // SAFETY: Both formats have the same size and a compatible alignment, so pixel `i` of either
+        // format occupies the same bytes. Pixel `i` is read as `S` before being overwritten as `T`.
+        unsafe { dst.add(i).write(Pixel::convert_from(src.add(i).read())) };
+    }
+    mem::forget(guard);
+
+    // SAFETY: Every pixel has been overwritten with a value of `T`, and the slice is borrowed
+    // mutably for the lifetime of the returned slice.
+    unsafe { core::slice::from_raw_parts_mut(dst, len) }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::formats::rgba::{Abgr8888, FloatRgba, Rgba8Snorm, Rgba8888, Swizzled};
+
+    use super::*;
+
+    #[test]
+    fn map_format() {
+        let pixels = [1, 2, 3].map(|c| Pixel::<Rgba8888>::with_rgba(c, 0, 0, 0xFF));
+        let mut iter = pixels.iter().copied().map_format::<Abgr8888>();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back().map(|p| p.red()), Some(3));
+        assert_eq!(
+            iter.map(|p| p.channels()).collect::<alloc::vec::Vec<_>>(),
+            [[1, 0, 0, 0xFF], [2, 0, 0, 0xFF]]
+        );
+    }
+
+    #[test]
+    fn reorders_channels() {
+        let pixel = Pixel::<Rgba8888>::with_rgba(1, 2, 3, 4);
+        let bgra = pixel.convert::<Swizzled<Rgba8888, 2, 1, 0, 3>>();
+        assert_eq!(bgra.as_raw(), &0x0401_0203.into());
+
+        let abgr: Pixel<Abgr8888> = pixel.convert_into();
+        assert_eq!(
+            (abgr.red(), abgr.green(), abgr.blue(), abgr.alpha()),
+            (1, 2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn reorder_matches_convert() {
+        fn check<T: RgbaFormat<RawPixel = U32x8888>>() {
+            for lane in 0..4 {
+                for value in 0..=u8::MAX {
+                    let mut channels = [0x01, 0x23, 0x45, 0x67];
+                    channels[lane] = value;
+                    let pixel = Pixel::<Rgba8888>::from_channels(channels);
+                    let fast = pixel.reorder::<T>().into_raw();
+                    assert_eq!(fast, pixel.convert::<T>().into_raw());
+                }
+            }
+        }
+
+        check::<Rgba8888>();
+        check::<Abgr8888>();
+        check::<Swizzled<Rgba8888, 3, 2, 1, 0>>();
+        check::<Swizzled<Rgba8888, 1, 2, 3, 0>>();
+        check::<Swizzled<Rgba8888, 2, 1, 0, 3>>();
+    }
+
+    #[test]
+    fn reorder_paths() {
+        let rgba = [0, 1, 2, 3];
+        assert!(matches!(Reorder::between(rgba, rgba), Reorder::Identity));
+        assert!(matches!(
+            Reorder::between(rgba, [3, 2, 1, 0]),
+            Reorder::SwapBytes
+        ));
+        assert!(matches!(
+            Reorder::between(rgba, [1, 2, 3, 0]),
+            Reorder::RotateRight(24)
+        ));
+        assert!(matches!(
+            Reorder::between(rgba, [2, 1, 0, 3]),
+            Reorder::Shuffle([2, 1, 0, 3])
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn rescales_depth() {
+        let pixel = Pixel::<FloatRgba>::with_rgba(0.5, -1.0, 2.0, 1.0);
+        let rgba = pixel.convert::<Rgba8888>();
+        assert_eq!((rgba.red(), rgba.green(), rgba.blue()), (0x80, 0x00, 0xFF));
+
+        let snorm = Pixel::<Rgba8888>::with_rgba(0x00, 0x80, 0xFF, 0xFF).convert::<Rgba8Snorm>();
+        assert_eq!((snorm.red(), snorm.green(), snorm.blue()), (0, 64, 127));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn slices() {
+        let src = [
+            Pixel::<Rgba8888>::with_rgba(0xFF, 0x00, 0x00, 0xFF),
+            Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0xFF, 0x00),
+        ];
+        let mut dst = [Pixel::<FloatRgba>::zeroed(); 2];
+        convert_slice(&src, &mut dst);
+        assert_eq!(dst[0].as_raw().into_inner(), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(dst[1].as_raw().into_inner(), [0.0, 0.0, 1.0, 0.0]);
+
+        let mut pixels = dst;
+        let swizzled = convert_slice_in_place::<_, Swizzled<FloatRgba, 3, 2, 1, 0>>(&mut pixels);
+        assert_eq!(swizzled[0].red(), 1.0);
+        assert_eq!(swizzled[0].as_raw().into_inner(), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(swizzled[1].as_raw().into_inner(), [0.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Slices must have the same length")]
+    fn convert_slice_length_mismatch() {
+        let mut dst = [Pixel::<Abgr8888>::zeroed(); 1];
+        convert_slice(&[Pixel::<Rgba8888>::zeroed(); 2], &mut dst);
+    }
+
+    #[test]
+    fn u8_round_trips_through_float() {
+        for value in 0..=u8::MAX {
+            let pixel = Pixel::<Abgr8888>::with_rgba(value, value, value, value);
+            assert_eq!(pixel.convert::<FloatRgba>().convert::<Abgr8888>(), pixel);
+        }
+    }
+}
@@ -0,0 +1,457 @@
+//! Dithering when converting to a lower channel depth.
+//!
+//! Converting pixels to a format with fewer bits per channel rounds each channel to the nearest
+//! representable value, which turns smooth gradients into visible bands. Dithering instead adds a
+//! small, position-dependent offset to each channel before rounding, so that the average of an
+//! area matches the original color.
+//!
+//! ## Ordered dithering
+//!
+//! [`ImageViewMut::convert_from_dithered`] converts an image using one of the [`OrderedDither`]
+//! (Bayer) matrices, keyed on the pixel coordinates:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     dither::OrderedDither,
+//!     formats::rgba::{FloatRgba, Rgba8888},
+//!     pixel::{Pixel, view::{ImageView, ImageViewMut}},
+//! };
+//!
+//! // A color a quarter of the way between two 8-bit values.
+//! let gray = 10.25 / 255.0;
+//! let src = [Pixel::<FloatRgba>::with_rgba(gray, gray, gray, 1.0); 16];
+//! let mut dst = [Pixel::<Rgba8888>::zeroed(); 16];
+//!
+//! let mut view = ImageViewMut::new(&mut dst, 4, 4).unwrap();
+//! view.convert_from_dithered(ImageView::new(&src, 4, 4).unwrap(), OrderedDither::Bayer4x4);
+//! assert_eq!(dst.iter().filter(|pixel| pixel.red() == 11).count(), 4);
+//! assert_eq!(dst.iter().filter(|pixel| pixel.red() == 10).count(), 12);
+//! ```
+//!
+//! Pixels can be dithered to any format that implements [`DitherTarget`]: every RGBA format with
+//! normalized channels, and formats with a lower depth per channel, such as [`Rgb565`].
+//!
+//! [`Rgb565`]: crate::formats::rgb::Rgb565
+//!
+//! ## Error diffusion
+//!
+//! With the `alloc` feature, [`PixelBuffer::convert_diffused`] and
+//! [`PixelBuffer::quantize_diffused`] instead use Floyd-Steinberg error diffusion, which carries
+//! the rounding error of each pixel over to its unvisited neighbors. This produces less regular
+//! patterns, and supports quantizing to the colors of a [`Palette`].
+//!
+//! [`PixelBuffer::convert_diffused`]: crate::pixel::buffer::PixelBuffer::convert_diffused
+//! [`PixelBuffer::quantize_diffused`]: crate::pixel::buffer::PixelBuffer::quantize_diffused
+//! [`Palette`]: crate::formats::indexed::Palette
+
+use crate::{
+    formats::{rgb::Rgb565, rgba::RgbaFormat},
+    norm::Normalized,
+    pixel::{
+        Format, Pixel,
+        raw::RawPixel,
+        view::{ImageView, ImageViewMut, assert_same_size},
+    },
+};
+
+/// A 4x4 ordered dither (Bayer) matrix, with thresholds in `0..16`.
+pub(crate) const BAYER_4X4: [[u8; 4]; 4] =
+    [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// An 8x8 ordered dither (Bayer) matrix, with thresholds in `0..64`.
+const BAYER_8X8: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+/// An ordered dither matrix, which repeats over the image.
+///
+/// Larger matrices resolve more intermediate shades, with a less visible pattern.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrderedDither {
+    /// A 4x4 Bayer matrix, with 16 thresholds.
+    Bayer4x4,
+
+    /// An 8x8 Bayer matrix, with 64 thresholds.
+    Bayer8x8,
+}
+
+impl OrderedDither {
+    /// Returns the offset added at the given pixel position, in `-0.5..0.5` steps of the target
+    /// channel.
+    ///
+    /// The offsets of every position of the matrix average to `0.0`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn offset(self, x: usize, y: usize) -> f32 {
+        let (threshold, levels) = match self {
+            Self::Bayer4x4 => (BAYER_4X4[y % 4][x % 4], 16.0),
+            Self::Bayer8x8 => (BAYER_8X8[y % 8][x % 8], 64.0),
+        };
+        (f32::from(threshold) + 0.5) / levels - 0.5
+    }
+
+    /// Converts a pixel to another format, dithered as if at the given pixel position.
+    #[must_use]
+    pub fn convert<S, T>(self, pixel: Pixel<S>, x: usize, y: usize) -> Pixel<T>
+    where
+        S: RgbaFormat,
+        S::RawPixel: RawPixel<Channel: Normalized>,
+        T: DitherTarget,
+    {
+        T::from_dithered(pixel.channels().map(Normalized::to_f32), self.offset(x, y))
+    }
+}
+
+/// A format that pixels can be converted to with a dither.
+///
+/// This is implemented for every RGBA format with [`Normalized`] channels, and for [`Rgb565`].
+pub trait DitherTarget: Format {
+    /// Converts normalized RGBA channels to a pixel of this format, after adding `offset` times the
+    /// step between two adjacent values to each channel.
+    fn from_dithered(rgba: [f32; 4], offset: f32) -> Pixel<Self>;
+}
+
+impl<T> DitherTarget for T
+where
+    T: RgbaFormat,
+    T::RawPixel: RawPixel<Channel: Normalized>,
+{
+    fn from_dithered(rgba: [f32; 4], offset: f32) -> Pixel<Self> {
+        type Channel<F> = <<F as Format>::RawPixel as RawPixel>::Channel;
+        let offset = offset * Channel::<T>::STEP;
+        let [red, green, blue, alpha] =
+            rgba.map(|channel| Channel::<T>::from_f32(channel + offset));
+        Pixel::with_rgba(red, green, blue, alpha)
+    }
+}
+
+impl DitherTarget for Rgb565 {
+    fn from_dithered([red, green, blue, _]: [f32; 4], offset: f32) -> Pixel<Self> {
+        let red = red + offset / f32::from(Self::MAX_RB);
+        let green = green + offset / f32::from(Self::MAX_G);
+        let blue = blue + offset / f32::from(Self::MAX_RB);
+        Pixel::with_rgb_normalized(red, green, blue)
+    }
+}
+
+impl<F: DitherTarget> ImageViewMut<'_, F> {
+    /// Converts the pixels of `src` into this view, with an ordered dither.
+    ///
+    /// This is the dithered counterpart of [`convert_from`](ImageViewMut::convert_from); the
+    /// matrix is keyed on the coordinates within the views.
+    ///
+    /// ## Panics
+    ///
+    /// If the views have different sizes.
+    pub fn convert_from_dithered<S>(&mut self, src: ImageView<'_, S>, dither: OrderedDither)
+    where
+        S: RgbaFormat,
+        S::RawPixel: RawPixel<Channel: Normalized>,
+    {
+        assert_same_size((self.width(), self.height()), (src.width(), src.height()));
+        for y in 0..self.height() {
+            for (x, (dst, src)) in self.row_mut(y).iter_mut().zip(src.row(y)).enumerate() {
+                *dst = dither.convert(*src, x, y);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod diffusion {
+    use alloc::vec;
+    use core::mem;
+
+    use crate::{
+        formats::{
+            indexed::{ColorMetric, Indexed8, Palette},
+            rgba::RgbaFormat,
+        },
+        norm::Normalized,
+        pixel::{
+            Format, Pixel,
+            buffer::PixelBuffer,
+            raw::RawPixel,
+            view::{ImageView, ImageViewMut},
+        },
+    };
+
+    impl<F> PixelBuffer<F>
+    where
+        F: RgbaFormat,
+        F::RawPixel: RawPixel<Channel: Normalized>,
+    {
+        /// Converts the buffer to another format, with Floyd-Steinberg error diffusion.
+        ///
+        /// This is the dithered counterpart of converting every pixel; conversions to floating
+        /// point formats have no rounding error, and are not dithered.
+        ///
+        /// ## Example
+        ///
+        /// ```rust
+        /// use pxlfmt::{
+        ///     formats::rgba::{FloatRgba, Rgba8888},
+        ///     pixel::{Pixel, buffer::PixelBuffer},
+        /// };
+        ///
+        /// let gray = 10.5 / 255.0;
+        /// let buffer = PixelBuffer::filled(2, 1, Pixel::<FloatRgba>::with_rgba(gray, gray, gray, 1.0));
+        /// let dithered = buffer.convert_diffused::<Rgba8888>();
+        /// assert_eq!(dithered[(0, 0)].red() + dithered[(1, 0)].red(), 21);
+        /// ```
+        #[must_use]
+        pub fn convert_diffused<T>(&self) -> PixelBuffer<T>
+        where
+            T: RgbaFormat,
+            T::RawPixel: RawPixel<Channel: Normalized>,
+        {
+            let mut dst = PixelBuffer::new(self.width(), self.height());
+            diffuse(self.as_view(), &mut dst.as_view_mut(), |wanted| {
+                let [red, green, blue, alpha] = wanted.map(Normalized::from_f32);
+                let pixel = Pixel::<T>::with_rgba(red, green, blue, alpha);
+                (pixel, pixel.channels().map(Normalized::to_f32))
+            });
+            dst
+        }
+
+        /// Quantizes the buffer to the colors of `palette`, with Floyd-Steinberg error diffusion.
+        ///
+        /// Each pixel is replaced by the index of the palette color nearest to it (by squared
+        /// distance of the red, green, and blue channels), after adding the error diffused from
+        /// its neighbors.
+        ///
+        /// ## Panics
+        ///
+        /// If the palette is empty.
+        ///
+        /// ## Example
+        ///
+        /// ```rust
+        /// use pxlfmt::{
+        ///     formats::{indexed::Palette, rgba::Rgba8888},
+        ///     pixel::{Pixel, buffer::PixelBuffer},
+        /// };
+        ///
+        /// let palette = Palette::from_slice(&[
+        ///     Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0x00, 0xFF),
+        ///     Pixel::<Rgba8888>::with_rgba(0xFF, 0xFF, 0xFF, 0xFF),
+        /// ]);
+        /// let gray = PixelBuffer::filled(4, 1, Pixel::<Rgba8888>::with_rgba(0x80, 0x80, 0x80, 0xFF));
+        /// let indexed = gray.quantize_diffused(&palette);
+        /// assert_eq!(indexed.as_slice().iter().filter(|pixel| pixel.index() == 1).count(), 2);
+        /// ```
+        #[must_use]
+        pub fn quantize_diffused<P>(&self, palette: &Palette<P>) -> PixelBuffer<Indexed8>
+        where
+            P: RgbaFormat,
+            P::RawPixel: RawPixel<Channel: Normalized>,
+        {
+            assert!(!palette.is_empty(), "Palette is empty");
+            let mut dst = PixelBuffer::new(self.width(), self.height());
+            diffuse(
+                self.as_view(),
+                &mut dst.as_view_mut(),
+                |[red, green, blue, alpha]| {
+                    let (index, color) = palette
+                        .nearest([red, green, blue], ColorMetric::Rgb)
+                        .unwrap_or_default();
+                    let [red, green, blue, _] = color.channels().map(Normalized::to_f32);
+                    (Pixel::with_index(index), [red, green, blue, alpha])
+                },
+            );
+            dst
+        }
+    }
+
+    /// Converts every pixel of `src` into `dst` with `quantize`, diffusing the error between the
+    /// wanted and the quantized channels (as returned by `quantize`) with the Floyd-Steinberg
+    /// kernel.
+    fn diffuse<S, T>(
+        src: ImageView<'_, S>,
+        dst: &mut ImageViewMut<'_, T>,
+        mut quantize: impl FnMut([f32; 4]) -> (Pixel<T>, [f32; 4]),
+    ) where
+        S: RgbaFormat,
+        S::RawPixel: RawPixel<Channel: Normalized>,
+        T: Format,
+    {
+        // Errors carried into the current and next row, with a column of padding on either side.
+        let mut current = vec![[0.0; 4]; src.width() + 2];
+        let mut next = current.clone();
+        for y in 0..src.height() {
+            for (x, (dst, src)) in dst.row_mut(y).iter_mut().zip(src.row(y)).enumerate() {
+                let mut wanted = src.channels().map(Normalized::to_f32);
+                for (channel, error) in wanted.iter_mut().zip(current[x + 1]) {
+                    *channel += error;
+                }
+                let (pixel, actual) = quantize(wanted);
+                *dst = pixel;
+
+                let spread = |errors: &mut [f32; 4], weight: f32| {
+                    for ((error, wanted), actual) in errors.iter_mut().zip(wanted).zip(actual) {
+                        // Non-finite channels (e.g. infinite HDR values) would poison the row.
+                        let delta = wanted - actual;
+                        if delta.is_finite() {
+                            *error += delta * weight;
+                        }
+                    }
+                };
+                spread(&mut current[x + 2], 7.0 / 16.0);
+                spread(&mut next[x], 3.0 / 16.0);
+                spread(&mut next[x + 1], 5.0 / 16.0);
+                spread(&mut next[x + 2], 1.0 / 16.0);
+            }
+            mem::swap(&mut current, &mut next);
+            next.fill([0.0; 4]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{FloatRgba, Rgba8888, Rgba16161616};
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn offsets_average_to_zero() {
+        for (dither, size) in [(OrderedDither::Bayer4x4, 4), (OrderedDither::Bayer8x8, 8)] {
+            let mut sum = 0.0;
+            for y in 0..size {
+                for x in 0..size {
+                    let offset = dither.offset(x, y);
+                    assert!((-0.5..0.5).contains(&offset));
+                    sum += offset;
+                }
+            }
+            assert_eq!(sum, 0.0);
+            assert_eq!(dither.offset(1, 2), dither.offset(1 + size, 2 + size));
+        }
+    }
+
+    #[test]
+    fn exact_values_are_preserved() {
+        let pixel = Pixel::<Rgba16161616>::with_rgba(0x0000, 0x8080, 0xFFFF, 0x4040);
+        for y in 0..8 {
+            for x in 0..8 {
+                let dithered = OrderedDither::Bayer8x8.convert::<_, Rgba8888>(pixel, x, y);
+                assert_eq!(dithered, Pixel::with_rgba(0x00, 0x80, 0xFF, 0x40));
+            }
+        }
+    }
+
+    #[test]
+    fn float_targets_are_not_dithered() {
+        let pixel = Pixel::<Rgba8888>::with_rgba(0x10, 0x20, 0x30, 0x40);
+        let dithered = OrderedDither::Bayer4x4.convert::<_, FloatRgba>(pixel, 3, 1);
+        assert_eq!(dithered, pixel.convert());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn convert_diffused_preserves_average() {
+        use crate::pixel::buffer::PixelBuffer;
+
+        let gray = 10.25 / 255.0;
+        let buffer = PixelBuffer::filled(8, 8, Pixel::<FloatRgba>::with_rgba(gray, 0.0, 1.0, 1.0));
+        let dithered = buffer.convert_diffused::<Rgba8888>();
+        let sum: u32 = dithered.as_slice().iter().map(|p| u32::from(p.red())).sum();
+        // Ideally 656, but error diffused past the edges of the buffer is lost.
+        assert!((650..=662).contains(&sum), "sum of red channels was {sum}");
+        assert!(dithered.as_slice().iter().all(|p| p.blue() == 0xFF));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn quantize_diffused_exact_colors() {
+        use alloc::vec;
+
+        use crate::{
+            formats::indexed::{Indexed8, Palette},
+            pixel::buffer::PixelBuffer,
+        };
+
+        let red = Pixel::<Rgba8888>::with_rgba(0xFF, 0x00, 0x00, 0xFF);
+        let blue = Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0xFF, 0xFF);
+        let palette = Palette::from_slice(&[red, blue]);
+        let buffer = PixelBuffer::from_vec(3, 1, vec![blue, red, blue]).unwrap();
+        let indexed = buffer.quantize_diffused(&palette);
+        assert_eq!(
+            indexed.into_vec(),
+            [1, 0, 1].map(Pixel::<Indexed8>::with_index)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic = "Palette is empty"]
+    fn quantize_diffused_empty_palette() {
+        use crate::{formats::indexed::Palette, pixel::buffer::PixelBuffer};
+
+        let buffer = PixelBuffer::<Rgba8888>::new(1, 1);
+        let _ = buffer.quantize_diffused(&Palette::<Rgba8888>::new());
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn reduces_banding_in_rgb565() {
+        use crate::formats::rgb::Rgb565;
+
+        // A shallow gradient, which only spans a few values of the 5-bit red channel.
+        const WIDTH: usize = 64;
+        const HEIGHT: usize = 8;
+        let (width, height) = (WIDTH, HEIGHT);
+        let src: [_; WIDTH * HEIGHT] = core::array::from_fn(|i| {
+            let red = 0.25 + (i % WIDTH) as f32 / 640.0;
+            Pixel::<FloatRgba>::with_rgba(red, 0.5, 0.5, 1.0)
+        });
+        let src = ImageView::new(&src, width, height).unwrap();
+
+        let mut plain = [Pixel::<Rgb565>::zeroed(); WIDTH * HEIGHT];
+        ImageViewMut::new(&mut plain, width, height)
+            .unwrap()
+            .convert_from(src);
+        let mut dithered = plain;
+        ImageViewMut::new(&mut dithered, width, height)
+            .unwrap()
+            .convert_from_dithered(src, OrderedDither::Bayer8x8);
+
+        // The largest error of the average red of each 8x8 block against the source gradient.
+        let banding = |pixels: &[Pixel<Rgb565>]| {
+            (0..width)
+                .step_by(8)
+                .map(|left| {
+                    let (mut actual, mut wanted) = (0.0, 0.0);
+                    for y in 0..height {
+                        for x in left..left + 8 {
+                            actual += pixels[y * width + x].rgb_normalized()[0];
+                            wanted += src.get(x, y).unwrap().red();
+                        }
+                    }
+                    (actual - wanted).abs() / 64.0
+                })
+                .fold(0.0, f32::max)
+        };
+        let (plain, dithered) = (banding(&plain), banding(&dithered));
+        assert!(plain > 0.005, "undithered error was {plain}");
+        assert!(dithered < plain / 4.0, "dithered error was {dithered}");
+    }
+
+    #[test]
+    #[should_panic = "Views must have the same size"]
+    fn convert_from_dithered_wrong_size() {
+        let src = [Pixel::<FloatRgba>::zeroed(); 4];
+        let mut dst = [Pixel::<Rgba8888>::zeroed(); 4];
+        ImageViewMut::new(&mut dst, 4, 1)
+            .unwrap()
+            .convert_from_dithered(ImageView::new(&src, 2, 2).unwrap(), OrderedDither::Bayer4x4);
+    }
+}
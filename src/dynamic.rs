@@ -0,0 +1,468 @@
+//! Pixel formats chosen at runtime.
+//!
+//! Formats are normally type parameters, which is what makes [`Pixel<F>`] zero-cost. File loaders
+//! and other code that only discovers the format at runtime can instead use [`DynFormat`], an enum
+//! of every built-in format, along with [`AnyPixel`] and [`DynPixelSlice`], which hold a pixel or a
+//! slice of pixels of any of them.
+//!
+//! Each can be downcast back to its static type, or converted dynamically to [`FloatRgba`]:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     dynamic::{AnyPixel, DynFormat, DynPixelSlice},
+//!     formats::{gray::Gray8, rgba::Rgba8888},
+//!     pixel::Pixel,
+//! };
+//!
+//! let pixel = AnyPixel::from(Pixel::<Gray8>::with_gray(0xFF));
+//! assert_eq!(pixel.format(), DynFormat::Gray8);
+//! assert_eq!(pixel.format().name(), "Gray8");
+//! assert_eq!(pixel.downcast::<Gray8>(), Some(Pixel::with_gray(0xFF)));
+//! assert_eq!(pixel.downcast::<Rgba8888>(), None);
+//! assert_eq!(pixel.to_float_rgba().map(|pixel| pixel.red()), Some(1.0));
+//!
+//! let pixels = [Pixel::<Rgba8888>::with_rgba(1, 2, 3, 4); 2];
+//! let slice = DynPixelSlice::from(&pixels[..]);
+//! assert_eq!((slice.format(), slice.len()), (DynFormat::Rgba8888, 2));
+//! assert_eq!(slice.downcast::<Rgba8888>(), Some(&pixels[..]));
+//! ```
+
+use core::{
+    any::TypeId,
+    fmt::{Display, Formatter},
+};
+
+use crate::{
+    formats::{
+        bayer::{
+            BayerBggr8, BayerBggr16, BayerGbrg8, BayerGbrg16, BayerGrbg8, BayerGrbg16, BayerRggb8,
+            BayerRggb16,
+        },
+        cmyk::{Cmyk8888, FloatCmyk},
+        depth::{D16, D24S8, D32F},
+        gray::{Gray2, Gray4, Gray8, Gray16, GrayFormat, Mono1},
+        hdr::{R11G11B10F, Rgb9e5},
+        hsv::{HslaF32, HsvaF32},
+        indexed::Indexed8,
+        lab::{LabF32, OklabF32},
+        rg::Rg8Snorm,
+        rgb::Rgb565,
+        rgba::{
+            Abgr8888, Abgr8888Be, Abgr8888Le, DoubleRgba, FloatRgba, Rgba8Snorm, Rgba8888,
+            Rgba8888Be, Rgba8888Le, Rgba16161616, RgbaFormat,
+        },
+        xyz::XyzaF32,
+        ycocg::YcocgR,
+        yuv::{P010, P016, Uyvy422, Ycbcr444, Yuyv422},
+    },
+    norm::Normalized,
+    pixel::{Format, Pixel, raw::RawPixel},
+};
+
+macro_rules! dyn_formats {
+    ($($name:ident => $to_float_rgba:ident,)*) => {
+        /// A built-in pixel format, chosen at runtime.
+        ///
+        /// Each variant corresponds to the format type of the same name.
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        #[non_exhaustive]
+        pub enum DynFormat {
+            $(
+                #[doc = concat!("[`", stringify!($name), "`]")]
+                $name,
+            )*
+        }
+
+        impl DynFormat {
+            /// Every built-in format.
+            pub const ALL: &'static [Self] = &[$(Self::$name),*];
+
+            /// Returns the name of the format, which is the name of its type.
+            ///
+            /// See [`Format::NAME`].
+            #[must_use]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$name => $name::NAME,)*
+                }
+            }
+
+            /// Returns the number of bits each pixel of the format occupies in memory.
+            ///
+            /// See [`Format::BITS_PER_PIXEL`].
+            #[must_use]
+            pub const fn bits_per_pixel(self) -> u32 {
+                match self {
+                    $(Self::$name => $name::BITS_PER_PIXEL,)*
+                }
+            }
+
+            /// Returns the number of channels of the format's raw pixel.
+            ///
+            /// See [`Format::CHANNEL_COUNT`].
+            #[must_use]
+            pub const fn channel_count(self) -> usize {
+                match self {
+                    $(Self::$name => $name::CHANNEL_COUNT,)*
+                }
+            }
+
+            /// Returns the size of a pixel of the format, in bytes.
+            ///
+            /// Packed formats (such as [`Mono1`]) are measured unpacked, as a single [`Pixel`].
+            #[must_use]
+            pub const fn bytes_per_pixel(self) -> usize {
+                match self {
+                    $(Self::$name => size_of::<Pixel<$name>>(),)*
+                }
+            }
+
+            /// Returns the runtime format of the static format `F`, or `None` if it is not a
+            /// built-in format (e.g. one declared with [`define_format!`](crate::define_format)).
+            #[must_use]
+            pub fn of<F: Format>() -> Option<Self> {
+                let id = TypeId::of::<F>();
+                $(
+                    if id == TypeId::of::<$name>() {
+                        return Some(Self::$name);
+                    }
+                )*
+                None
+            }
+        }
+
+        /// A pixel of any built-in format, chosen at runtime.
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        #[non_exhaustive]
+        pub enum AnyPixel {
+            $(
+                #[doc = concat!("A [`", stringify!($name), "`] pixel.")]
+                $name(Pixel<$name>),
+            )*
+        }
+
+        impl AnyPixel {
+            /// Returns the format of the pixel.
+            #[must_use]
+            pub const fn format(&self) -> DynFormat {
+                match self {
+                    $(Self::$name(_) => DynFormat::$name,)*
+                }
+            }
+
+            /// Converts the pixel to [`FloatRgba`], or returns `None` if the format has no single
+            /// conversion to RGBA.
+            ///
+            /// Formats without color (depth, Bayer mosaics, indexed pixels without a palette), and
+            /// formats whose conversion depends on a choice (`YCbCr` matrices, CMYK profiles) are
+            /// not converted. Formats without alpha are converted as opaque.
+            #[must_use]
+            pub fn to_float_rgba(&self) -> Option<Pixel<FloatRgba>> {
+                match *self {
+                    $(Self::$name(pixel) => $to_float_rgba(pixel),)*
+                }
+            }
+        }
+
+        $(
+            impl From<Pixel<$name>> for AnyPixel {
+                fn from(pixel: Pixel<$name>) -> Self {
+                    Self::$name(pixel)
+                }
+            }
+
+            impl TryFrom<AnyPixel> for Pixel<$name> {
+                type Error = AnyPixel;
+
+                fn try_from(pixel: AnyPixel) -> Result<Self, AnyPixel> {
+                    match pixel {
+                        AnyPixel::$name(pixel) => Ok(pixel),
+                        #[allow(unreachable_patterns)]
+                        _ => Err(pixel),
+                    }
+                }
+            }
+        )*
+
+        /// A slice of pixels of any built-in format, chosen at runtime.
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        #[non_exhaustive]
+        pub enum DynPixelSlice<'a> {
+            $(
+                #[doc = concat!("A slice of [`", stringify!($name), "`] pixels.")]
+                $name(&'a [Pixel<$name>]),
+            )*
+        }
+
+        impl<'a> DynPixelSlice<'a> {
+            /// Returns the format of the pixels.
+            #[must_use]
+            pub const fn format(&self) -> DynFormat {
+                match self {
+                    $(Self::$name(_) => DynFormat::$name,)*
+                }
+            }
+
+            /// Returns the number of pixels in the slice.
+            #[must_use]
+            pub const fn len(&self) -> usize {
+                match self {
+                    $(Self::$name(pixels) => pixels.len(),)*
+                }
+            }
+
+            /// Returns `true` if the slice contains no pixels.
+            #[must_use]
+            pub const fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Returns the pixel at `index`, or `None` if it is out of bounds.
+            #[must_use]
+            pub fn get(&self, index: usize) -> Option<AnyPixel> {
+                match self {
+                    $(Self::$name(pixels) => pixels.get(index).copied().map(AnyPixel::$name),)*
+                }
+            }
+        }
+
+        $(
+            impl<'a> From<&'a [Pixel<$name>]> for DynPixelSlice<'a> {
+                fn from(pixels: &'a [Pixel<$name>]) -> Self {
+                    Self::$name(pixels)
+                }
+            }
+
+            impl<'a> TryFrom<DynPixelSlice<'a>> for &'a [Pixel<$name>] {
+                type Error = DynPixelSlice<'a>;
+
+                fn try_from(pixels: DynPixelSlice<'a>) -> Result<Self, DynPixelSlice<'a>> {
+                    match pixels {
+                        DynPixelSlice::$name(pixels) => Ok(pixels),
+                        #[allow(unreachable_patterns)]
+                        _ => Err(pixels),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+dyn_formats! {
+    Rgba8888 => rgba,
+    Abgr8888 => rgba,
+    Rgba8888Be => rgba,
+    Rgba8888Le => rgba,
+    Abgr8888Be => rgba,
+    Abgr8888Le => rgba,
+    Rgba16161616 => rgba,
+    Rgba8Snorm => rgba,
+    FloatRgba => rgba,
+    DoubleRgba => from,
+    Mono1 => gray,
+    Gray2 => gray,
+    Gray4 => gray,
+    Gray8 => gray,
+    Gray16 => gray,
+    Rg8Snorm => rg8_snorm,
+    Rgb565 => rgb565,
+    R11G11B10F => r11g11b10f,
+    Rgb9e5 => rgb9e5,
+    HslaF32 => from,
+    HsvaF32 => from,
+    LabF32 => from,
+    OklabF32 => from,
+    XyzaF32 => from,
+    YcocgR => ycocg_r,
+    Ycbcr444 => none,
+    P010 => none,
+    P016 => none,
+    Yuyv422 => none,
+    Uyvy422 => none,
+    Cmyk8888 => none,
+    FloatCmyk => none,
+    Indexed8 => none,
+    D16 => none,
+    D24S8 => none,
+    D32F => none,
+    BayerRggb8 => none,
+    BayerRggb16 => none,
+    BayerBggr8 => none,
+    BayerBggr16 => none,
+    BayerGrbg8 => none,
+    BayerGrbg16 => none,
+    BayerGbrg8 => none,
+    BayerGbrg16 => none,
+}
+
+impl Display for DynFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl AnyPixel {
+    /// Returns the pixel as the static type `Pixel<F>`, or `None` if it is of another format.
+    #[must_use]
+    pub fn downcast<F: Format>(self) -> Option<Pixel<F>>
+    where
+        Pixel<F>: TryFrom<Self>,
+    {
+        Pixel::try_from(self).ok()
+    }
+}
+
+impl<'a> DynPixelSlice<'a> {
+    /// Returns the pixels as the static type `&[Pixel<F>]`, or `None` if they are of another
+    /// format.
+    #[must_use]
+    pub fn downcast<F: Format>(self) -> Option<&'a [Pixel<F>]>
+    where
+        &'a [Pixel<F>]: TryFrom<Self>,
+    {
+        <&[Pixel<F>]>::try_from(self).ok()
+    }
+
+    /// Returns an iterator over the pixels of the slice.
+    pub fn iter(&self) -> impl Iterator<Item = AnyPixel> + use<'a> {
+        let pixels = *self;
+        (0..pixels.len()).filter_map(move |index| pixels.get(index))
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn rgba<F>(pixel: Pixel<F>) -> Option<Pixel<FloatRgba>>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    Some(pixel.convert())
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn from<F: Format>(pixel: Pixel<F>) -> Option<Pixel<FloatRgba>>
+where
+    Pixel<FloatRgba>: From<Pixel<F>>,
+{
+    Some(pixel.into())
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn gray<F>(pixel: Pixel<F>) -> Option<Pixel<FloatRgba>>
+where
+    F: GrayFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    let gray = pixel.gray().to_f32() / F::GRAY_MAX;
+    Some(Pixel::with_rgba(gray, gray, gray, 1.0))
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn rg8_snorm(pixel: Pixel<Rg8Snorm>) -> Option<Pixel<FloatRgba>> {
+    Some(Pixel::with_rgba(
+        pixel.red().to_f32(),
+        pixel.green().to_f32(),
+        0.0,
+        1.0,
+    ))
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn rgb565(pixel: Pixel<Rgb565>) -> Option<Pixel<FloatRgba>> {
+    Some(pixel.convert())
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn r11g11b10f(pixel: Pixel<R11G11B10F>) -> Option<Pixel<FloatRgba>> {
+    let [red, green, blue] = pixel.rgb();
+    Some(Pixel::with_rgba(red, green, blue, 1.0))
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn rgb9e5(pixel: Pixel<Rgb9e5>) -> Option<Pixel<FloatRgba>> {
+    let [red, green, blue] = pixel.rgb();
+    Some(Pixel::with_rgba(red, green, blue, 1.0))
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn ycocg_r(pixel: Pixel<YcocgR>) -> Option<Pixel<FloatRgba>> {
+    Some(Pixel::<Rgba8888>::from(pixel).convert())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn none<F: Format>(_: Pixel<F>) -> Option<Pixel<FloatRgba>> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_formats_round_trip() {
+        for format in DynFormat::ALL {
+            assert_eq!(
+                DynFormat::ALL
+                    .iter()
+                    .filter(|f| f.name() == format.name())
+                    .count(),
+                1
+            );
+            assert!(format.bytes_per_pixel() > 0);
+        }
+        assert_eq!(
+            DynFormat::of::<Rgba16161616>(),
+            Some(DynFormat::Rgba16161616)
+        );
+        assert_eq!(DynFormat::Rgba16161616.bytes_per_pixel(), 8);
+        assert_eq!(DynFormat::Mono1.bits_per_pixel(), 1);
+        assert_eq!(DynFormat::D24S8.channel_count(), 2);
+    }
+
+    #[test]
+    fn of_custom_format() {
+        crate::define_format! {
+            enum Argb8888: crate::pixel::raw::U32x8888 {
+                red: 1,
+                green: 2,
+                blue: 3,
+                alpha: 0,
+            }
+        }
+        assert_eq!(DynFormat::of::<Argb8888>(), None);
+    }
+
+    #[test]
+    fn downcast_mismatch_returns_pixel() {
+        let pixel = AnyPixel::from(Pixel::<D16>::zeroed());
+        assert_eq!(Pixel::<D32F>::try_from(pixel), Err(pixel));
+        assert_eq!(pixel.to_float_rgba(), None);
+    }
+
+    #[test]
+    fn to_float_rgba() {
+        let pixel = AnyPixel::from(Pixel::<Gray4>::with_gray(15));
+        assert_eq!(
+            pixel.to_float_rgba(),
+            Some(Pixel::with_rgba(1.0, 1.0, 1.0, 1.0))
+        );
+
+        let pixel = AnyPixel::from(Pixel::<YcocgR>::from(Pixel::<Rgba8888>::with_rgba(
+            0xFF, 0x00, 0x00, 0xFF,
+        )));
+        assert_eq!(
+            pixel.to_float_rgba(),
+            Some(Pixel::with_rgba(1.0, 0.0, 0.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn slice_iter() {
+        let pixels = [Pixel::<Abgr8888>::with_rgba(1, 2, 3, 4), Pixel::zeroed()];
+        let slice = DynPixelSlice::from(&pixels[..]);
+        assert!(!slice.is_empty());
+        assert_eq!(slice.get(2), None);
+        assert!(slice.iter().eq(pixels.map(AnyPixel::from)));
+        assert_eq!(slice.downcast::<Rgba8888>(), None);
+    }
+}
@@ -1,3 +1,16 @@
 //! Pixel formats.
 
+pub mod bayer;
+pub mod cmyk;
+pub mod depth;
+pub mod gray;
+pub mod hdr;
+pub mod hsv;
+pub mod indexed;
+pub mod lab;
+pub mod rg;
+pub mod rgb;
 pub mod rgba;
+pub mod xyz;
+pub mod ycocg;
+pub mod yuv;
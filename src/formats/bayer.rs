@@ -0,0 +1,140 @@
+//! Bayer mosaic (color filter array) pixel formats.
+//!
+//! Raw camera sensor data is a _mosaic_: each pixel samples a single color, determined by the color
+//! filter in front of it. The filters repeat in a 2x2 pattern, named after the colors of the tile
+//! in row-major order (e.g. `RGGB` is red and green on the first row, and green and blue on the
+//! second).
+//!
+//! Every pixel stores a single sample; use [`BayerFormat::color_at`] to find out which color a
+//! pixel at a given position sampled, which allows demosaicing code to be written generically over
+//! all patterns.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pxlfmt::formats::bayer::{BayerFormat, BayerGrbg8, BayerRggb8, CfaColor};
+//!
+//! assert_eq!(BayerRggb8::color_at(0, 0), CfaColor::Red);
+//! assert_eq!(BayerRggb8::color_at(1, 1), CfaColor::Blue);
+//! assert_eq!(BayerGrbg8::color_at(1, 0), CfaColor::Red);
+//! assert_eq!(BayerGrbg8::color_at(3, 2), CfaColor::Red);
+//! ```
+
+mod bggr;
+pub use bggr::{BayerBggr8, BayerBggr16};
+
+mod gbrg;
+pub use gbrg::{BayerGbrg8, BayerGbrg16};
+
+mod grbg;
+pub use grbg::{BayerGrbg8, BayerGrbg16};
+
+mod rggb;
+pub use rggb::{BayerRggb8, BayerRggb16};
+
+use crate::pixel::{ChannelOf, Format, Pixel, raw::RawPixel};
+
+/// The single channel of a mosaic pixel, the sample of whichever color its filter passes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Cfa {
+    Sample,
+}
+
+/// A color of a color filter array.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum CfaColor {
+    Red,
+    Green,
+    Blue,
+}
+
+/// A Bayer mosaic pixel format, where each pixel samples one color of a repeating 2x2 pattern.
+///
+/// The offset of the sample channel in the pixel's raw representation, and the pattern of colors,
+/// are defined by this trait:
+/// - `SAMPLE_OFFSET`: Offset of the sample channel.
+/// - `PATTERN`: Colors of the 2x2 tile, indexed by `[y][x]`.
+pub trait BayerFormat: Format<Channels = Cfa> {
+    /// The offset of the sample channel in the pixel's raw representation.
+    const SAMPLE_OFFSET: usize;
+
+    /// The colors of the repeating 2x2 tile, indexed by `[y][x]`.
+    const PATTERN: [[CfaColor; 2]; 2];
+
+    /// Returns the color sampled by the pixel at the given coordinates.
+    #[must_use]
+    fn color_at(x: usize, y: usize) -> CfaColor {
+        Self::PATTERN[y % 2][x % 2]
+    }
+
+    /// Returns the sample channel value of a pixel.
+    fn get_sample(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::SAMPLE_OFFSET)
+    }
+
+    /// Sets the sample channel value of a pixel.
+    fn set_sample(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::SAMPLE_OFFSET, value);
+    }
+}
+
+impl<F: BayerFormat> ChannelOf<F> for Cfa {
+    const ALL: &'static [Self] = &[Self::Sample];
+
+    fn offset(self) -> usize {
+        match self {
+            Self::Sample => F::SAMPLE_OFFSET,
+        }
+    }
+}
+
+impl<F: BayerFormat> Pixel<F> {
+    /// Returns the sample channel value of the pixel.
+    pub fn sample(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_sample(self.as_raw())
+    }
+
+    /// Sets the sample channel value of the pixel.
+    pub fn set_sample(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_sample(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Creates a new pixel from a sample value.
+    pub fn with_sample(value: <F::RawPixel as RawPixel>::Channel) -> Self {
+        let mut pixel = Self::from_raw(F::RawPixel::DEFAULT);
+        pixel.set_sample(value);
+        pixel
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_tile_has_two_greens() {
+        fn greens<F: BayerFormat>() -> usize {
+            F::PATTERN
+                .iter()
+                .flatten()
+                .filter(|&&color| color == CfaColor::Green)
+                .count()
+        }
+        assert_eq!(greens::<BayerRggb8>(), 2);
+        assert_eq!(greens::<BayerBggr8>(), 2);
+        assert_eq!(greens::<BayerGrbg16>(), 2);
+        assert_eq!(greens::<BayerGbrg16>(), 2);
+    }
+
+    #[test]
+    fn with_sample() {
+        let mut pixel = Pixel::<BayerRggb16>::with_sample(0x0FFF);
+        assert_eq!(pixel.sample(), 0x0FFF);
+
+        pixel.set_sample(0x0001);
+        assert_eq!(pixel.sample(), 0x0001);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    formats::bayer::{BayerFormat, Cfa, CfaColor},
+    pixel::{
+        Format,
+        raw::{U8x1, U16x1},
+    },
+};
+
+/// An 8-bit Bayer mosaic format with the `BGGR` pattern.
+///
+/// The repeating 2x2 tile is:
+/// ```text
+/// B G
+/// G R
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum BayerBggr8 {}
+
+impl crate::internal::Sealed for BayerBggr8 {}
+
+impl Format for BayerBggr8 {
+    type RawPixel = U8x1;
+    type Channels = Cfa;
+    const NAME: &'static str = "BayerBggr8";
+}
+
+impl BayerFormat for BayerBggr8 {
+    const SAMPLE_OFFSET: usize = 0;
+    const PATTERN: [[CfaColor; 2]; 2] = [
+        [CfaColor::Blue, CfaColor::Green],
+        [CfaColor::Green, CfaColor::Red],
+    ];
+}
+
+/// A 16-bit Bayer mosaic format with the `BGGR` pattern.
+///
+/// Sensors with fewer than 16 bits per sample (e.g. 10 or 12) store their samples in the low bits.
+///
+/// The repeating 2x2 tile is:
+/// ```text
+/// B G
+/// G R
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum BayerBggr16 {}
+
+impl crate::internal::Sealed for BayerBggr16 {}
+
+impl Format for BayerBggr16 {
+    type RawPixel = U16x1;
+    type Channels = Cfa;
+    const NAME: &'static str = "BayerBggr16";
+}
+
+impl BayerFormat for BayerBggr16 {
+    const SAMPLE_OFFSET: usize = 0;
+    const PATTERN: [[CfaColor; 2]; 2] = BayerBggr8::PATTERN;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_at() {
+        assert_eq!(BayerBggr8::color_at(0, 0), CfaColor::Blue);
+        assert_eq!(BayerBggr8::color_at(1, 0), CfaColor::Green);
+        assert_eq!(BayerBggr8::color_at(0, 1), CfaColor::Green);
+        assert_eq!(BayerBggr16::color_at(3, 5), CfaColor::Red);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    formats::bayer::{BayerFormat, Cfa, CfaColor},
+    pixel::{
+        Format,
+        raw::{U8x1, U16x1},
+    },
+};
+
+/// An 8-bit Bayer mosaic format with the `GBRG` pattern.
+///
+/// The repeating 2x2 tile is:
+/// ```text
+/// G B
+/// R G
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum BayerGbrg8 {}
+
+impl crate::internal::Sealed for BayerGbrg8 {}
+
+impl Format for BayerGbrg8 {
+    type RawPixel = U8x1;
+    type Channels = Cfa;
+    const NAME: &'static str = "BayerGbrg8";
+}
+
+impl BayerFormat for BayerGbrg8 {
+    const SAMPLE_OFFSET: usize = 0;
+    const PATTERN: [[CfaColor; 2]; 2] = [
+        [CfaColor::Green, CfaColor::Blue],
+        [CfaColor::Red, CfaColor::Green],
+    ];
+}
+
+/// A 16-bit Bayer mosaic format with the `GBRG` pattern.
+///
+/// Sensors with fewer than 16 bits per sample (e.g. 10 or 12) store their samples in the low bits.
+///
+/// The repeating 2x2 tile is:
+/// ```text
+/// G B
+/// R G
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum BayerGbrg16 {}
+
+impl crate::internal::Sealed for BayerGbrg16 {}
+
+impl Format for BayerGbrg16 {
+    type RawPixel = U16x1;
+    type Channels = Cfa;
+    const NAME: &'static str = "BayerGbrg16";
+}
+
+impl BayerFormat for BayerGbrg16 {
+    const SAMPLE_OFFSET: usize = 0;
+    const PATTERN: [[CfaColor; 2]; 2] = BayerGbrg8::PATTERN;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_at() {
+        assert_eq!(BayerGbrg8::color_at(0, 0), CfaColor::Green);
+        assert_eq!(BayerGbrg8::color_at(1, 0), CfaColor::Blue);
+        assert_eq!(BayerGbrg8::color_at(0, 1), CfaColor::Red);
+        assert_eq!(BayerGbrg16::color_at(3, 5), CfaColor::Green);
+    }
+}
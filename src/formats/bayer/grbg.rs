@@ -0,0 +1,71 @@
+use crate::{
+    formats::bayer::{BayerFormat, Cfa, CfaColor},
+    pixel::{
+        Format,
+        raw::{U8x1, U16x1},
+    },
+};
+
+/// An 8-bit Bayer mosaic format with the `GRBG` pattern.
+///
+/// The repeating 2x2 tile is:
+/// ```text
+/// G R
+/// B G
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum BayerGrbg8 {}
+
+impl crate::internal::Sealed for BayerGrbg8 {}
+
+impl Format for BayerGrbg8 {
+    type RawPixel = U8x1;
+    type Channels = Cfa;
+    const NAME: &'static str = "BayerGrbg8";
+}
+
+impl BayerFormat for BayerGrbg8 {
+    const SAMPLE_OFFSET: usize = 0;
+    const PATTERN: [[CfaColor; 2]; 2] = [
+        [CfaColor::Green, CfaColor::Red],
+        [CfaColor::Blue, CfaColor::Green],
+    ];
+}
+
+/// A 16-bit Bayer mosaic format with the `GRBG` pattern.
+///
+/// Sensors with fewer than 16 bits per sample (e.g. 10 or 12) store their samples in the low bits.
+///
+/// The repeating 2x2 tile is:
+/// ```text
+/// G R
+/// B G
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum BayerGrbg16 {}
+
+impl crate::internal::Sealed for BayerGrbg16 {}
+
+impl Format for BayerGrbg16 {
+    type RawPixel = U16x1;
+    type Channels = Cfa;
+    const NAME: &'static str = "BayerGrbg16";
+}
+
+impl BayerFormat for BayerGrbg16 {
+    const SAMPLE_OFFSET: usize = 0;
+    const PATTERN: [[CfaColor; 2]; 2] = BayerGrbg8::PATTERN;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_at() {
+        assert_eq!(BayerGrbg8::color_at(0, 0), CfaColor::Green);
+        assert_eq!(BayerGrbg8::color_at(1, 0), CfaColor::Red);
+        assert_eq!(BayerGrbg8::color_at(0, 1), CfaColor::Blue);
+        assert_eq!(BayerGrbg16::color_at(3, 5), CfaColor::Green);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    formats::bayer::{BayerFormat, Cfa, CfaColor},
+    pixel::{
+        Format,
+        raw::{U8x1, U16x1},
+    },
+};
+
+/// An 8-bit Bayer mosaic format with the `RGGB` pattern.
+///
+/// The repeating 2x2 tile is:
+/// ```text
+/// R G
+/// G B
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum BayerRggb8 {}
+
+impl crate::internal::Sealed for BayerRggb8 {}
+
+impl Format for BayerRggb8 {
+    type RawPixel = U8x1;
+    type Channels = Cfa;
+    const NAME: &'static str = "BayerRggb8";
+}
+
+impl BayerFormat for BayerRggb8 {
+    const SAMPLE_OFFSET: usize = 0;
+    const PATTERN: [[CfaColor; 2]; 2] = [
+        [CfaColor::Red, CfaColor::Green],
+        [CfaColor::Green, CfaColor::Blue],
+    ];
+}
+
+/// A 16-bit Bayer mosaic format with the `RGGB` pattern.
+///
+/// Sensors with fewer than 16 bits per sample (e.g. 10 or 12) store their samples in the low bits.
+///
+/// The repeating 2x2 tile is:
+/// ```text
+/// R G
+/// G B
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum BayerRggb16 {}
+
+impl crate::internal::Sealed for BayerRggb16 {}
+
+impl Format for BayerRggb16 {
+    type RawPixel = U16x1;
+    type Channels = Cfa;
+    const NAME: &'static str = "BayerRggb16";
+}
+
+impl BayerFormat for BayerRggb16 {
+    const SAMPLE_OFFSET: usize = 0;
+    const PATTERN: [[CfaColor; 2]; 2] = BayerRggb8::PATTERN;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_at() {
+        assert_eq!(BayerRggb8::color_at(0, 0), CfaColor::Red);
+        assert_eq!(BayerRggb8::color_at(1, 0), CfaColor::Green);
+        assert_eq!(BayerRggb8::color_at(0, 1), CfaColor::Green);
+        assert_eq!(BayerRggb16::color_at(3, 5), CfaColor::Blue);
+    }
+}
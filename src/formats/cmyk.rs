@@ -0,0 +1,281 @@
+//! CMYK (subtractive) pixel formats.
+//!
+//! Pixels can be converted to and from RGBA formats using a _naive_ conversion, which does not take
+//! ink characteristics or color profiles into account; it is suitable for previews and simple
+//! print workflows, but not for color-accurate output:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::{cmyk::Cmyk8888, rgba::Rgba8888},
+//!     pixel::Pixel,
+//! };
+//!
+//! let red = Pixel::<Rgba8888>::with_rgba(0xFF, 0x00, 0x00, 0xFF);
+//! let cmyk = Pixel::<Cmyk8888>::from_rgba_naive(red);
+//! assert_eq!((cmyk.cyan(), cmyk.magenta(), cmyk.yellow(), cmyk.black()), (0x00, 0xFF, 0xFF, 0x00));
+//!
+//! let rgba = cmyk.to_rgba_naive::<Rgba8888>();
+//! assert_eq!(rgba, red);
+//! ```
+
+mod cmyk8888;
+pub use cmyk8888::Cmyk8888;
+
+mod float_cmyk;
+pub use float_cmyk::FloatCmyk;
+
+use crate::{
+    formats::rgba::RgbaFormat,
+    norm::Normalized,
+    pixel::{ChannelOf, Format, Pixel, raw::RawPixel},
+};
+
+/// Channels representing `C`yan, `M`agenta, `Y`ellow, and `K` (black) components of a pixel.
+///
+/// Used in pixel formats that support CMYK color representation.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Cmyk {
+    Cyan,
+    Magenta,
+    Yellow,
+    Black,
+}
+
+/// A pixel format with cyan, magenta, yellow, and black channels.
+///
+/// The offsets of individual channels in the pixel's raw representation are defined by this trait:
+/// - `CYAN_OFFSET`: Offset of the cyan channel.
+/// - `MAGENTA_OFFSET`: Offset of the magenta channel.
+/// - `YELLOW_OFFSET`: Offset of the yellow channel.
+/// - `BLACK_OFFSET`: Offset of the black channel.
+pub trait CmykFormat: Format<Channels = Cmyk> {
+    /// The offset of the cyan channel in the pixel's raw representation.
+    const CYAN_OFFSET: usize;
+
+    /// The offset of the magenta channel in the pixel's raw representation.
+    const MAGENTA_OFFSET: usize;
+
+    /// The offset of the yellow channel in the pixel's raw representation.
+    const YELLOW_OFFSET: usize;
+
+    /// The offset of the black channel in the pixel's raw representation.
+    const BLACK_OFFSET: usize;
+
+    /// Returns the cyan channel value of a pixel.
+    fn get_cyan(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::CYAN_OFFSET)
+    }
+
+    /// Sets the cyan channel value of a pixel.
+    fn set_cyan(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::CYAN_OFFSET, value);
+    }
+
+    /// Returns the magenta channel value of a pixel.
+    fn get_magenta(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::MAGENTA_OFFSET)
+    }
+
+    /// Sets the magenta channel value of a pixel.
+    fn set_magenta(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::MAGENTA_OFFSET, value);
+    }
+
+    /// Returns the yellow channel value of a pixel.
+    fn get_yellow(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::YELLOW_OFFSET)
+    }
+
+    /// Sets the yellow channel value of a pixel.
+    fn set_yellow(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::YELLOW_OFFSET, value);
+    }
+
+    /// Returns the black channel value of a pixel.
+    fn get_black(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::BLACK_OFFSET)
+    }
+
+    /// Sets the black channel value of a pixel.
+    fn set_black(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::BLACK_OFFSET, value);
+    }
+}
+
+impl<F: CmykFormat> ChannelOf<F> for Cmyk {
+    const ALL: &'static [Self] = &[Self::Cyan, Self::Magenta, Self::Yellow, Self::Black];
+
+    fn offset(self) -> usize {
+        match self {
+            Self::Cyan => F::CYAN_OFFSET,
+            Self::Magenta => F::MAGENTA_OFFSET,
+            Self::Yellow => F::YELLOW_OFFSET,
+            Self::Black => F::BLACK_OFFSET,
+        }
+    }
+}
+
+impl<F: CmykFormat> Pixel<F> {
+    /// Returns the cyan channel value of the pixel.
+    pub fn cyan(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_cyan(self.as_raw())
+    }
+
+    /// Sets the cyan channel value of the pixel.
+    pub fn set_cyan(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_cyan(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Returns the magenta channel value of the pixel.
+    pub fn magenta(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_magenta(self.as_raw())
+    }
+
+    /// Sets the magenta channel value of the pixel.
+    pub fn set_magenta(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_magenta(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Returns the yellow channel value of the pixel.
+    pub fn yellow(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_yellow(self.as_raw())
+    }
+
+    /// Sets the yellow channel value of the pixel.
+    pub fn set_yellow(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_yellow(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Returns the black channel value of the pixel.
+    pub fn black(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_black(self.as_raw())
+    }
+
+    /// Sets the black channel value of the pixel.
+    pub fn set_black(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_black(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Creates a new pixel from CMYK channel values.
+    pub fn with_cmyk(
+        c: <F::RawPixel as RawPixel>::Channel,
+        m: <F::RawPixel as RawPixel>::Channel,
+        y: <F::RawPixel as RawPixel>::Channel,
+        k: <F::RawPixel as RawPixel>::Channel,
+    ) -> Self {
+        let mut pixel = Self::from_raw(F::RawPixel::DEFAULT);
+        pixel.set_cyan(c).set_magenta(m).set_yellow(y).set_black(k);
+        pixel
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: CmykFormat,
+    <F::RawPixel as RawPixel>::Channel: Normalized,
+{
+    /// Converts an RGBA pixel to CMYK using the naive formula.
+    ///
+    /// Black is extracted as `1 - max(r, g, b)`, and the remaining channels are scaled by the
+    /// remaining (non-black) intensity. The alpha channel is discarded.
+    pub fn from_rgba_naive<T>(rgba: Pixel<T>) -> Self
+    where
+        T: RgbaFormat,
+        <T::RawPixel as RawPixel>::Channel: Normalized,
+    {
+        let red = rgba.red().to_f32();
+        let green = rgba.green().to_f32();
+        let blue = rgba.blue().to_f32();
+
+        let k = 1.0 - red.max(green).max(blue);
+        if k >= 1.0 {
+            let zero = Normalized::ZERO;
+            return Self::with_cmyk(zero, zero, zero, Normalized::ONE);
+        }
+        let ink = |value: f32| Normalized::from_f32((1.0 - value - k) / (1.0 - k));
+        Self::with_cmyk(ink(red), ink(green), ink(blue), Normalized::from_f32(k))
+    }
+
+    /// Converts the pixel to an opaque RGBA pixel using the naive formula.
+    ///
+    /// Each color channel is computed as `(1 - ink) * (1 - k)`.
+    #[must_use]
+    pub fn to_rgba_naive<T>(&self) -> Pixel<T>
+    where
+        T: RgbaFormat,
+        <T::RawPixel as RawPixel>::Channel: Normalized,
+    {
+        let k = 1.0 - self.black().to_f32();
+        let light = |ink: <F::RawPixel as RawPixel>::Channel| {
+            Normalized::from_f32((1.0 - ink.to_f32()) * k)
+        };
+        Pixel::with_rgba(
+            light(self.cyan()),
+            light(self.magenta()),
+            light(self.yellow()),
+            Normalized::ONE,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{FloatRgba, Rgba8888};
+
+    use super::*;
+
+    #[test]
+    fn with_cmyk() {
+        let pixel = Pixel::<Cmyk8888>::with_cmyk(0x01, 0x02, 0x03, 0x04);
+        assert_eq!(pixel.cyan(), 0x01);
+        assert_eq!(pixel.magenta(), 0x02);
+        assert_eq!(pixel.yellow(), 0x03);
+        assert_eq!(pixel.black(), 0x04);
+    }
+
+    #[test]
+    fn from_rgba_naive() {
+        let black = Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0x00, 0xFF);
+        let pixel = Pixel::<Cmyk8888>::from_rgba_naive(black);
+        assert_eq!(pixel, Pixel::with_cmyk(0x00, 0x00, 0x00, 0xFF));
+
+        let white = Pixel::<Rgba8888>::with_rgba(0xFF, 0xFF, 0xFF, 0xFF);
+        let pixel = Pixel::<Cmyk8888>::from_rgba_naive(white);
+        assert_eq!(pixel, Pixel::with_cmyk(0x00, 0x00, 0x00, 0x00));
+
+        let gray = Pixel::<Rgba8888>::with_rgba(0x80, 0x40, 0x80, 0xFF);
+        let pixel = Pixel::<Cmyk8888>::from_rgba_naive(gray);
+        assert_eq!(pixel, Pixel::with_cmyk(0x00, 0x80, 0x00, 0x7F));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn to_rgba_naive_float() {
+        let pixel = Pixel::<FloatCmyk>::with_cmyk(0.0, 0.5, 1.0, 0.5);
+        let rgba = pixel.to_rgba_naive::<FloatRgba>();
+        assert_eq!(rgba, Pixel::with_rgba(0.5, 0.25, 0.0, 1.0));
+    }
+
+    #[test]
+    fn round_trip() {
+        for (r, g, b) in [
+            (0, 0, 0),
+            (255, 255, 255),
+            (255, 0, 0),
+            (12, 200, 99),
+            (1, 2, 3),
+        ] {
+            let rgba = Pixel::<Rgba8888>::with_rgba(r, g, b, 0xFF);
+            let back = Pixel::<Cmyk8888>::from_rgba_naive(rgba).to_rgba_naive::<Rgba8888>();
+            assert!(back.red().abs_diff(r) <= 1, "{rgba:?} {back:?}");
+            assert!(back.green().abs_diff(g) <= 1, "{rgba:?} {back:?}");
+            assert!(back.blue().abs_diff(b) <= 1, "{rgba:?} {back:?}");
+            assert_eq!(back.alpha(), 0xFF);
+        }
+    }
+}
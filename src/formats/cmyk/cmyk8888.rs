@@ -0,0 +1,62 @@
+use crate::{
+    formats::cmyk::{Cmyk, CmykFormat},
+    pixel::{Format, raw::U32x8888},
+};
+
+/// A 32-bit CMYK pixel format with four 8-bit channels.
+///
+/// This format is used to represent pixels in the CMYK order:
+/// - `C`yan (8 bits)
+/// - `M`agenta (8 bits)
+/// - `Y`ellow (8 bits)
+/// - `K` (black) (8 bits)
+///
+/// The pixel is represented as a 32-bit unsigned integer, where each channel occupies 8 bits.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Cmyk8888 {}
+
+impl crate::internal::Sealed for Cmyk8888 {}
+
+impl Format for Cmyk8888 {
+    type RawPixel = U32x8888;
+    type Channels = Cmyk;
+    const NAME: &'static str = "Cmyk8888";
+}
+
+impl CmykFormat for Cmyk8888 {
+    const CYAN_OFFSET: usize = 0;
+    const MAGENTA_OFFSET: usize = 1;
+    const YELLOW_OFFSET: usize = 2;
+    const BLACK_OFFSET: usize = 3;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::{
+        Pixel,
+        raw::{RawPixel, U32x8888},
+    };
+
+    use super::*;
+
+    #[test]
+    fn new_zeroed() {
+        let pixel: Pixel<Cmyk8888> = Pixel::zeroed();
+        assert_eq!(pixel.as_raw().into_inner(), 0x0000_0000);
+    }
+
+    #[test]
+    fn from_raw() {
+        let mut pixel: Pixel<Cmyk8888> = Pixel::new(U32x8888::from(0xFF00_00FF));
+        assert_eq!(pixel.cyan(), 0xFF);
+        assert_eq!(pixel.magenta(), 0x00);
+        assert_eq!(pixel.yellow(), 0x00);
+        assert_eq!(pixel.black(), 0xFF);
+
+        pixel.set_cyan(0x01);
+        pixel.set_magenta(0x02);
+        pixel.set_yellow(0x03);
+        pixel.set_black(0x04);
+        assert_eq!(pixel.as_raw().into_inner(), 0x0403_0201);
+    }
+}
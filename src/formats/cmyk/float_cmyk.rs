@@ -0,0 +1,56 @@
+use crate::{
+    formats::cmyk::{Cmyk, CmykFormat},
+    pixel::{Format, raw::F32x4},
+};
+
+/// A 128-bit CMYK pixel format with four 32-bit floating point channels.
+///
+/// This format is used to represent pixels in the CMYK order:
+/// - `C`yan (32 bits)
+/// - `M`agenta (32 bits)
+/// - `Y`ellow (32 bits)
+/// - `K` (black) (32 bits)
+///
+/// The pixel is represented as a 128-bit value, where each channel occupies 32 bits.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum FloatCmyk {}
+
+impl crate::internal::Sealed for FloatCmyk {}
+
+impl Format for FloatCmyk {
+    type RawPixel = F32x4;
+    type Channels = Cmyk;
+    const NAME: &'static str = "FloatCmyk";
+}
+
+impl CmykFormat for FloatCmyk {
+    const CYAN_OFFSET: usize = 0;
+    const MAGENTA_OFFSET: usize = 1;
+    const YELLOW_OFFSET: usize = 2;
+    const BLACK_OFFSET: usize = 3;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::{Pixel, raw::RawPixel};
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn new_zeroed() {
+        let pixel: Pixel<FloatCmyk> = Pixel::zeroed();
+        assert_eq!(pixel.as_raw().into_inner(), [0.0; 4]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn from_raw() {
+        let pixel = Pixel::<FloatCmyk>::from_raw([0.5, 0.25, 0.75, 1.0].into());
+        assert_eq!(pixel.as_raw().into_inner(), [0.5, 0.25, 0.75, 1.0]);
+        assert_eq!(pixel.cyan(), 0.5);
+        assert_eq!(pixel.magenta(), 0.25);
+        assert_eq!(pixel.yellow(), 0.75);
+        assert_eq!(pixel.black(), 1.0);
+    }
+}
@@ -0,0 +1,136 @@
+//! Depth and stencil pixel formats.
+//!
+//! These formats describe the contents of depth (and depth-stencil) attachments, such as the ones
+//! read back from a GPU.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pxlfmt::{formats::depth::D24S8, pixel::Pixel};
+//!
+//! let pixel = Pixel::<D24S8>::with_depth_stencil(0x00FF_FFFF, 0x01);
+//! assert_eq!(pixel.depth(), 0x00FF_FFFF);
+//! assert_eq!(pixel.stencil(), 0x01);
+//! assert_eq!(pixel.depth_normalized(), 1.0);
+//! ```
+
+mod d16;
+pub use d16::D16;
+
+mod d24s8;
+pub use d24s8::D24S8;
+
+mod d32f;
+pub use d32f::D32F;
+
+use crate::pixel::{ChannelOf, Format, Pixel, raw::RawPixel};
+
+/// Channels representing the depth and (optional) stencil components of a pixel.
+///
+/// Formats without a stencil channel, such as [`D16`] and [`D32F`], do not list
+/// [`Stencil`](DepthStencil::Stencil) in [`ChannelOf::ALL`], and panic if it is accessed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum DepthStencil {
+    Depth,
+    Stencil,
+}
+
+/// A pixel format with a depth channel.
+///
+/// The offset of the depth channel in the pixel's raw representation is defined by this trait:
+/// - `DEPTH_OFFSET`: Offset of the depth channel.
+pub trait DepthFormat: Format<Channels = DepthStencil> {
+    /// The offset of the depth channel in the pixel's raw representation.
+    const DEPTH_OFFSET: usize;
+
+    /// Returns the depth channel value of a pixel.
+    fn get_depth(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::DEPTH_OFFSET)
+    }
+
+    /// Sets the depth channel value of a pixel.
+    fn set_depth(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::DEPTH_OFFSET, value);
+    }
+
+    /// Returns the depth of a pixel as a floating point number.
+    ///
+    /// Normalized integer depths are mapped to `0.0..=1.0`; floating point depths are returned as-is.
+    fn get_depth_normalized(pixel: &Self::RawPixel) -> f32;
+}
+
+/// A pixel format with both a depth and an 8-bit stencil channel.
+///
+/// The offset of the stencil channel in the pixel's raw representation is defined by this trait:
+/// - `STENCIL_OFFSET`: Offset of the stencil channel.
+pub trait StencilFormat: DepthFormat {
+    /// The offset of the stencil channel in the pixel's raw representation.
+    const STENCIL_OFFSET: usize;
+
+    /// Returns the stencil channel value of a pixel.
+    fn get_stencil(pixel: &Self::RawPixel) -> u8;
+
+    /// Sets the stencil channel value of a pixel.
+    fn set_stencil(pixel: &mut Self::RawPixel, value: u8);
+}
+
+impl<F: StencilFormat> ChannelOf<F> for DepthStencil {
+    const ALL: &'static [Self] = &[Self::Depth, Self::Stencil];
+
+    fn offset(self) -> usize {
+        match self {
+            Self::Depth => F::DEPTH_OFFSET,
+            Self::Stencil => F::STENCIL_OFFSET,
+        }
+    }
+}
+
+impl<F: DepthFormat> Pixel<F> {
+    /// Returns the depth channel value of the pixel.
+    pub fn depth(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_depth(self.as_raw())
+    }
+
+    /// Sets the depth channel value of the pixel.
+    pub fn set_depth(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_depth(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Returns the depth of the pixel as a floating point number.
+    ///
+    /// Normalized integer depths are mapped to `0.0..=1.0`; floating point depths are returned as-is.
+    pub fn depth_normalized(&self) -> f32 {
+        F::get_depth_normalized(self.as_raw())
+    }
+
+    /// Creates a new pixel from a depth value.
+    ///
+    /// For formats with a stencil channel, the stencil is set to `0`.
+    pub fn with_depth(depth: <F::RawPixel as RawPixel>::Channel) -> Self {
+        let mut pixel = Self::from_raw(F::RawPixel::DEFAULT);
+        pixel.set_depth(depth);
+        pixel
+    }
+}
+
+impl<F: StencilFormat> Pixel<F> {
+    /// Returns the stencil channel value of the pixel.
+    pub fn stencil(&self) -> u8 {
+        F::get_stencil(self.as_raw())
+    }
+
+    /// Sets the stencil channel value of the pixel.
+    pub fn set_stencil(&mut self, value: u8) -> &mut Self {
+        F::set_stencil(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Creates a new pixel from a depth and stencil value.
+    pub fn with_depth_stencil(depth: <F::RawPixel as RawPixel>::Channel, stencil: u8) -> Self {
+        let mut pixel = Self::with_depth(depth);
+        pixel.set_stencil(stencil);
+        pixel
+    }
+}
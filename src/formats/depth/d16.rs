@@ -0,0 +1,61 @@
+use crate::{
+    formats::depth::{DepthFormat, DepthStencil},
+    pixel::{ChannelOf, Format, raw::RawPixel, raw::U16x1},
+};
+
+/// A 16-bit normalized depth format.
+///
+/// The pixel is represented as a 16-bit unsigned integer, where `0` is the near plane and
+/// `u16::MAX` is the far plane.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum D16 {}
+
+impl crate::internal::Sealed for D16 {}
+
+impl Format for D16 {
+    type RawPixel = U16x1;
+    type Channels = DepthStencil;
+    const NAME: &'static str = "D16";
+}
+
+impl ChannelOf<D16> for DepthStencil {
+    const ALL: &'static [Self] = &[Self::Depth];
+
+    fn offset(self) -> usize {
+        match self {
+            Self::Depth => 0,
+            Self::Stencil => panic!("D16 has no stencil channel"),
+        }
+    }
+}
+
+impl DepthFormat for D16 {
+    const DEPTH_OFFSET: usize = 0;
+
+    fn get_depth_normalized(pixel: &Self::RawPixel) -> f32 {
+        f32::from(pixel.into_inner()) / f32::from(u16::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::Pixel;
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn from_raw() {
+        let mut pixel: Pixel<D16> = Pixel::new(U16x1::from(0xFFFF));
+        assert_eq!(pixel.depth(), 0xFFFF);
+        assert_eq!(pixel.depth_normalized(), 1.0);
+
+        pixel.set_depth(0);
+        assert_eq!(pixel.depth_normalized(), 0.0);
+    }
+    #[test]
+    #[should_panic(expected = "D16 has no stencil channel")]
+    fn no_stencil() {
+        let _ = Pixel::<D16>::zeroed().get(DepthStencil::Stencil);
+    }
+}
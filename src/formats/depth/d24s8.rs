@@ -0,0 +1,70 @@
+use crate::{
+    formats::depth::{DepthFormat, DepthStencil, StencilFormat},
+    pixel::{Format, raw::RawPixel, raw::U32x248},
+};
+
+/// A 32-bit packed depth-stencil format with a 24-bit normalized depth and an 8-bit stencil.
+///
+/// The pixel is represented as a 32-bit unsigned integer:
+/// - `Depth` in the low 24 bits, where `0` is the near plane and `0xFF_FFFF` is the far plane
+/// - `Stencil` in the high 8 bits
+///
+/// This matches the layout of `DXGI_FORMAT_D24_UNORM_S8_UINT`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum D24S8 {}
+
+impl crate::internal::Sealed for D24S8 {}
+
+impl Format for D24S8 {
+    type RawPixel = U32x248;
+    type Channels = DepthStencil;
+    const NAME: &'static str = "D24S8";
+}
+
+impl DepthFormat for D24S8 {
+    const DEPTH_OFFSET: usize = 0;
+
+    #[allow(clippy::cast_precision_loss)]
+    fn get_depth_normalized(pixel: &Self::RawPixel) -> f32 {
+        // 24-bit integers are exactly representable as `f32`.
+        Self::get_depth(pixel) as f32 / 0x00FF_FFFF as f32
+    }
+}
+
+impl StencilFormat for D24S8 {
+    const STENCIL_OFFSET: usize = 1;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn get_stencil(pixel: &Self::RawPixel) -> u8 {
+        pixel.get_channel(Self::STENCIL_OFFSET) as u8
+    }
+
+    fn set_stencil(pixel: &mut Self::RawPixel, value: u8) {
+        pixel.set_channel(Self::STENCIL_OFFSET, u32::from(value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::Pixel;
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn from_raw() {
+        let mut pixel: Pixel<D24S8> = Pixel::new(U32x248::from(0x0580_0000));
+        assert_eq!(pixel.depth(), 0x80_0000);
+        assert_eq!(pixel.stencil(), 0x05);
+        assert_eq!(pixel.depth_normalized(), 8_388_608.0 / 16_777_215.0);
+
+        pixel.set_depth(0x12_3456).set_stencil(0xFF);
+        assert_eq!(pixel.as_raw().into_inner(), 0xFF12_3456);
+    }
+
+    #[test]
+    fn with_depth_stencil() {
+        let pixel = Pixel::<D24S8>::with_depth_stencil(0xFF_FFFF, 0x01);
+        assert_eq!(pixel.as_raw().into_inner(), 0x01FF_FFFF);
+    }
+}
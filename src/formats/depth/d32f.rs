@@ -0,0 +1,53 @@
+use crate::{
+    formats::depth::{DepthFormat, DepthStencil},
+    pixel::{ChannelOf, Format, raw::F32x1, raw::RawPixel},
+};
+
+/// A 32-bit floating point depth format.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum D32F {}
+
+impl crate::internal::Sealed for D32F {}
+
+impl Format for D32F {
+    type RawPixel = F32x1;
+    type Channels = DepthStencil;
+    const NAME: &'static str = "D32F";
+}
+
+impl ChannelOf<D32F> for DepthStencil {
+    const ALL: &'static [Self] = &[Self::Depth];
+
+    fn offset(self) -> usize {
+        match self {
+            Self::Depth => 0,
+            Self::Stencil => panic!("D32F has no stencil channel"),
+        }
+    }
+}
+
+impl DepthFormat for D32F {
+    const DEPTH_OFFSET: usize = 0;
+
+    fn get_depth_normalized(pixel: &Self::RawPixel) -> f32 {
+        pixel.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::Pixel;
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn with_depth() {
+        let mut pixel = Pixel::<D32F>::with_depth(0.5);
+        assert_eq!(pixel.depth(), 0.5);
+        assert_eq!(pixel.depth_normalized(), 0.5);
+
+        pixel.set_depth(0.25);
+        assert_eq!(pixel.as_raw().into_inner(), 0.25);
+    }
+}
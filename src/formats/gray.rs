@@ -0,0 +1,174 @@
+//! Grayscale pixel formats.
+//!
+//! Formats with fewer than 8 bits per pixel, such as [`Mono1`], [`Gray2`], and [`Gray4`], are
+//! still represented as one byte per [`Pixel`]; use a [`PackedBitSlice`][] to read and write them
+//! in their packed form.
+//!
+//! [`PackedBitSlice`]: crate::pixel::packed::PackedBitSlice
+//!
+//! ## Conversion from RGBA
+//!
+//! RGBA pixels provide their [`luma`](Pixel::luma), a weighted sum of the color channels, and can
+//! be converted to any grayscale format with [`to_gray`](Pixel::to_gray):
+//!
+//! ```rust
+//! use pxlfmt::{formats::{gray::{Gray4, Gray8}, rgba::Rgba8888}, pixel::Pixel};
+//!
+//! let green = Pixel::<Rgba8888>::with_rgba(0x00, 0xFF, 0x00, 0xFF);
+//! assert_eq!(green.to_gray::<Gray8>().gray(), 0xB6);
+//! assert_eq!(green.to_gray::<Gray4>().gray(), 0x0B);
+//! ```
+
+mod gray16;
+pub use gray16::Gray16;
+
+mod gray2;
+pub use gray2::Gray2;
+
+mod gray4;
+pub use gray4::Gray4;
+
+mod gray8;
+pub use gray8::Gray8;
+
+mod mono1;
+pub use mono1::Mono1;
+
+use crate::{
+    formats::{rgba::RgbaFormat, yuv::YcbcrMatrix},
+    norm::Normalized,
+    pixel::{ChannelOf, Format, Pixel, raw::RawPixel},
+};
+
+/// The single channel of a grayscale pixel, its intensity.
+///
+/// Used in pixel formats that only represent shades of gray.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Gray {
+    Gray,
+}
+
+/// A pixel format with a single gray (intensity) channel.
+///
+/// The offset of the gray channel in the pixel's raw representation is defined by this trait:
+/// - `GRAY_OFFSET`: Offset of the gray channel.
+pub trait GrayFormat: Format<Channels = Gray> {
+    /// The offset of the gray channel in the pixel's raw representation.
+    const GRAY_OFFSET: usize;
+
+    /// The normalized value of white, as a fraction of the channel type's range.
+    ///
+    /// This is `1.0` unless the format uses fewer bits than its channel type, such as [`Gray4`],
+    /// where white is `15` in a `u8` channel.
+    const GRAY_MAX: f32 = 1.0;
+
+    /// Returns the gray channel value of a pixel.
+    fn get_gray(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::GRAY_OFFSET)
+    }
+
+    /// Sets the gray channel value of a pixel.
+    fn set_gray(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::GRAY_OFFSET, value);
+    }
+}
+
+impl<F: GrayFormat> ChannelOf<F> for Gray {
+    const ALL: &'static [Self] = &[Self::Gray];
+
+    fn offset(self) -> usize {
+        match self {
+            Self::Gray => F::GRAY_OFFSET,
+        }
+    }
+}
+
+impl<F: GrayFormat> Pixel<F> {
+    /// Returns the gray channel value of the pixel.
+    pub fn gray(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_gray(self.as_raw())
+    }
+
+    /// Sets the gray channel value of the pixel.
+    pub fn set_gray(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_gray(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Creates a new pixel from a gray channel value.
+    pub fn with_gray(value: <F::RawPixel as RawPixel>::Channel) -> Self {
+        let mut pixel = Self::from_raw(F::RawPixel::DEFAULT);
+        pixel.set_gray(value);
+        pixel
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    /// Returns the luma of the pixel using the ITU-R BT.709 weights, normalized to `0.0..=1.0`.
+    ///
+    /// The weights are applied to the channel values as is; for the relative luminance of an sRGB
+    /// pixel, convert it to linear light first, for example with
+    /// [`to_linear`](Pixel::to_linear). The alpha channel is ignored.
+    #[must_use]
+    pub fn luma(&self) -> f32 {
+        self.luma_with(YcbcrMatrix::Bt709)
+    }
+
+    /// Returns the luma of the pixel using the weights of the given matrix.
+    ///
+    /// See [`luma`](Pixel::luma) for details.
+    #[must_use]
+    pub fn luma_with(&self, matrix: YcbcrMatrix) -> f32 {
+        let (kr, kb) = matrix.weights();
+        let kg = 1.0 - kr - kb;
+        kr * self.red().to_f32() + kg * self.green().to_f32() + kb * self.blue().to_f32()
+    }
+
+    /// Converts the pixel to a grayscale format using its BT.709 [`luma`](Pixel::luma).
+    ///
+    /// The alpha channel is discarded.
+    #[must_use]
+    pub fn to_gray<T>(&self) -> Pixel<T>
+    where
+        T: GrayFormat,
+        T::RawPixel: RawPixel<Channel: Normalized>,
+    {
+        Pixel::with_gray(Normalized::from_f32(self.luma() * T::GRAY_MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{FloatRgba, Rgba8888};
+
+    use super::*;
+
+    #[test]
+    fn luma_weights() {
+        let white = Pixel::<Rgba8888>::with_rgba(0xFF, 0xFF, 0xFF, 0x00);
+        assert!((white.luma() - 1.0).abs() < 1e-6);
+        assert!((white.luma_with(YcbcrMatrix::Bt601) - 1.0).abs() < 1e-6);
+
+        let red = Pixel::<FloatRgba>::with_rgba(1.0, 0.0, 0.0, 1.0);
+        assert!((red.luma() - 0.2126).abs() < 1e-6);
+        assert!((red.luma_with(YcbcrMatrix::Bt601) - 0.299).abs() < 1e-6);
+    }
+
+    #[test]
+    fn to_gray_scales_to_format() {
+        let white = Pixel::<Rgba8888>::with_rgba(0xFF, 0xFF, 0xFF, 0xFF);
+        assert_eq!(white.to_gray::<Gray8>().gray(), 0xFF);
+        assert_eq!(white.to_gray::<Gray16>().gray(), 0xFFFF);
+        assert_eq!(white.to_gray::<Gray2>().gray(), 3);
+        assert_eq!(white.to_gray::<Mono1>().gray(), 1);
+
+        let gray = Pixel::<Rgba8888>::with_rgba(0x40, 0x40, 0x40, 0xFF);
+        assert_eq!(gray.to_gray::<Gray8>().gray(), 0x40);
+        assert_eq!(gray.to_gray::<Mono1>().gray(), 0);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{
+    formats::gray::{Gray, GrayFormat},
+    pixel::{Format, raw::U16x1},
+};
+
+/// A 16-bit grayscale pixel format.
+///
+/// The gray channel ranges from `0` (black) to `65535` (white).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Gray16 {}
+
+impl crate::internal::Sealed for Gray16 {}
+
+impl Format for Gray16 {
+    type RawPixel = U16x1;
+    type Channels = Gray;
+    const NAME: &'static str = "Gray16";
+}
+
+impl GrayFormat for Gray16 {
+    const GRAY_OFFSET: usize = 0;
+}
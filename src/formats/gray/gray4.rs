@@ -0,0 +1,43 @@
+use crate::{
+    formats::gray::{Gray, GrayFormat},
+    pixel::{
+        Format,
+        packed::PackedFormat,
+        raw::{ChannelDescriptor, U8x1},
+    },
+};
+
+/// A 4-bit grayscale pixel format with 16 levels of gray.
+///
+/// The gray channel ranges from `0` (black) to `15` (white).
+///
+/// Each pixel is represented as a byte when unpacked; see [`PackedBitSlice`][] to access pixels
+/// packed 2 to a byte.
+///
+/// [`PackedBitSlice`]: crate::pixel::packed::PackedBitSlice
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Gray4 {}
+
+impl crate::internal::Sealed for Gray4 {}
+
+impl Format for Gray4 {
+    type RawPixel = U8x1;
+    type Channels = Gray;
+    const NAME: &'static str = "Gray4";
+    const BITS_PER_PIXEL: u32 = 4;
+
+    fn channel_descriptor(offset: usize) -> ChannelDescriptor {
+        assert!(offset < Self::CHANNEL_COUNT, "Offset out of bounds");
+        ChannelDescriptor {
+            shift: 0,
+            bits: Self::BITS_PER_PIXEL,
+        }
+    }
+}
+
+impl GrayFormat for Gray4 {
+    const GRAY_OFFSET: usize = 0;
+    const GRAY_MAX: f32 = 15.0 / 255.0;
+}
+
+impl PackedFormat for Gray4 {}
@@ -0,0 +1,38 @@
+use crate::{
+    formats::gray::{Gray, GrayFormat},
+    pixel::{Format, raw::U8x1},
+};
+
+/// An 8-bit grayscale pixel format.
+///
+/// The gray channel ranges from `0` (black) to `255` (white).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Gray8 {}
+
+impl crate::internal::Sealed for Gray8 {}
+
+impl Format for Gray8 {
+    type RawPixel = U8x1;
+    type Channels = Gray;
+    const NAME: &'static str = "Gray8";
+}
+
+impl GrayFormat for Gray8 {
+    const GRAY_OFFSET: usize = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::{Pixel, raw::RawPixel};
+
+    use super::*;
+
+    #[test]
+    fn with_gray() {
+        let mut pixel = Pixel::<Gray8>::with_gray(0x80);
+        assert_eq!(pixel.gray(), 0x80);
+
+        pixel.set_gray(0xFF);
+        assert_eq!(pixel.as_raw().into_inner(), 0xFF);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{
+    formats::gray::{Gray, GrayFormat},
+    pixel::{
+        Format,
+        packed::PackedFormat,
+        raw::{ChannelDescriptor, U8x1},
+    },
+};
+
+/// A 1-bit monochrome pixel format.
+///
+/// The gray channel is either `0` (black, or "off") or `1` (white, or "on").
+///
+/// Each pixel is represented as a byte when unpacked; see [`PackedBitSlice`][] to access pixels
+/// packed 8 to a byte, as used by e-paper and monochrome OLED displays.
+///
+/// [`PackedBitSlice`]: crate::pixel::packed::PackedBitSlice
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Mono1 {}
+
+impl crate::internal::Sealed for Mono1 {}
+
+impl Format for Mono1 {
+    type RawPixel = U8x1;
+    type Channels = Gray;
+    const NAME: &'static str = "Mono1";
+    const BITS_PER_PIXEL: u32 = 1;
+
+    fn channel_descriptor(offset: usize) -> ChannelDescriptor {
+        assert!(offset < Self::CHANNEL_COUNT, "Offset out of bounds");
+        ChannelDescriptor {
+            shift: 0,
+            bits: Self::BITS_PER_PIXEL,
+        }
+    }
+}
+
+impl GrayFormat for Mono1 {
+    const GRAY_OFFSET: usize = 0;
+    const GRAY_MAX: f32 = 1.0 / 255.0;
+}
+
+impl PackedFormat for Mono1 {}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::{Pixel, raw::RawPixel};
+
+    use super::*;
+
+    #[test]
+    fn with_gray() {
+        let mut pixel = Pixel::<Mono1>::with_gray(1);
+        assert_eq!(pixel.gray(), 1);
+
+        pixel.set_gray(0);
+        assert_eq!(pixel.as_raw().into_inner(), 0);
+    }
+}
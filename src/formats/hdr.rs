@@ -0,0 +1,78 @@
+//! Packed high dynamic range (HDR) pixel formats.
+//!
+//! These formats pack unsigned floating point RGB values into 32 bits, trading precision for
+//! size, and are commonly used for HDR textures and render targets. Channels are accessed as
+//! `f32`, and are encoded and decoded on access:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::hdr::{R11G11B10F, Rgb9e5},
+//!     pixel::Pixel,
+//! };
+//!
+//! let pixel = Pixel::<Rgb9e5>::with_rgb(1.0, 0.5, 0.25);
+//! assert_eq!(pixel.rgb(), [1.0, 0.5, 0.25]);
+//!
+//! // The exponent is shared, so small channels lose precision next to large ones.
+//! let pixel = Pixel::<Rgb9e5>::with_rgb(1.0, 0.5, 4096.0);
+//! assert_eq!(pixel.rgb(), [0.0, 0.0, 4096.0]);
+//!
+//! // Each channel has its own exponent, with fewer mantissa bits.
+//! let pixel = Pixel::<R11G11B10F>::with_rgb(1.0, 0.5, 4096.0);
+//! assert_eq!(pixel.rgb(), [1.0, 0.5, 4096.0]);
+//! ```
+
+mod r11g11b10f;
+pub use r11g11b10f::R11G11B10F;
+
+mod rgb9e5;
+pub use rgb9e5::Rgb9e5;
+
+pub use crate::formats::rgb::Rgb;
+
+/// Channels representing the `R`ed, `G`reen, and `B`lue mantissas, and the shared `E`xponent of a
+/// shared-exponent pixel.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Rgbe {
+    Red,
+    Green,
+    Blue,
+    Exponent,
+}
+
+/// Returns `2^exponent` as an `f32`.
+///
+/// `exponent` must be in the normal range of `f32` (`-126..=127`).
+#[allow(clippy::cast_sign_loss)]
+fn exp2i(exponent: i32) -> f32 {
+    debug_assert!((-126..=127).contains(&exponent));
+    f32::from_bits(((exponent + 127) as u32) << 23)
+}
+
+/// Returns `floor(log2(value))` for a positive, normal `f32`.
+#[allow(clippy::cast_possible_wrap)]
+fn floor_log2(value: f32) -> i32 {
+    ((value.to_bits() >> 23) & 0xFF) as i32 - 127
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn exp2i_values() {
+        assert_eq!(exp2i(0), 1.0);
+        assert_eq!(exp2i(3), 8.0);
+        assert_eq!(exp2i(-2), 0.25);
+    }
+
+    #[test]
+    fn floor_log2_values() {
+        assert_eq!(floor_log2(1.0), 0);
+        assert_eq!(floor_log2(1.9), 0);
+        assert_eq!(floor_log2(8.0), 3);
+        assert_eq!(floor_log2(0.3), -2);
+    }
+}
@@ -0,0 +1,255 @@
+use crate::{
+    formats::hdr::{Rgb, exp2i},
+    pixel::{
+        ChannelOf, Format, Pixel,
+        raw::{RawPixel, U32x111110},
+    },
+};
+
+/// A 32-bit packed RGB pixel format with unsigned small floating point channels.
+///
+/// This format is used to represent unsigned floating point pixels in the RGB order:
+/// - `R`ed (11 bits: 5-bit exponent, 6-bit mantissa)
+/// - `G`reen (11 bits: 5-bit exponent, 6-bit mantissa)
+/// - `B`lue (10 bits: 5-bit exponent, 5-bit mantissa)
+///
+/// The pixel is represented as a 32-bit unsigned integer, compatible with
+/// `DXGI_FORMAT_R11G11B10_FLOAT` and `GL_R11F_G11F_B10F`.
+///
+/// Channels are rounded to the nearest representable value. Negative values are encoded as `0.0`,
+/// finite values above the largest representable value are clamped to it, and infinity and `NaN`
+/// are preserved.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[allow(non_camel_case_types)]
+pub enum R11G11B10F {}
+
+impl R11G11B10F {
+    /// The number of mantissa bits of each channel.
+    const MANTISSA_BITS: [u32; 3] = [6, 6, 5];
+
+    /// The largest finite value of the red and green channels (`65024.0`).
+    pub const MAX_RG: f32 = 65024.0;
+
+    /// The largest finite value of the blue channel (`64512.0`).
+    pub const MAX_B: f32 = 64512.0;
+}
+
+impl crate::internal::Sealed for R11G11B10F {}
+
+impl Format for R11G11B10F {
+    type RawPixel = U32x111110;
+    type Channels = Rgb;
+    const NAME: &'static str = "R11G11B10F";
+}
+
+impl ChannelOf<R11G11B10F> for Rgb {
+    const ALL: &'static [Self] = &[Self::Red, Self::Green, Self::Blue];
+
+    fn offset(self) -> usize {
+        self as usize
+    }
+}
+
+/// Shifts `value` right by `shift` bits, rounding to the nearest value (ties to even).
+fn shift_round(value: u32, shift: u32) -> u32 {
+    if shift >= 32 {
+        return 0;
+    }
+    let quotient = value >> shift;
+    let remainder = value & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if remainder > half || (remainder == half && quotient & 1 == 1) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+/// Encodes an `f32` as an unsigned float with a 5-bit exponent and `mantissa_bits` of mantissa.
+#[allow(clippy::cast_sign_loss)]
+fn encode(value: f32, mantissa_bits: u32) -> u32 {
+    let infinity = 0x1F << mantissa_bits;
+    if value.is_nan() {
+        return infinity | 1 << (mantissa_bits - 1);
+    }
+    if value <= 0.0 {
+        return 0;
+    }
+    if value.is_infinite() {
+        return infinity;
+    }
+
+    let bits = value.to_bits();
+    let exponent = ((bits >> 23) & 0xFF).cast_signed() - 127 + 15;
+    let mantissa = bits & 0x7F_FFFF;
+    let shift = 23 - mantissa_bits;
+    let max = infinity - 1;
+
+    let encoded = if exponent <= 0 {
+        // Subnormal: shift the implicit leading one into the mantissa.
+        shift_round(mantissa | 0x80_0000, shift + (1 - exponent) as u32)
+    } else if exponent >= 31 {
+        max
+    } else {
+        // A rounding carry out of the mantissa correctly increments the exponent.
+        shift_round((exponent as u32) << 23 | mantissa, shift)
+    };
+    encoded.min(max)
+}
+
+/// Decodes an unsigned float with a 5-bit exponent and `mantissa_bits` of mantissa to an `f32`.
+#[allow(clippy::cast_precision_loss)]
+fn decode(value: u32, mantissa_bits: u32) -> f32 {
+    let exponent = value >> mantissa_bits;
+    let mantissa = value & ((1 << mantissa_bits) - 1);
+    match exponent {
+        0 => mantissa as f32 * exp2i(-14 - mantissa_bits.cast_signed()),
+        0x1F if mantissa == 0 => f32::INFINITY,
+        0x1F => f32::NAN,
+        _ => f32::from_bits((exponent + 127 - 15) << 23 | mantissa << (23 - mantissa_bits)),
+    }
+}
+
+impl Pixel<R11G11B10F> {
+    /// Creates a new pixel from RGB channel values.
+    #[must_use]
+    pub fn with_rgb(r: f32, g: f32, b: f32) -> Self {
+        let mut pixel = Self::zeroed();
+        pixel.set_red(r).set_green(g).set_blue(b);
+        pixel
+    }
+
+    /// Returns the decoded red, green, and blue channel values of the pixel.
+    #[must_use]
+    pub fn rgb(&self) -> [f32; 3] {
+        [self.red(), self.green(), self.blue()]
+    }
+
+    /// Returns the decoded value of the channel at `offset`.
+    fn decode_channel(self, offset: usize) -> f32 {
+        decode(
+            self.as_raw().get_channel(offset),
+            R11G11B10F::MANTISSA_BITS[offset],
+        )
+    }
+
+    /// Encodes and sets the value of the channel at `offset`.
+    fn encode_channel(&mut self, offset: usize, value: f32) -> &mut Self {
+        let encoded = encode(value, R11G11B10F::MANTISSA_BITS[offset]);
+        self.as_raw_mut().set_channel(offset, encoded);
+        self
+    }
+
+    /// Returns the decoded red channel value of the pixel.
+    #[must_use]
+    pub fn red(&self) -> f32 {
+        self.decode_channel(0)
+    }
+
+    /// Encodes and sets the red channel value of the pixel.
+    pub fn set_red(&mut self, value: f32) -> &mut Self {
+        self.encode_channel(0, value)
+    }
+
+    /// Returns the decoded green channel value of the pixel.
+    #[must_use]
+    pub fn green(&self) -> f32 {
+        self.decode_channel(1)
+    }
+
+    /// Encodes and sets the green channel value of the pixel.
+    pub fn set_green(&mut self, value: f32) -> &mut Self {
+        self.encode_channel(1, value)
+    }
+
+    /// Returns the decoded blue channel value of the pixel.
+    #[must_use]
+    pub fn blue(&self) -> f32 {
+        self.decode_channel(2)
+    }
+
+    /// Encodes and sets the blue channel value of the pixel.
+    pub fn set_blue(&mut self, value: f32) -> &mut Self {
+        self.encode_channel(2, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_one() {
+        // 1.0 has a biased exponent of 15 and an empty mantissa.
+        let pixel = Pixel::<R11G11B10F>::with_rgb(1.0, 1.0, 1.0);
+        assert_eq!(
+            pixel.as_raw().into_inner(),
+            15 << 6 | (15 << 6) << 11 | (15 << 5) << 22
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn exact_values() {
+        for value in [0.0, 0.5, 1.0, 1.5, 3.25, 1024.0, R11G11B10F::MAX_B] {
+            let pixel = Pixel::<R11G11B10F>::with_rgb(value, value, value);
+            assert_eq!(pixel.rgb(), [value; 3]);
+        }
+        let pixel = Pixel::<R11G11B10F>::with_rgb(R11G11B10F::MAX_RG, 0.25, 2.0);
+        assert_eq!(pixel.rgb(), [R11G11B10F::MAX_RG, 0.25, 2.0]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn special_values() {
+        let pixel = Pixel::<R11G11B10F>::with_rgb(-1.0, 1e10, f32::INFINITY);
+        assert_eq!(pixel.rgb(), [0.0, R11G11B10F::MAX_RG, f32::INFINITY]);
+
+        let pixel = Pixel::<R11G11B10F>::with_rgb(f32::NAN, f32::NEG_INFINITY, f32::NAN);
+        assert!(pixel.red().is_nan());
+        assert_eq!(pixel.green(), 0.0);
+        assert!(pixel.blue().is_nan());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn rounding() {
+        // 1 + 1/128 is halfway between 1.0 and 1 + 1/64, and rounds to the even mantissa.
+        let mut pixel = Pixel::<R11G11B10F>::zeroed();
+        pixel.set_red(1.0 + 1.0 / 128.0);
+        assert_eq!(pixel.red(), 1.0);
+
+        pixel.set_red(1.0 + 3.0 / 128.0);
+        assert_eq!(pixel.red(), 1.0 + 2.0 / 64.0);
+
+        // Rounding up out of the mantissa carries into the exponent.
+        pixel.set_red(1.999);
+        assert_eq!(pixel.red(), 2.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn subnormals() {
+        let smallest = exp2i(-20);
+        let mut pixel = Pixel::<R11G11B10F>::zeroed();
+        pixel.set_red(smallest).set_blue(smallest);
+        assert_eq!(pixel.red(), smallest);
+        assert_eq!(pixel.blue(), 0.0);
+
+        pixel.set_green(exp2i(-14) - smallest);
+        assert_eq!(pixel.green(), exp2i(-14) - smallest);
+
+        pixel.set_red(1e-30);
+        assert_eq!(pixel.red(), 0.0);
+    }
+
+    #[test]
+    fn relative_error() {
+        let mut pixel = Pixel::<R11G11B10F>::zeroed();
+        for value in [0.001, 0.1, 0.7, 12.34, 1000.0, 60000.0] {
+            pixel.set_red(value).set_blue(value);
+            assert!((pixel.red() - value).abs() / value <= 1.0 / 128.0);
+            assert!((pixel.blue() - value).abs() / value <= 1.0 / 64.0);
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{
+    formats::hdr::{Rgbe, exp2i, floor_log2},
+    pixel::{
+        ChannelOf, Format, Pixel,
+        raw::{RawPixel, U32x9995},
+    },
+};
+
+/// A 32-bit shared-exponent RGB pixel format.
+///
+/// This format is used to represent unsigned floating point pixels in the RGB order, with
+/// three 9-bit mantissas and a single 5-bit exponent shared by all three channels:
+/// - `R`ed mantissa (9 bits)
+/// - `G`reen mantissa (9 bits)
+/// - `B`lue mantissa (9 bits)
+/// - `E`xponent (5 bits)
+///
+/// The pixel is represented as a 32-bit unsigned integer, compatible with
+/// `DXGI_FORMAT_R9G9B9E5_SHAREDEXP` and `GL_RGB9_E5`.
+///
+/// Because the exponent is shared, channels much smaller than the largest channel lose precision,
+/// and channels can only be set together using [`Pixel::set_rgb`]. Negative and `NaN` values are
+/// encoded as `0.0`, and values above [`Rgb9e5::MAX`] are clamped.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Rgb9e5 {}
+
+impl Rgb9e5 {
+    /// The number of mantissa bits of each channel.
+    const MANTISSA_BITS: i32 = 9;
+
+    /// The exponent bias.
+    const EXPONENT_BIAS: i32 = 15;
+
+    /// The largest representable channel value (`65408.0`).
+    pub const MAX: f32 = 65408.0;
+
+    /// Encodes RGB values into the raw representation.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn encode(rgb: [f32; 3]) -> U32x9995 {
+        // Written as a comparison (rather than `clamp`) so that NaN is encoded as zero.
+        let rgb = rgb.map(|c| if c > 0.0 { c.min(Self::MAX) } else { 0.0 });
+        let max = rgb[0].max(rgb[1]).max(rgb[2]);
+
+        let min_exponent = -Self::EXPONENT_BIAS - 1;
+        let mut exponent = if max < exp2i(min_exponent) {
+            min_exponent
+        } else {
+            floor_log2(max)
+        } + 1
+            + Self::EXPONENT_BIAS;
+
+        let scale = |exponent: i32| exp2i(exponent - Self::EXPONENT_BIAS - Self::MANTISSA_BITS);
+        if (max / scale(exponent) + 0.5) as u32 == 1 << Self::MANTISSA_BITS {
+            exponent += 1;
+        }
+
+        let scale = scale(exponent);
+        let [r, g, b] = rgb.map(|c| (c / scale + 0.5) as u32);
+        U32x9995::from_u32(r | g << 9 | b << 18 | (exponent as u32) << 27)
+    }
+
+    /// Decodes RGB values from the raw representation.
+    #[allow(clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+    fn decode(raw: U32x9995) -> [f32; 3] {
+        let bits = raw.into_inner();
+        let exponent = (bits >> 27) as i32;
+        let scale = exp2i(exponent - Self::EXPONENT_BIAS - Self::MANTISSA_BITS);
+        [0, 9, 18].map(|shift| ((bits >> shift) & 0x1FF) as f32 * scale)
+    }
+}
+
+impl crate::internal::Sealed for Rgb9e5 {}
+
+impl Format for Rgb9e5 {
+    type RawPixel = U32x9995;
+    type Channels = Rgbe;
+    const NAME: &'static str = "Rgb9e5";
+}
+
+impl ChannelOf<Rgb9e5> for Rgbe {
+    const ALL: &'static [Self] = &[Self::Red, Self::Green, Self::Blue, Self::Exponent];
+
+    fn offset(self) -> usize {
+        self as usize
+    }
+}
+
+impl Pixel<Rgb9e5> {
+    /// Creates a new pixel from RGB channel values.
+    #[must_use]
+    pub fn with_rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::from_raw(Rgb9e5::encode([r, g, b]))
+    }
+
+    /// Returns the decoded red, green, and blue channel values of the pixel.
+    #[must_use]
+    pub fn rgb(&self) -> [f32; 3] {
+        Rgb9e5::decode(*self.as_raw())
+    }
+
+    /// Sets the red, green, and blue channel values of the pixel.
+    pub fn set_rgb(&mut self, r: f32, g: f32, b: f32) -> &mut Self {
+        *self.as_raw_mut() = Rgb9e5::encode([r, g, b]);
+        self
+    }
+
+    /// Returns the decoded red channel value of the pixel.
+    #[must_use]
+    pub fn red(&self) -> f32 {
+        self.rgb()[0]
+    }
+
+    /// Returns the decoded green channel value of the pixel.
+    #[must_use]
+    pub fn green(&self) -> f32 {
+        self.rgb()[1]
+    }
+
+    /// Returns the decoded blue channel value of the pixel.
+    #[must_use]
+    pub fn blue(&self) -> f32 {
+        self.rgb()[2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn new_zeroed() {
+        let pixel: Pixel<Rgb9e5> = Pixel::zeroed();
+        assert_eq!(pixel.rgb(), [0.0; 3]);
+    }
+
+    #[test]
+    fn encode_one() {
+        // 1.0 = 256 * 2^(16 - 15 - 9)
+        let pixel = Pixel::<Rgb9e5>::with_rgb(1.0, 0.0, 0.0);
+        assert_eq!(pixel.as_raw().into_inner(), 256 | 16 << 27);
+        assert_eq!(pixel.as_raw().get_channel(3), 16);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn exact_values() {
+        for rgb in [
+            [1.0, 0.5, 0.25],
+            [0.0, 0.0, 0.0],
+            [Rgb9e5::MAX, 0.0, 0.0],
+            [3.0, 2.0, 1.0],
+        ] {
+            let pixel = Pixel::<Rgb9e5>::with_rgb(rgb[0], rgb[1], rgb[2]);
+            assert_eq!(pixel.rgb(), rgb);
+        }
+        let pixel = Pixel::<Rgb9e5>::with_rgb(0.25, 0.5, 0.75);
+        assert_eq!(
+            (pixel.red(), pixel.green(), pixel.blue()),
+            (0.25, 0.5, 0.75)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn clamps() {
+        let pixel = Pixel::<Rgb9e5>::with_rgb(-1.0, f32::NAN, f32::INFINITY);
+        assert_eq!(pixel.rgb(), [0.0, 0.0, Rgb9e5::MAX]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn mantissa_rounds_up_to_next_exponent() {
+        // Rounds to 512 * 2^-9, which does not fit in 9 bits, so the exponent is bumped.
+        let pixel = Pixel::<Rgb9e5>::with_rgb(0.9995, 0.0, 0.0);
+        assert_eq!(pixel.rgb(), [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn relative_error() {
+        let mut pixel = Pixel::<Rgb9e5>::zeroed();
+        for value in [0.001, 0.1, 0.7, 12.34, 1000.0, 60000.0] {
+            pixel.set_rgb(value, value, value);
+            let [r, g, b] = pixel.rgb();
+            for decoded in [r, g, b] {
+                assert!(
+                    (decoded - value).abs() / value < 1.0 / 256.0,
+                    "{value} {decoded}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn tiny_values() {
+        let pixel = Pixel::<Rgb9e5>::with_rgb(1e-20, 0.0, 0.0);
+        assert_eq!(pixel.rgb(), [0.0; 3]);
+    }
+}
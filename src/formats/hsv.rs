@@ -0,0 +1,322 @@
+//! HSV and HSL (cylindrical RGB) pixel formats.
+//!
+//! Hue is represented in degrees, in the range `0.0..360.0`, and saturation, value, and lightness
+//! are represented in the range `0.0..=1.0`. Pixels can be converted to and from [`FloatRgba`]
+//! using [`From`]; the alpha channel is preserved:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::{hsv::HsvaF32, rgba::FloatRgba},
+//!     pixel::Pixel,
+//! };
+//!
+//! let orange = Pixel::<FloatRgba>::with_rgba(1.0, 0.5, 0.0, 1.0);
+//! let mut hsva = Pixel::<HsvaF32>::from(orange);
+//! assert_eq!((hsva.hue(), hsva.saturation(), hsva.value()), (30.0, 1.0, 1.0));
+//!
+//! hsva.set_hue(210.0);
+//! let azure = Pixel::<FloatRgba>::from(hsva);
+//! assert_eq!(azure, Pixel::with_rgba(0.0, 0.5, 1.0, 1.0));
+//! ```
+//!
+//! [`FloatRgba`]: crate::formats::rgba::FloatRgba
+
+mod hsla_f32;
+pub use hsla_f32::HslaF32;
+
+mod hsva_f32;
+pub use hsva_f32::HsvaF32;
+
+use crate::{
+    alpha::AlphaFormat,
+    pixel::{ChannelOf, Format, Pixel, raw::RawPixel},
+};
+
+/// Channels representing `H`ue, `S`aturation, `V`alue, and `A`lpha components of a pixel.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Hsva {
+    Hue,
+    Saturation,
+    Value,
+    Alpha,
+}
+
+/// Channels representing `H`ue, `S`aturation, `L`ightness, and `A`lpha components of a pixel.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Hsla {
+    Hue,
+    Saturation,
+    Lightness,
+    Alpha,
+}
+
+/// A pixel format with hue and saturation channels.
+///
+/// Shared by [`HsvaFormat`] and [`HslaFormat`], which define the remaining channels:
+/// - `HUE_OFFSET`: Offset of the hue channel.
+/// - `SATURATION_OFFSET`: Offset of the saturation channel.
+pub trait HueFormat: AlphaFormat {
+    /// The offset of the hue channel in the pixel's raw representation.
+    const HUE_OFFSET: usize;
+
+    /// The offset of the saturation channel in the pixel's raw representation.
+    const SATURATION_OFFSET: usize;
+
+    /// Returns the hue channel value of a pixel.
+    fn get_hue(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::HUE_OFFSET)
+    }
+
+    /// Sets the hue channel value of a pixel.
+    fn set_hue(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::HUE_OFFSET, value);
+    }
+
+    /// Returns the saturation channel value of a pixel.
+    fn get_saturation(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::SATURATION_OFFSET)
+    }
+
+    /// Sets the saturation channel value of a pixel.
+    fn set_saturation(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::SATURATION_OFFSET, value);
+    }
+}
+
+/// A pixel format with hue, saturation, value, and alpha channels.
+///
+/// - `VALUE_OFFSET`: Offset of the value channel.
+pub trait HsvaFormat: HueFormat + Format<Channels = Hsva> {
+    /// The offset of the value channel in the pixel's raw representation.
+    const VALUE_OFFSET: usize;
+
+    /// Returns the value channel value of a pixel.
+    fn get_value(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::VALUE_OFFSET)
+    }
+
+    /// Sets the value channel value of a pixel.
+    fn set_value(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::VALUE_OFFSET, value);
+    }
+}
+
+/// A pixel format with hue, saturation, lightness, and alpha channels.
+///
+/// - `LIGHTNESS_OFFSET`: Offset of the lightness channel.
+pub trait HslaFormat: HueFormat + Format<Channels = Hsla> {
+    /// The offset of the lightness channel in the pixel's raw representation.
+    const LIGHTNESS_OFFSET: usize;
+
+    /// Returns the lightness channel value of a pixel.
+    fn get_lightness(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::LIGHTNESS_OFFSET)
+    }
+
+    /// Sets the lightness channel value of a pixel.
+    fn set_lightness(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::LIGHTNESS_OFFSET, value);
+    }
+}
+
+impl<F: HsvaFormat> ChannelOf<F> for Hsva {
+    const ALL: &'static [Self] = &[Self::Hue, Self::Saturation, Self::Value, Self::Alpha];
+
+    fn offset(self) -> usize {
+        match self {
+            Self::Hue => F::HUE_OFFSET,
+            Self::Saturation => F::SATURATION_OFFSET,
+            Self::Value => F::VALUE_OFFSET,
+            Self::Alpha => F::ALPHA_OFFSET,
+        }
+    }
+}
+
+impl<F: HslaFormat> ChannelOf<F> for Hsla {
+    const ALL: &'static [Self] = &[Self::Hue, Self::Saturation, Self::Lightness, Self::Alpha];
+
+    fn offset(self) -> usize {
+        match self {
+            Self::Hue => F::HUE_OFFSET,
+            Self::Saturation => F::SATURATION_OFFSET,
+            Self::Lightness => F::LIGHTNESS_OFFSET,
+            Self::Alpha => F::ALPHA_OFFSET,
+        }
+    }
+}
+
+impl<F: HueFormat> Pixel<F> {
+    /// Returns the hue channel value of the pixel.
+    pub fn hue(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_hue(self.as_raw())
+    }
+
+    /// Sets the hue channel value of the pixel.
+    pub fn set_hue(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_hue(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Returns the saturation channel value of the pixel.
+    pub fn saturation(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_saturation(self.as_raw())
+    }
+
+    /// Sets the saturation channel value of the pixel.
+    pub fn set_saturation(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_saturation(self.as_raw_mut(), value);
+        self
+    }
+}
+
+impl<F: HsvaFormat> Pixel<F> {
+    /// Returns the value channel value of the pixel.
+    pub fn value(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_value(self.as_raw())
+    }
+
+    /// Sets the value channel value of the pixel.
+    pub fn set_value(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_value(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Creates a new pixel from HSVA channel values.
+    pub fn with_hsva(
+        h: <F::RawPixel as RawPixel>::Channel,
+        s: <F::RawPixel as RawPixel>::Channel,
+        v: <F::RawPixel as RawPixel>::Channel,
+        a: <F::RawPixel as RawPixel>::Channel,
+    ) -> Self {
+        let mut pixel = Self::from_raw(F::RawPixel::DEFAULT);
+        pixel.set_hue(h).set_saturation(s).set_value(v).set_alpha(a);
+        pixel
+    }
+}
+
+impl<F: HslaFormat> Pixel<F> {
+    /// Returns the lightness channel value of the pixel.
+    pub fn lightness(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_lightness(self.as_raw())
+    }
+
+    /// Sets the lightness channel value of the pixel.
+    pub fn set_lightness(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_lightness(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Creates a new pixel from HSLA channel values.
+    pub fn with_hsla(
+        h: <F::RawPixel as RawPixel>::Channel,
+        s: <F::RawPixel as RawPixel>::Channel,
+        l: <F::RawPixel as RawPixel>::Channel,
+        a: <F::RawPixel as RawPixel>::Channel,
+    ) -> Self {
+        let mut pixel = Self::from_raw(F::RawPixel::DEFAULT);
+        pixel
+            .set_hue(h)
+            .set_saturation(s)
+            .set_lightness(l)
+            .set_alpha(a);
+        pixel
+    }
+}
+
+/// Returns the hue (in degrees), and the maximum and minimum channel values of an RGB color.
+#[allow(clippy::float_cmp)] // `max` is always exactly one of the channels.
+fn hue_max_min(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let sector = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        let sector = (g - b) / delta;
+        if sector < 0.0 { sector + 6.0 } else { sector }
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    (sector * 60.0, max, min)
+}
+
+/// Wraps a hue in degrees to the range `0.0..360.0`.
+fn wrap_hue(hue: f32) -> f32 {
+    let hue = hue % 360.0;
+    if hue < 0.0 { hue + 360.0 } else { hue }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::FloatRgba;
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn with_hsva() {
+        let pixel = Pixel::<HsvaF32>::with_hsva(120.0, 0.5, 0.25, 1.0);
+        assert_eq!(pixel.hue(), 120.0);
+        assert_eq!(pixel.saturation(), 0.5);
+        assert_eq!(pixel.value(), 0.25);
+        assert_eq!(pixel.alpha(), 1.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn with_hsla() {
+        let pixel = Pixel::<HslaF32>::with_hsla(240.0, 0.5, 0.75, 0.0);
+        assert_eq!(pixel.hue(), 240.0);
+        assert_eq!(pixel.saturation(), 0.5);
+        assert_eq!(pixel.lightness(), 0.75);
+        assert_eq!(pixel.alpha(), 0.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn hue_primaries() {
+        assert_eq!(hue_max_min(1.0, 0.0, 0.0).0, 0.0);
+        assert_eq!(hue_max_min(1.0, 1.0, 0.0).0, 60.0);
+        assert_eq!(hue_max_min(0.0, 1.0, 0.0).0, 120.0);
+        assert_eq!(hue_max_min(0.0, 1.0, 1.0).0, 180.0);
+        assert_eq!(hue_max_min(0.0, 0.0, 1.0).0, 240.0);
+        assert_eq!(hue_max_min(1.0, 0.0, 1.0).0, 300.0);
+        assert_eq!(hue_max_min(0.5, 0.5, 0.5).0, 0.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn wrap_hue_range() {
+        assert_eq!(wrap_hue(0.0), 0.0);
+        assert_eq!(wrap_hue(360.0), 0.0);
+        assert_eq!(wrap_hue(-30.0), 330.0);
+        assert_eq!(wrap_hue(750.0), 30.0);
+    }
+
+    #[test]
+    fn round_trip() {
+        for (r, g, b) in [
+            (0.0, 0.0, 0.0),
+            (1.0, 1.0, 1.0),
+            (0.2, 0.4, 0.6),
+            (0.9, 0.1, 0.3),
+            (0.5, 0.5, 0.25),
+        ] {
+            let rgba = Pixel::<FloatRgba>::with_rgba(r, g, b, 0.5);
+            for back in [
+                Pixel::<FloatRgba>::from(Pixel::<HsvaF32>::from(rgba)),
+                Pixel::<FloatRgba>::from(Pixel::<HslaF32>::from(rgba)),
+            ] {
+                assert!((back.red() - r).abs() < 1e-6, "{rgba:?} {back:?}");
+                assert!((back.green() - g).abs() < 1e-6, "{rgba:?} {back:?}");
+                assert!((back.blue() - b).abs() < 1e-6, "{rgba:?} {back:?}");
+                assert!((back.alpha() - 0.5).abs() < 1e-6, "{rgba:?} {back:?}");
+            }
+        }
+    }
+}
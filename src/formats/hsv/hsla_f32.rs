@@ -0,0 +1,106 @@
+use crate::{
+    alpha::AlphaFormat,
+    formats::{
+        hsv::{Hsla, HslaFormat, HueFormat, hue_max_min, wrap_hue},
+        rgba::FloatRgba,
+    },
+    pixel::{Format, Pixel, raw::F32x4},
+};
+
+/// A 128-bit HSLA pixel format with four 32-bit floating point channels.
+///
+/// This format is used to represent pixels in the HSLA order:
+/// - `H`ue (32 bits, in degrees)
+/// - `S`aturation (32 bits)
+/// - `L`ightness (32 bits)
+/// - `A`lpha (32 bits)
+///
+/// The pixel is represented as a 128-bit value, where each channel occupies 32 bits.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum HslaF32 {}
+
+impl crate::internal::Sealed for HslaF32 {}
+
+impl Format for HslaF32 {
+    type RawPixel = F32x4;
+    type Channels = Hsla;
+    const NAME: &'static str = "HslaF32";
+}
+
+impl AlphaFormat for HslaF32 {
+    const ALPHA_OFFSET: usize = 3;
+}
+
+impl HueFormat for HslaF32 {
+    const HUE_OFFSET: usize = 0;
+    const SATURATION_OFFSET: usize = 1;
+}
+
+impl HslaFormat for HslaF32 {
+    const LIGHTNESS_OFFSET: usize = 2;
+}
+
+impl From<Pixel<FloatRgba>> for Pixel<HslaF32> {
+    fn from(rgba: Pixel<FloatRgba>) -> Self {
+        let (hue, max, min) = hue_max_min(rgba.red(), rgba.green(), rgba.blue());
+        let lightness = f32::midpoint(max, min);
+        let range = lightness.min(1.0 - lightness);
+        let saturation = if range == 0.0 {
+            0.0
+        } else {
+            (max - lightness) / range
+        };
+        Pixel::with_hsla(hue, saturation, lightness, rgba.alpha())
+    }
+}
+
+impl From<Pixel<HslaF32>> for Pixel<FloatRgba> {
+    fn from(hsla: Pixel<HslaF32>) -> Self {
+        let sector = wrap_hue(hsla.hue()) / 30.0;
+        let lightness = hsla.lightness();
+        let chroma = hsla.saturation() * lightness.min(1.0 - lightness);
+        let channel = |n: f32| {
+            let k = (n + sector) % 12.0;
+            lightness - chroma * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        Pixel::with_rgba(channel(0.0), channel(8.0), channel(4.0), hsla.alpha())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::raw::RawPixel;
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn from_raw() {
+        let pixel = Pixel::<HslaF32>::from_raw([90.0, 0.25, 0.75, 1.0].into());
+        assert_eq!(pixel.as_raw().into_inner(), [90.0, 0.25, 0.75, 1.0]);
+        assert_eq!(pixel.hue(), 90.0);
+        assert_eq!(pixel.saturation(), 0.25);
+        assert_eq!(pixel.lightness(), 0.75);
+        assert_eq!(pixel.alpha(), 1.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn from_rgba() {
+        let pixel = Pixel::<HslaF32>::from(Pixel::<FloatRgba>::with_rgba(0.0, 0.5, 0.25, 1.0));
+        assert_eq!(pixel, Pixel::with_hsla(150.0, 1.0, 0.25, 1.0));
+
+        let white = Pixel::<HslaF32>::from(Pixel::<FloatRgba>::with_rgba(1.0, 1.0, 1.0, 1.0));
+        assert_eq!(white, Pixel::with_hsla(0.0, 0.0, 1.0, 1.0));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn into_rgba() {
+        let red = Pixel::<FloatRgba>::from(Pixel::<HslaF32>::with_hsla(0.0, 1.0, 0.5, 1.0));
+        assert_eq!(red, Pixel::with_rgba(1.0, 0.0, 0.0, 1.0));
+
+        let pink = Pixel::<FloatRgba>::from(Pixel::<HslaF32>::with_hsla(300.0, 1.0, 0.75, 0.5));
+        assert_eq!(pink, Pixel::with_rgba(1.0, 0.5, 1.0, 0.5));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    alpha::AlphaFormat,
+    formats::{
+        hsv::{Hsva, HsvaFormat, HueFormat, hue_max_min, wrap_hue},
+        rgba::FloatRgba,
+    },
+    pixel::{Format, Pixel, raw::F32x4},
+};
+
+/// A 128-bit HSVA pixel format with four 32-bit floating point channels.
+///
+/// This format is used to represent pixels in the HSVA order:
+/// - `H`ue (32 bits, in degrees)
+/// - `S`aturation (32 bits)
+/// - `V`alue (32 bits)
+/// - `A`lpha (32 bits)
+///
+/// The pixel is represented as a 128-bit value, where each channel occupies 32 bits.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum HsvaF32 {}
+
+impl crate::internal::Sealed for HsvaF32 {}
+
+impl Format for HsvaF32 {
+    type RawPixel = F32x4;
+    type Channels = Hsva;
+    const NAME: &'static str = "HsvaF32";
+}
+
+impl AlphaFormat for HsvaF32 {
+    const ALPHA_OFFSET: usize = 3;
+}
+
+impl HueFormat for HsvaF32 {
+    const HUE_OFFSET: usize = 0;
+    const SATURATION_OFFSET: usize = 1;
+}
+
+impl HsvaFormat for HsvaF32 {
+    const VALUE_OFFSET: usize = 2;
+}
+
+impl From<Pixel<FloatRgba>> for Pixel<HsvaF32> {
+    fn from(rgba: Pixel<FloatRgba>) -> Self {
+        let (hue, max, min) = hue_max_min(rgba.red(), rgba.green(), rgba.blue());
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Pixel::with_hsva(hue, saturation, max, rgba.alpha())
+    }
+}
+
+impl From<Pixel<HsvaF32>> for Pixel<FloatRgba> {
+    fn from(hsva: Pixel<HsvaF32>) -> Self {
+        let sector = wrap_hue(hsva.hue()) / 60.0;
+        let chroma = hsva.value() * hsva.saturation();
+        let channel = |n: f32| {
+            let k = (n + sector) % 6.0;
+            hsva.value() - chroma * k.min(4.0 - k).clamp(0.0, 1.0)
+        };
+        Pixel::with_rgba(channel(5.0), channel(3.0), channel(1.0), hsva.alpha())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::raw::RawPixel;
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn from_raw() {
+        let pixel = Pixel::<HsvaF32>::from_raw([90.0, 0.25, 0.75, 1.0].into());
+        assert_eq!(pixel.as_raw().into_inner(), [90.0, 0.25, 0.75, 1.0]);
+        assert_eq!(pixel.hue(), 90.0);
+        assert_eq!(pixel.saturation(), 0.25);
+        assert_eq!(pixel.value(), 0.75);
+        assert_eq!(pixel.alpha(), 1.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn from_rgba() {
+        let pixel = Pixel::<HsvaF32>::from(Pixel::<FloatRgba>::with_rgba(0.0, 0.5, 0.25, 1.0));
+        assert_eq!(pixel, Pixel::with_hsva(150.0, 1.0, 0.5, 1.0));
+
+        let gray = Pixel::<HsvaF32>::from(Pixel::<FloatRgba>::with_rgba(0.5, 0.5, 0.5, 0.0));
+        assert_eq!(gray, Pixel::with_hsva(0.0, 0.0, 0.5, 0.0));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn into_rgba() {
+        let red = Pixel::<FloatRgba>::from(Pixel::<HsvaF32>::with_hsva(360.0, 1.0, 1.0, 1.0));
+        assert_eq!(red, Pixel::with_rgba(1.0, 0.0, 0.0, 1.0));
+
+        let teal = Pixel::<FloatRgba>::from(Pixel::<HsvaF32>::with_hsva(-180.0, 1.0, 0.5, 1.0));
+        assert_eq!(teal, Pixel::with_rgba(0.0, 0.5, 0.5, 1.0));
+    }
+}
@@ -0,0 +1,382 @@
+//! Indexed (paletted) pixel formats.
+//!
+//! An indexed pixel does not store a color directly, but an index into a [`Palette`] of colors.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::{indexed::{Indexed8, Palette}, rgba::Rgba8888},
+//!     pixel::Pixel,
+//! };
+//!
+//! let palette = Palette::from_slice(&[
+//!     Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0x00, 0xFF),
+//!     Pixel::<Rgba8888>::with_rgba(0xFF, 0x00, 0x00, 0xFF),
+//! ]);
+//!
+//! let pixel = Pixel::<Indexed8>::with_index(1);
+//! assert_eq!(pixel.resolve(&palette).red(), 0xFF);
+//! ```
+
+mod indexed8;
+pub use indexed8::Indexed8;
+
+use crate::{
+    formats::rgba::RgbaFormat,
+    norm::Normalized,
+    pixel::{ChannelOf, Format, Pixel, raw::RawPixel},
+};
+
+/// The single channel of an indexed pixel, an index into a [`Palette`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Indexed {
+    Index,
+}
+
+/// A pixel format where each pixel is an index into a [`Palette`].
+///
+/// The offset of the index channel in the pixel's raw representation is defined by this trait:
+/// - `INDEX_OFFSET`: Offset of the index channel.
+pub trait IndexedFormat: Format<Channels = Indexed> {
+    /// The offset of the index channel in the pixel's raw representation.
+    const INDEX_OFFSET: usize;
+
+    /// Returns the palette index of a pixel.
+    fn get_index(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::INDEX_OFFSET)
+    }
+
+    /// Sets the palette index of a pixel.
+    fn set_index(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::INDEX_OFFSET, value);
+    }
+}
+
+impl<F: IndexedFormat> ChannelOf<F> for Indexed {
+    const ALL: &'static [Self] = &[Self::Index];
+
+    fn offset(self) -> usize {
+        match self {
+            Self::Index => F::INDEX_OFFSET,
+        }
+    }
+}
+
+impl<F: IndexedFormat> Pixel<F> {
+    /// Returns the palette index of the pixel.
+    pub fn index(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_index(self.as_raw())
+    }
+
+    /// Sets the palette index of the pixel.
+    pub fn set_index(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_index(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Creates a new pixel from a palette index.
+    pub fn with_index(index: <F::RawPixel as RawPixel>::Channel) -> Self {
+        let mut pixel = Self::from_raw(F::RawPixel::DEFAULT);
+        pixel.set_index(index);
+        pixel
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: IndexedFormat,
+    F::RawPixel: RawPixel<Channel = u8>,
+{
+    /// Returns the color in the palette that this pixel refers to.
+    ///
+    /// Indices beyond the length of the palette resolve to [`Pixel::zeroed`].
+    #[must_use]
+    pub fn resolve<T: RgbaFormat>(&self, palette: &Palette<T>) -> Pixel<T> {
+        palette.colors[usize::from(self.index())]
+    }
+}
+
+/// A palette of up to 256 colors, referenced by 8-bit indexed pixels.
+///
+/// The palette is stored inline (without allocating); entries beyond [`Palette::len`] are always
+/// [`Pixel::zeroed`].
+#[derive(Copy, Clone)]
+pub struct Palette<F: RgbaFormat> {
+    colors: [Pixel<F>; PALETTE_CAPACITY],
+    len: usize,
+}
+
+const PALETTE_CAPACITY: usize = 256;
+
+impl<F> core::fmt::Debug for Palette<F>
+where
+    F: RgbaFormat,
+    Pixel<F>: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Palette")
+            .field("colors", &self.as_slice())
+            .finish()
+    }
+}
+
+impl<F> PartialEq for Palette<F>
+where
+    F: RgbaFormat,
+    Pixel<F>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<F> Eq for Palette<F>
+where
+    F: RgbaFormat,
+    Pixel<F>: Eq,
+{
+}
+
+impl<F: RgbaFormat> Default for Palette<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: RgbaFormat> Palette<F> {
+    /// The maximum number of colors in a palette.
+    pub const CAPACITY: usize = PALETTE_CAPACITY;
+
+    /// Creates a new, empty palette.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            colors: [Pixel::zeroed(); PALETTE_CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// Creates a new palette with the given colors, in index order.
+    ///
+    /// ## Panics
+    ///
+    /// If more than [`Palette::CAPACITY`] colors are provided.
+    #[must_use]
+    pub fn from_slice(colors: &[Pixel<F>]) -> Self {
+        assert!(colors.len() <= Self::CAPACITY, "Too many colors");
+        let mut palette = Self::new();
+        palette.colors[..colors.len()].copy_from_slice(colors);
+        palette.len = colors.len();
+        palette
+    }
+
+    /// Appends a color to the end of the palette, returning its index.
+    ///
+    /// ## Panics
+    ///
+    /// If the palette already contains [`Palette::CAPACITY`] colors.
+    pub fn push(&mut self, color: Pixel<F>) -> u8 {
+        let index = u8::try_from(self.len).expect("Palette is full");
+        self.colors[self.len] = color;
+        self.len += 1;
+        index
+    }
+
+    /// Returns the number of colors in the palette.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the palette contains no colors.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the color at the given index, or `None` if it is beyond the length of the palette.
+    #[must_use]
+    pub fn get(&self, index: u8) -> Option<Pixel<F>> {
+        self.as_slice().get(usize::from(index)).copied()
+    }
+
+    /// Returns the colors of the palette, in index order.
+    #[must_use]
+    pub fn as_slice(&self) -> &[Pixel<F>] {
+        &self.colors[..self.len]
+    }
+}
+
+impl<F> Palette<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    /// Returns the index of the color closest to a normalized RGB color, and that color.
+    ///
+    /// Ties resolve to the lowest index. Returns `None` if the palette is empty.
+    pub(crate) fn nearest(&self, rgb: [f32; 3], metric: ColorMetric) -> Option<(u8, Pixel<F>)> {
+        let distance = |color: &Pixel<F>| {
+            let channels = [color.red(), color.green(), color.blue()].map(Normalized::to_f32);
+            metric.distance(channels, rgb)
+        };
+        let (index, color) = self
+            .as_slice()
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)))?;
+        Some((u8::try_from(index).ok()?, *color))
+    }
+}
+
+/// How the distance between two colors is measured when searching a [`Palette`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorMetric {
+    /// The squared difference of the red, green, and blue channels.
+    #[default]
+    Rgb,
+
+    /// The squared difference of the red, green, and blue channels, weighted by the BT.601 luma
+    /// coefficients, so that differences in green (to which the eye is most sensitive) count the
+    /// most.
+    Perceptual,
+}
+
+impl ColorMetric {
+    /// Returns the distance between two normalized RGB colors.
+    #[must_use]
+    pub fn distance(self, a: [f32; 3], b: [f32; 3]) -> f32 {
+        let weights = match self {
+            Self::Rgb => [1.0; 3],
+            Self::Perceptual => [0.299, 0.587, 0.114],
+        };
+        let mut distance = 0.0;
+        for ((a, b), weight) in a.into_iter().zip(b).zip(weights) {
+            distance += (a - b) * (a - b) * weight;
+        }
+        distance
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    /// Returns the index of the color in `palette` nearest to this pixel, as an indexed pixel.
+    ///
+    /// Distance is measured with [`ColorMetric::Rgb`], ignoring alpha; ties resolve to the lowest
+    /// index. See [`nearest_in_with`](Pixel::nearest_in_with) to use another metric.
+    ///
+    /// ## Panics
+    ///
+    /// If the palette is empty.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{
+    ///     formats::{indexed::Palette, rgba::Rgba8888},
+    ///     pixel::Pixel,
+    /// };
+    ///
+    /// let palette = Palette::from_slice(&[
+    ///     Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0x00, 0xFF),
+    ///     Pixel::<Rgba8888>::with_rgba(0xFF, 0x00, 0x00, 0xFF),
+    /// ]);
+    /// let orange = Pixel::<Rgba8888>::with_rgba(0xF0, 0x40, 0x00, 0xFF);
+    /// assert_eq!(orange.nearest_in(&palette).index(), 1);
+    /// ```
+    #[must_use]
+    pub fn nearest_in<P>(&self, palette: &Palette<P>) -> Pixel<Indexed8>
+    where
+        P: RgbaFormat,
+        P::RawPixel: RawPixel<Channel: Normalized>,
+    {
+        self.nearest_in_with(palette, ColorMetric::Rgb)
+    }
+
+    /// Returns the index of the color in `palette` nearest to this pixel, by the given metric.
+    ///
+    /// ## Panics
+    ///
+    /// If the palette is empty.
+    #[must_use]
+    pub fn nearest_in_with<P>(&self, palette: &Palette<P>, metric: ColorMetric) -> Pixel<Indexed8>
+    where
+        P: RgbaFormat,
+        P::RawPixel: RawPixel<Channel: Normalized>,
+    {
+        let rgb = [self.red(), self.green(), self.blue()].map(Normalized::to_f32);
+        let (index, _) = palette.nearest(rgb, metric).expect("Palette is empty");
+        Pixel::with_index(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::Rgba8888;
+
+    use super::*;
+
+    #[test]
+    fn push_and_get() {
+        let mut palette = Palette::<Rgba8888>::new();
+        assert!(palette.is_empty());
+
+        let red = Pixel::with_rgba(0xFF, 0x00, 0x00, 0xFF);
+        assert_eq!(palette.push(red), 0);
+        assert_eq!(palette.len(), 1);
+        assert_eq!(palette.get(0), Some(red));
+        assert_eq!(palette.get(1), None);
+        assert_eq!(palette.as_slice(), &[red]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Palette is full")]
+    fn push_full() {
+        let mut palette = Palette::<Rgba8888>::from_slice(&[Pixel::zeroed(); 256]);
+        palette.push(Pixel::zeroed());
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many colors")]
+    fn from_slice_too_many() {
+        let _ = Palette::<Rgba8888>::from_slice(&[Pixel::zeroed(); 257]);
+    }
+
+    #[test]
+    fn nearest_in_metrics() {
+        let palette = Palette::from_slice(&[
+            Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0x00, 0xFF),
+            Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0x80, 0xFF),
+            Pixel::<Rgba8888>::with_rgba(0x00, 0x60, 0x00, 0xFF),
+        ]);
+        let teal = Pixel::<Rgba8888>::with_rgba(0x00, 0x50, 0x70, 0x00);
+        assert_eq!(teal.nearest_in(&palette).index(), 1);
+        assert_eq!(
+            teal.nearest_in_with(&palette, ColorMetric::Perceptual)
+                .index(),
+            2
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Palette is empty")]
+    fn nearest_in_empty() {
+        let _ = Pixel::<Rgba8888>::zeroed().nearest_in(&Palette::<Rgba8888>::new());
+    }
+
+    #[test]
+    fn resolve() {
+        let blue = Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0xFF, 0xFF);
+        let palette = Palette::from_slice(&[Pixel::zeroed(), blue]);
+        assert_eq!(Pixel::<Indexed8>::with_index(1).resolve(&palette), blue);
+        assert_eq!(
+            Pixel::<Indexed8>::with_index(200).resolve(&palette),
+            Pixel::zeroed()
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    formats::indexed::{Indexed, IndexedFormat},
+    pixel::{Format, raw::U8x1},
+};
+
+/// An 8-bit indexed pixel format, referencing one of up to 256 colors in a palette.
+///
+/// The pixel is represented as a single byte, which is the palette index.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Indexed8 {}
+
+impl crate::internal::Sealed for Indexed8 {}
+
+impl Format for Indexed8 {
+    type RawPixel = U8x1;
+    type Channels = Indexed;
+    const NAME: &'static str = "Indexed8";
+}
+
+impl IndexedFormat for Indexed8 {
+    const INDEX_OFFSET: usize = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::{Pixel, raw::RawPixel};
+
+    use super::*;
+
+    #[test]
+    fn from_raw() {
+        let mut pixel: Pixel<Indexed8> = Pixel::new(U8x1::from(0x2A));
+        assert_eq!(pixel.index(), 0x2A);
+
+        pixel.set_index(0x01);
+        assert_eq!(pixel.as_raw().into_inner(), 0x01);
+    }
+}
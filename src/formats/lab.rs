@@ -0,0 +1,199 @@
+//! Perceptual (CIE Lab and Oklab) pixel formats.
+//!
+//! Both formats store a lightness channel (`L`) and two opponent color channels (`a`, green to
+//! red, and `b`, blue to yellow), and can be converted to and from [`XyzaF32`] and linear (not
+//! gamma-encoded) sRGB [`FloatRgba`] pixels using [`From`]; the alpha channel is preserved.
+//!
+//! Euclidean distance in these spaces approximates perceived color difference, which is available
+//! with [`Pixel::delta_e`]:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::{lab::OklabF32, rgba::FloatRgba},
+//!     pixel::Pixel,
+//! };
+//!
+//! let red = Pixel::<OklabF32>::from(Pixel::<FloatRgba>::with_rgba(1.0, 0.0, 0.0, 1.0));
+//! let orange = Pixel::<OklabF32>::from(Pixel::<FloatRgba>::with_rgba(1.0, 0.2, 0.0, 1.0));
+//! let blue = Pixel::<OklabF32>::from(Pixel::<FloatRgba>::with_rgba(0.0, 0.0, 1.0, 1.0));
+//!
+//! assert!(red.delta_e(&orange) < red.delta_e(&blue));
+//! ```
+//!
+//! [`FloatRgba`]: crate::formats::rgba::FloatRgba
+//! [`XyzaF32`]: crate::formats::xyz::XyzaF32
+
+mod lab_f32;
+pub use lab_f32::LabF32;
+
+mod oklab_f32;
+pub use oklab_f32::OklabF32;
+
+use crate::{
+    alpha::AlphaFormat,
+    pixel::{ChannelOf, Format, Pixel, raw::RawPixel},
+};
+
+/// Channels representing `L`ightness, `a`, `b`, and `Alpha` components of a pixel.
+///
+/// `A` is the green-red opponent channel, and `B` is the blue-yellow opponent channel.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Laba {
+    L,
+    A,
+    B,
+    Alpha,
+}
+
+/// A pixel format with lightness, two opponent color, and alpha channels.
+///
+/// The offsets of individual channels in the pixel's raw representation are defined by this trait:
+/// - `L_OFFSET`: Offset of the lightness channel.
+/// - `A_OFFSET`: Offset of the green-red channel.
+/// - `B_OFFSET`: Offset of the blue-yellow channel.
+///
+/// The alpha channel is defined by the [`AlphaFormat`] supertrait.
+pub trait LabFormat: AlphaFormat + Format<Channels = Laba> {
+    /// The offset of the lightness channel in the pixel's raw representation.
+    const L_OFFSET: usize;
+
+    /// The offset of the green-red channel in the pixel's raw representation.
+    const A_OFFSET: usize;
+
+    /// The offset of the blue-yellow channel in the pixel's raw representation.
+    const B_OFFSET: usize;
+
+    /// Returns the lightness channel value of a pixel.
+    fn get_l(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::L_OFFSET)
+    }
+
+    /// Sets the lightness channel value of a pixel.
+    fn set_l(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::L_OFFSET, value);
+    }
+
+    /// Returns the green-red channel value of a pixel.
+    fn get_a(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::A_OFFSET)
+    }
+
+    /// Sets the green-red channel value of a pixel.
+    fn set_a(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::A_OFFSET, value);
+    }
+
+    /// Returns the blue-yellow channel value of a pixel.
+    fn get_b(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::B_OFFSET)
+    }
+
+    /// Sets the blue-yellow channel value of a pixel.
+    fn set_b(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::B_OFFSET, value);
+    }
+}
+
+impl<F: LabFormat> ChannelOf<F> for Laba {
+    const ALL: &'static [Self] = &[Self::L, Self::A, Self::B, Self::Alpha];
+
+    fn offset(self) -> usize {
+        match self {
+            Self::L => F::L_OFFSET,
+            Self::A => F::A_OFFSET,
+            Self::B => F::B_OFFSET,
+            Self::Alpha => F::ALPHA_OFFSET,
+        }
+    }
+}
+
+impl<F: LabFormat> Pixel<F> {
+    /// Returns the lightness channel value of the pixel.
+    pub fn l(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_l(self.as_raw())
+    }
+
+    /// Sets the lightness channel value of the pixel.
+    pub fn set_l(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_l(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Returns the green-red channel value of the pixel.
+    pub fn a(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_a(self.as_raw())
+    }
+
+    /// Sets the green-red channel value of the pixel.
+    pub fn set_a(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_a(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Returns the blue-yellow channel value of the pixel.
+    pub fn b(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_b(self.as_raw())
+    }
+
+    /// Sets the blue-yellow channel value of the pixel.
+    pub fn set_b(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_b(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Creates a new pixel from `L`, `a`, `b`, and alpha channel values.
+    pub fn with_laba(
+        l: <F::RawPixel as RawPixel>::Channel,
+        a: <F::RawPixel as RawPixel>::Channel,
+        b: <F::RawPixel as RawPixel>::Channel,
+        alpha: <F::RawPixel as RawPixel>::Channel,
+    ) -> Self {
+        let mut pixel = Self::from_raw(F::RawPixel::DEFAULT);
+        pixel.set_l(l).set_a(a).set_b(b).set_alpha(alpha);
+        pixel
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: LabFormat,
+    F::RawPixel: RawPixel<Channel = f32>,
+{
+    /// Returns the color difference between two pixels.
+    ///
+    /// This is the Euclidean distance between the `L`, `a`, and `b` channels (CIE76 ΔE for
+    /// [`LabF32`], and ΔE<sub>OK</sub> for [`OklabF32`]); the alpha channel is ignored.
+    #[must_use]
+    pub fn delta_e(&self, other: &Self) -> f32 {
+        let dl = self.l() - other.l();
+        let da = self.a() - other.a();
+        let db = self.b() - other.b();
+        libm::sqrtf(dl * dl + da * da + db * db)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn with_laba() {
+        let pixel = Pixel::<LabF32>::with_laba(50.0, -20.0, 30.0, 1.0);
+        assert_eq!(pixel.l(), 50.0);
+        assert_eq!(pixel.a(), -20.0);
+        assert_eq!(pixel.b(), 30.0);
+        assert_eq!(pixel.alpha(), 1.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn delta_e() {
+        let a = Pixel::<LabF32>::with_laba(50.0, 0.0, 0.0, 1.0);
+        let b = Pixel::<LabF32>::with_laba(53.0, 4.0, 0.0, 0.0);
+        assert_eq!(a.delta_e(&b), 5.0);
+        assert_eq!(b.delta_e(&a), 5.0);
+        assert_eq!(a.delta_e(&a), 0.0);
+    }
+}
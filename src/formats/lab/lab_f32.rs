@@ -0,0 +1,133 @@
+use crate::{
+    alpha::AlphaFormat,
+    formats::{
+        lab::{LabFormat, Laba},
+        rgba::FloatRgba,
+        xyz::{D65_WHITE, XyzaF32},
+    },
+    pixel::{Format, Pixel, raw::F32x4},
+};
+
+/// A 128-bit CIE L\*a\*b\* pixel format with four 32-bit floating point channels.
+///
+/// This format is used to represent pixels in the Lab order:
+/// - `L`ightness (32 bits, `0.0..=100.0`)
+/// - `a` (32 bits, green to red, roughly `-128.0..=128.0`)
+/// - `b` (32 bits, blue to yellow, roughly `-128.0..=128.0`)
+/// - `A`lpha (32 bits)
+///
+/// The pixel is represented as a 128-bit value, where each channel occupies 32 bits.
+///
+/// Conversions are relative to a D65 white point, and go through [`XyzaF32`] when converting to
+/// and from linear sRGB [`FloatRgba`] pixels.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum LabF32 {}
+
+impl crate::internal::Sealed for LabF32 {}
+
+impl Format for LabF32 {
+    type RawPixel = F32x4;
+    type Channels = Laba;
+    const NAME: &'static str = "LabF32";
+}
+
+impl AlphaFormat for LabF32 {
+    const ALPHA_OFFSET: usize = 3;
+}
+
+impl LabFormat for LabF32 {
+    const L_OFFSET: usize = 0;
+    const A_OFFSET: usize = 1;
+    const B_OFFSET: usize = 2;
+}
+
+/// The threshold between the linear and cube-root segments of the Lab transfer function.
+const DELTA: f32 = 6.0 / 29.0;
+
+fn lab_f(t: f32) -> f32 {
+    if t > DELTA * DELTA * DELTA {
+        libm::cbrtf(t)
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+fn lab_f_inv(t: f32) -> f32 {
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+impl From<Pixel<XyzaF32>> for Pixel<LabF32> {
+    fn from(xyza: Pixel<XyzaF32>) -> Self {
+        let xyz = [xyza.x(), xyza.y(), xyza.z()];
+        let [fx, fy, fz] = [0, 1, 2].map(|i| lab_f(xyz[i] / D65_WHITE[i]));
+        Pixel::with_laba(
+            116.0 * fy - 16.0,
+            500.0 * (fx - fy),
+            200.0 * (fy - fz),
+            xyza.alpha(),
+        )
+    }
+}
+
+impl From<Pixel<LabF32>> for Pixel<XyzaF32> {
+    fn from(lab: Pixel<LabF32>) -> Self {
+        let fy = (lab.l() + 16.0) / 116.0;
+        let f = [fy + lab.a() / 500.0, fy, fy - lab.b() / 200.0];
+        let [x, y, z] = [0, 1, 2].map(|i| lab_f_inv(f[i]) * D65_WHITE[i]);
+        Pixel::with_xyza(x, y, z, lab.alpha())
+    }
+}
+
+impl From<Pixel<FloatRgba>> for Pixel<LabF32> {
+    fn from(rgba: Pixel<FloatRgba>) -> Self {
+        Pixel::<XyzaF32>::from(rgba).into()
+    }
+}
+
+impl From<Pixel<LabF32>> for Pixel<FloatRgba> {
+    fn from(lab: Pixel<LabF32>) -> Self {
+        Pixel::<XyzaF32>::from(lab).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::raw::RawPixel;
+
+    use super::*;
+
+    fn assert_close(actual: [f32; 4], expected: [f32; 4], epsilon: f32) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < epsilon, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn from_rgba() {
+        let white = Pixel::<LabF32>::from(Pixel::<FloatRgba>::with_rgba(1.0, 1.0, 1.0, 1.0));
+        assert_close(white.as_raw().into_inner(), [100.0, 0.0, 0.0, 1.0], 1e-3);
+
+        let black = Pixel::<LabF32>::from(Pixel::<FloatRgba>::with_rgba(0.0, 0.0, 0.0, 0.5));
+        assert_close(black.as_raw().into_inner(), [0.0, 0.0, 0.0, 0.5], 1e-3);
+
+        let red = Pixel::<LabF32>::from(Pixel::<FloatRgba>::with_rgba(1.0, 0.0, 0.0, 1.0));
+        assert_close(red.as_raw().into_inner(), [53.24, 80.09, 67.20, 1.0], 1e-2);
+    }
+
+    #[test]
+    fn round_trip() {
+        for rgba in [
+            [0.2, 0.4, 0.6, 1.0],
+            [0.9, 0.1, 0.3, 0.5],
+            [0.001, 0.002, 0.0, 0.0],
+        ] {
+            let pixel = Pixel::<FloatRgba>::from_raw(rgba.into());
+            let back = Pixel::<FloatRgba>::from(Pixel::<LabF32>::from(pixel));
+            assert_close(back.as_raw().into_inner(), rgba, 1e-5);
+        }
+    }
+}
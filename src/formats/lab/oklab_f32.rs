@@ -0,0 +1,172 @@
+use crate::{
+    alpha::AlphaFormat,
+    formats::{
+        lab::{LabFormat, Laba},
+        rgba::FloatRgba,
+        xyz::{XyzaF32, mul_mat3},
+    },
+    pixel::{Format, Pixel, raw::F32x4},
+};
+
+/// A 128-bit [Oklab](https://bottosson.github.io/posts/oklab/) pixel format with four 32-bit
+/// floating point channels.
+///
+/// This format is used to represent pixels in the Lab order:
+/// - `L`ightness (32 bits, `0.0..=1.0`)
+/// - `a` (32 bits, green to red, roughly `-0.4..=0.4`)
+/// - `b` (32 bits, blue to yellow, roughly `-0.4..=0.4`)
+/// - `A`lpha (32 bits)
+///
+/// The pixel is represented as a 128-bit value, where each channel occupies 32 bits.
+///
+/// Conversions to and from [`FloatRgba`] assume linear sRGB primaries, and use the direct linear
+/// sRGB to LMS matrix rather than going through [`XyzaF32`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum OklabF32 {}
+
+impl crate::internal::Sealed for OklabF32 {}
+
+impl Format for OklabF32 {
+    type RawPixel = F32x4;
+    type Channels = Laba;
+    const NAME: &'static str = "OklabF32";
+}
+
+impl AlphaFormat for OklabF32 {
+    const ALPHA_OFFSET: usize = 3;
+}
+
+impl LabFormat for OklabF32 {
+    const L_OFFSET: usize = 0;
+    const A_OFFSET: usize = 1;
+    const B_OFFSET: usize = 2;
+}
+
+/// Linear sRGB to LMS cone responses.
+const RGB_TO_LMS: [[f32; 3]; 3] = [
+    [0.412_221_47, 0.536_332_55, 0.051_445_995],
+    [0.211_903_5, 0.680_699_5, 0.107_396_96],
+    [0.088_302_46, 0.281_718_85, 0.629_978_7],
+];
+
+/// CIE XYZ (D65) to LMS cone responses.
+///
+/// Derived from [`RGB_TO_LMS`] and [`XYZ_TO_SRGB`](crate::formats::xyz::XYZ_TO_SRGB), so that
+/// converting through [`XyzaF32`] agrees with converting from linear sRGB directly.
+const XYZ_TO_LMS: [[f32; 3]; 3] = [
+    [0.818_798_5, 0.362_027_8, -0.128_827_5],
+    [0.032_860_59, 0.929_362_9, 0.036_189_37],
+    [0.048_133_75, 0.264_242_5, 0.633_714_9],
+];
+
+/// LMS cone responses to CIE XYZ (D65).
+///
+/// Derived from [`SRGB_TO_XYZ`](crate::formats::xyz::SRGB_TO_XYZ) and [`LMS_TO_RGB`].
+const LMS_TO_XYZ: [[f32; 3]; 3] = [
+    [1.227_158, -0.558_023, 0.281_335_1],
+    [-0.040_416_61, 1.112_143_9, -0.071_727_18],
+    [-0.076_355_905, -0.421_350_36, 1.586_536_3],
+];
+
+/// Non-linear LMS to Oklab.
+const LMS_TO_LAB: [[f32; 3]; 3] = [
+    [0.210_454_26, 0.793_617_8, -0.004_072_047],
+    [1.977_998_5, -2.428_592_2, 0.450_593_7],
+    [0.025_904_037, 0.782_771_77, -0.808_675_77],
+];
+
+/// Oklab to non-linear LMS.
+const LAB_TO_LMS: [[f32; 3]; 3] = [
+    [1.0, 0.396_337_78, 0.215_803_76],
+    [1.0, -0.105_561_346, -0.063_854_17],
+    [1.0, -0.089_484_18, -1.291_485_5],
+];
+
+/// LMS cone responses to linear sRGB.
+const LMS_TO_RGB: [[f32; 3]; 3] = [
+    [4.076_741_7, -3.307_711_6, 0.230_969_94],
+    [-1.268_438, 2.609_757_4, -0.341_319_38],
+    [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
+];
+
+impl From<Pixel<FloatRgba>> for Pixel<OklabF32> {
+    fn from(rgba: Pixel<FloatRgba>) -> Self {
+        let lms = mul_mat3(&RGB_TO_LMS, [rgba.red(), rgba.green(), rgba.blue()]);
+        let [l, a, b] = mul_mat3(&LMS_TO_LAB, lms.map(libm::cbrtf));
+        Pixel::with_laba(l, a, b, rgba.alpha())
+    }
+}
+
+impl From<Pixel<OklabF32>> for Pixel<FloatRgba> {
+    fn from(lab: Pixel<OklabF32>) -> Self {
+        let lms = mul_mat3(&LAB_TO_LMS, [lab.l(), lab.a(), lab.b()]);
+        let [r, g, b] = mul_mat3(&LMS_TO_RGB, lms.map(|c| c * c * c));
+        Pixel::with_rgba(r, g, b, lab.alpha())
+    }
+}
+
+impl From<Pixel<XyzaF32>> for Pixel<OklabF32> {
+    fn from(xyza: Pixel<XyzaF32>) -> Self {
+        let lms = mul_mat3(&XYZ_TO_LMS, [xyza.x(), xyza.y(), xyza.z()]);
+        let [l, a, b] = mul_mat3(&LMS_TO_LAB, lms.map(libm::cbrtf));
+        Pixel::with_laba(l, a, b, xyza.alpha())
+    }
+}
+
+impl From<Pixel<OklabF32>> for Pixel<XyzaF32> {
+    fn from(lab: Pixel<OklabF32>) -> Self {
+        let lms = mul_mat3(&LAB_TO_LMS, [lab.l(), lab.a(), lab.b()]);
+        let [x, y, z] = mul_mat3(&LMS_TO_XYZ, lms.map(|c| c * c * c));
+        Pixel::with_xyza(x, y, z, lab.alpha())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::raw::RawPixel;
+
+    use super::*;
+
+    fn assert_close(actual: [f32; 4], expected: [f32; 4], epsilon: f32) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < epsilon, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn from_rgba() {
+        let white = Pixel::<OklabF32>::from(Pixel::<FloatRgba>::with_rgba(1.0, 1.0, 1.0, 1.0));
+        assert_close(white.as_raw().into_inner(), [1.0, 0.0, 0.0, 1.0], 1e-4);
+
+        let red = Pixel::<OklabF32>::from(Pixel::<FloatRgba>::with_rgba(1.0, 0.0, 0.0, 0.5));
+        assert_close(red.as_raw().into_inner(), [0.628, 0.225, 0.126, 0.5], 1e-3);
+    }
+
+    #[test]
+    fn round_trip() {
+        for rgba in [
+            [0.2, 0.4, 0.6, 1.0],
+            [0.9, 0.1, 0.3, 0.5],
+            [0.0, 0.0, 0.0, 0.0],
+        ] {
+            let pixel = Pixel::<FloatRgba>::from_raw(rgba.into());
+            let back = Pixel::<FloatRgba>::from(Pixel::<OklabF32>::from(pixel));
+            assert_close(back.as_raw().into_inner(), rgba, 1e-5);
+        }
+    }
+
+    #[test]
+    fn from_xyza() {
+        let rgba = Pixel::<FloatRgba>::with_rgba(0.2, 0.4, 0.6, 1.0);
+        let direct = Pixel::<OklabF32>::from(rgba);
+        let via_xyz = Pixel::<OklabF32>::from(Pixel::<XyzaF32>::from(rgba));
+        assert_close(
+            via_xyz.as_raw().into_inner(),
+            direct.as_raw().into_inner(),
+            1e-4,
+        );
+
+        let back = Pixel::<FloatRgba>::from(Pixel::<XyzaF32>::from(direct));
+        assert_close(back.as_raw().into_inner(), rgba.as_raw().into_inner(), 1e-4);
+    }
+}
@@ -0,0 +1,23 @@
+//! Two-channel (red and green) pixel formats.
+//!
+//! Two-channel formats are commonly used for data other than color, such as the `X` and `Y`
+//! components of tangent-space normal maps, where `Z` is reconstructed from the other two:
+//!
+//! ```rust
+//! use pxlfmt::{formats::rg::Rg8Snorm, pixel::Pixel};
+//!
+//! let normal = Pixel::<Rg8Snorm>::with_rg_normalized(0.0, -1.0);
+//! assert_eq!((normal.red(), normal.green()), (0, -127));
+//! assert_eq!(normal.rg_normalized(), [0.0, -1.0]);
+//! ```
+
+mod rg8_snorm;
+pub use rg8_snorm::Rg8Snorm;
+
+/// Channels representing `R`ed and `G`reen components of a pixel.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Rg {
+    Red,
+    Green,
+}
@@ -0,0 +1,106 @@
+use crate::{
+    formats::rg::Rg,
+    norm::Normalized,
+    pixel::{
+        ChannelOf, Format, Pixel,
+        raw::{I8x2, RawPixel},
+    },
+};
+
+/// A 16-bit RG pixel format with two 8-bit signed normalized (snorm) channels.
+///
+/// This format is used to represent pixels in the RG order:
+/// - `R`ed (8 bits)
+/// - `G`reen (8 bits)
+///
+/// The pixel is represented as two bytes, in memory order. Each channel maps `-127..=127` to
+/// `-1.0..=1.0` (`-128` is also `-1.0`), as with `DXGI_FORMAT_R8G8_SNORM`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Rg8Snorm {}
+
+impl crate::internal::Sealed for Rg8Snorm {}
+
+impl Format for Rg8Snorm {
+    type RawPixel = I8x2;
+    type Channels = Rg;
+    const NAME: &'static str = "Rg8Snorm";
+}
+
+impl ChannelOf<Rg8Snorm> for Rg {
+    const ALL: &'static [Self] = &[Self::Red, Self::Green];
+
+    fn offset(self) -> usize {
+        self as usize
+    }
+}
+
+impl Pixel<Rg8Snorm> {
+    /// Returns the red channel value of the pixel.
+    #[must_use]
+    pub fn red(&self) -> i8 {
+        self.as_raw().get_channel(0)
+    }
+
+    /// Sets the red channel value of the pixel.
+    pub fn set_red(&mut self, value: i8) -> &mut Self {
+        self.as_raw_mut().set_channel(0, value);
+        self
+    }
+
+    /// Returns the green channel value of the pixel.
+    #[must_use]
+    pub fn green(&self) -> i8 {
+        self.as_raw().get_channel(1)
+    }
+
+    /// Sets the green channel value of the pixel.
+    pub fn set_green(&mut self, value: i8) -> &mut Self {
+        self.as_raw_mut().set_channel(1, value);
+        self
+    }
+
+    /// Creates a new pixel from RG channel values.
+    #[must_use]
+    pub fn with_rg(r: i8, g: i8) -> Self {
+        Self::new(I8x2::from_channels(r, g))
+    }
+
+    /// Creates a new pixel from RG channel values in the range `-1.0..=1.0`.
+    ///
+    /// Values outside of the range are clamped.
+    #[must_use]
+    pub fn with_rg_normalized(r: f32, g: f32) -> Self {
+        Self::with_rg(i8::from_f32(r), i8::from_f32(g))
+    }
+
+    /// Returns the RG channel values of the pixel in the range `-1.0..=1.0`.
+    #[must_use]
+    pub fn rg_normalized(&self) -> [f32; 2] {
+        [self.red().to_f32(), self.green().to_f32()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_rg() {
+        let mut pixel = Pixel::<Rg8Snorm>::with_rg(1, -1);
+        assert_eq!(pixel.as_raw().into_inner(), [1, -1]);
+        assert_eq!((pixel.red(), pixel.green()), (1, -1));
+
+        pixel.set_red(-128).set_green(127);
+        assert_eq!(pixel.as_raw().into_inner(), [-128, 127]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn normalized() {
+        let pixel = Pixel::<Rg8Snorm>::with_rg_normalized(0.5, -3.0);
+        assert_eq!((pixel.red(), pixel.green()), (64, -127));
+
+        let pixel = Pixel::<Rg8Snorm>::with_rg(-128, 127);
+        assert_eq!(pixel.rg_normalized(), [-1.0, 1.0]);
+    }
+}
@@ -0,0 +1,30 @@
+//! Three-channel (red, green, and blue) pixel formats without an alpha channel.
+//!
+//! These formats are commonly used by framebuffers and displays with a low color depth, such as
+//! the 16-bit [`Rgb565`]:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::rgb::Rgb565,
+//!     pixel::{Pixel, raw::RawPixel},
+//! };
+//!
+//! let pixel = Pixel::<Rgb565>::with_rgb(0x1F, 0x20, 0x00);
+//! assert_eq!(pixel.as_raw().into_inner(), 0xFC00);
+//! assert_eq!(pixel.rgb_normalized(), [1.0, 32.0 / 63.0, 0.0]);
+//! ```
+//!
+//! Converting to these formats drops the alpha channel of RGBA pixels, and converting from them
+//! produces opaque pixels.
+
+mod rgb565;
+pub use rgb565::Rgb565;
+
+/// Channels representing `R`ed, `G`reen, and `B`lue components of a pixel.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Rgb {
+    Red,
+    Green,
+    Blue,
+}
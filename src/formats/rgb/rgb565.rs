@@ -0,0 +1,204 @@
+use crate::{
+    convert::ConvertFrom,
+    formats::{rgb::Rgb, rgba::RgbaFormat},
+    norm::Normalized,
+    pixel::{
+        ChannelOf, Format, Pixel,
+        raw::{RawPixel, U16x565},
+    },
+};
+
+/// A 16-bit RGB pixel format with 5-bit red and blue channels, and a 6-bit green channel.
+///
+/// This format is used to represent pixels in the RGB order:
+/// - `R`ed (5 bits)
+/// - `G`reen (6 bits)
+/// - `B`lue (5 bits)
+///
+/// The pixel is represented as a 16-bit unsigned integer with red in the most significant bits,
+/// compatible with `DRM_FORMAT_RGB565` and `SDL_PIXELFORMAT_RGB565`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Rgb565 {}
+
+impl Rgb565 {
+    /// The largest value of the red and blue channels.
+    pub const MAX_RB: u16 = 0x1F;
+
+    /// The largest value of the green channel.
+    pub const MAX_G: u16 = 0x3F;
+
+    /// The largest value of each channel, in offset order.
+    const MAX: [u16; 3] = [Self::MAX_RB, Self::MAX_G, Self::MAX_RB];
+}
+
+impl crate::internal::Sealed for Rgb565 {}
+
+impl Format for Rgb565 {
+    type RawPixel = U16x565;
+    type Channels = Rgb;
+    const NAME: &'static str = "Rgb565";
+}
+
+impl ChannelOf<Rgb565> for Rgb {
+    const ALL: &'static [Self] = &[Self::Red, Self::Green, Self::Blue];
+
+    fn offset(self) -> usize {
+        self as usize
+    }
+}
+
+impl Pixel<Rgb565> {
+    /// Creates a new pixel from RGB channel values.
+    ///
+    /// Values are truncated to the width of each channel.
+    #[must_use]
+    pub fn with_rgb(r: u16, g: u16, b: u16) -> Self {
+        let mut pixel = Self::zeroed();
+        pixel.set_red(r).set_green(g).set_blue(b);
+        pixel
+    }
+
+    /// Creates a new pixel from RGB channel values in the range `0.0..=1.0`.
+    ///
+    /// Values are rounded to the nearest representable value, and values outside of the range are
+    /// clamped.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn with_rgb_normalized(r: f32, g: f32, b: f32) -> Self {
+        let mut pixel = Self::zeroed();
+        for (offset, value) in [r, g, b].into_iter().enumerate() {
+            let max = f32::from(Rgb565::MAX[offset]);
+            let value = (value.clamp(0.0, 1.0) * max + 0.5) as u16;
+            pixel.as_raw_mut().set_channel(offset, value);
+        }
+        pixel
+    }
+
+    /// Returns the RGB channel values of the pixel in the range `0.0..=1.0`.
+    #[must_use]
+    pub fn rgb_normalized(&self) -> [f32; 3] {
+        [0, 1, 2].map(|offset| {
+            f32::from(self.as_raw().get_channel(offset)) / f32::from(Rgb565::MAX[offset])
+        })
+    }
+
+    /// Returns the red channel value of the pixel.
+    #[must_use]
+    pub fn red(&self) -> u16 {
+        self.as_raw().get_channel(0)
+    }
+
+    /// Sets the red channel value of the pixel.
+    pub fn set_red(&mut self, value: u16) -> &mut Self {
+        self.as_raw_mut().set_channel(0, value);
+        self
+    }
+
+    /// Returns the green channel value of the pixel.
+    #[must_use]
+    pub fn green(&self) -> u16 {
+        self.as_raw().get_channel(1)
+    }
+
+    /// Sets the green channel value of the pixel.
+    pub fn set_green(&mut self, value: u16) -> &mut Self {
+        self.as_raw_mut().set_channel(1, value);
+        self
+    }
+
+    /// Returns the blue channel value of the pixel.
+    #[must_use]
+    pub fn blue(&self) -> u16 {
+        self.as_raw().get_channel(2)
+    }
+
+    /// Sets the blue channel value of the pixel.
+    pub fn set_blue(&mut self, value: u16) -> &mut Self {
+        self.as_raw_mut().set_channel(2, value);
+        self
+    }
+}
+
+impl<S> ConvertFrom<Pixel<S>> for Pixel<Rgb565>
+where
+    S: RgbaFormat,
+    S::RawPixel: RawPixel<Channel: Normalized>,
+{
+    fn convert_from(value: Pixel<S>) -> Self {
+        let [red, green, blue, _] = value.channels().map(Normalized::to_f32);
+        Self::with_rgb_normalized(red, green, blue)
+    }
+}
+
+impl<T> ConvertFrom<Pixel<Rgb565>> for Pixel<T>
+where
+    T: RgbaFormat,
+    T::RawPixel: RawPixel<Channel: Normalized>,
+{
+    fn convert_from(value: Pixel<Rgb565>) -> Self {
+        let [red, green, blue] = value.rgb_normalized().map(Normalized::from_f32);
+        Self::with_rgba(red, green, blue, Normalized::ONE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        formats::rgba::{FloatRgba, Rgba8888},
+        pixel::raw::ChannelDescriptor,
+    };
+
+    use super::*;
+
+    #[test]
+    fn with_rgb() {
+        let mut pixel = Pixel::<Rgb565>::with_rgb(0x1F, 0x00, 0x01);
+        assert_eq!(pixel.as_raw().into_inner(), 0xF801);
+        assert_eq!(
+            (pixel.red(), pixel.green(), pixel.blue()),
+            (0x1F, 0x00, 0x01)
+        );
+
+        pixel.set_green(0xFF);
+        assert_eq!(pixel.as_raw().into_inner(), 0xFFE1);
+    }
+
+    #[test]
+    fn describe() {
+        assert_eq!(
+            Rgb565::describe(Rgb::Green),
+            ChannelDescriptor { shift: 5, bits: 6 }
+        );
+        assert_eq!(
+            Rgb565::describe(Rgb::Red),
+            ChannelDescriptor { shift: 11, bits: 5 }
+        );
+        assert_eq!(Rgb565::BITS_PER_PIXEL, 16);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn normalized() {
+        let pixel = Pixel::<Rgb565>::with_rgb_normalized(1.0, 0.5, -1.0);
+        assert_eq!(
+            (pixel.red(), pixel.green(), pixel.blue()),
+            (0x1F, 0x20, 0x00)
+        );
+        assert_eq!(pixel.rgb_normalized(), [1.0, 32.0 / 63.0, 0.0]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn convert() {
+        let rgba = Pixel::<Rgba8888>::with_rgba(0xFF, 0x82, 0x00, 0x40);
+        let pixel = rgba.convert::<Rgb565>();
+        assert_eq!(
+            (pixel.red(), pixel.green(), pixel.blue()),
+            (0x1F, 0x20, 0x00)
+        );
+
+        let back = pixel.convert::<Rgba8888>();
+        assert_eq!(back, Pixel::with_rgba(0xFF, 0x82, 0x00, 0xFF));
+        assert_eq!(pixel.convert::<FloatRgba>().alpha(), 1.0_f32);
+    }
+}
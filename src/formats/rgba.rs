@@ -1,15 +1,54 @@
 //! RGBA pixel formats.
 
+pub mod planar;
+
 mod abgr8888;
 pub use abgr8888::Abgr8888;
 
+mod abgr8888_be;
+pub use abgr8888_be::Abgr8888Be;
+
+mod abgr8888_le;
+pub use abgr8888_le::Abgr8888Le;
+
+mod css;
+pub use css::{CssHex, ParseColorError};
+
+mod double_rgba;
+pub use double_rgba::DoubleRgba;
+
 mod float_rgba;
 pub use float_rgba::FloatRgba;
 
+mod lens;
+pub use lens::{AlphaLens, ColorLens, split_rgb_alpha_mut};
+
+mod rgba16161616;
+pub use rgba16161616::Rgba16161616;
+
 mod rgba8888;
 pub use rgba8888::Rgba8888;
 
-use crate::pixel::{Format, Pixel, raw::RawPixel};
+mod rgba8888_be;
+pub use rgba8888_be::Rgba8888Be;
+
+mod rgba8888_le;
+pub use rgba8888_le::Rgba8888Le;
+
+mod rgba8_snorm;
+pub use rgba8_snorm::Rgba8Snorm;
+
+mod swizzled;
+pub use swizzled::Swizzled;
+
+use crate::{
+    alpha::AlphaFormat,
+    norm::Normalized,
+    pixel::{
+        ChannelOf, Format, Pixel,
+        raw::{ConstChannels, RawPixel},
+    },
+};
 
 /// Channels representing `R`ed, `G`reen, `B`lue, and `A`lpha components of a pixel.
 ///
@@ -29,8 +68,9 @@ pub enum Rgba {
 /// - `RED_OFFSET`: Offset of the red channel.
 /// - `GREEN_OFFSET`: Offset of the green channel.
 /// - `BLUE_OFFSET`: Offset of the blue channel.
-/// - `ALPHA_OFFSET`: Offset of the alpha channel.
-pub trait RgbaFormat: Format<Channels = Rgba> {
+///
+/// The alpha channel is defined by the [`AlphaFormat`] supertrait.
+pub trait RgbaFormat: AlphaFormat + Format<Channels = Rgba> {
     /// The offset of the red channel in the pixel's raw representation.
     const RED_OFFSET: usize;
 
@@ -40,9 +80,6 @@ pub trait RgbaFormat: Format<Channels = Rgba> {
     /// The offset of the blue channel in the pixel's raw representation.
     const BLUE_OFFSET: usize;
 
-    /// The offset of the alpha channel in the pixel's raw representation.
-    const ALPHA_OFFSET: usize;
-
     /// Returns the red channel value of a pixel.
     fn get_red(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
         pixel.get_channel(Self::RED_OFFSET)
@@ -72,15 +109,18 @@ pub trait RgbaFormat: Format<Channels = Rgba> {
     fn set_blue(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
         pixel.set_channel(Self::BLUE_OFFSET, value);
     }
+}
 
-    /// Returns the alpha channel value of a pixel.
-    fn get_alpha(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
-        pixel.get_channel(Self::ALPHA_OFFSET)
-    }
+impl<F: RgbaFormat> ChannelOf<F> for Rgba {
+    const ALL: &'static [Self] = &[Self::Red, Self::Green, Self::Blue, Self::Alpha];
 
-    /// Sets the alpha channel value of a pixel.
-    fn set_alpha(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
-        pixel.set_channel(Self::ALPHA_OFFSET, value);
+    fn offset(self) -> usize {
+        match self {
+            Self::Red => F::RED_OFFSET,
+            Self::Green => F::GREEN_OFFSET,
+            Self::Blue => F::BLUE_OFFSET,
+            Self::Alpha => F::ALPHA_OFFSET,
+        }
     }
 }
 
@@ -117,17 +157,6 @@ impl<F: RgbaFormat> Pixel<F> {
         F::set_blue(self.as_raw_mut(), value);
         self
     }
-
-    /// Returns the alpha channel value of the pixel.
-    pub fn alpha(&self) -> <F::RawPixel as RawPixel>::Channel {
-        F::get_alpha(self.as_raw())
-    }
-
-    /// Sets the alpha channel value of the pixel.
-    pub fn set_alpha(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
-        F::set_alpha(self.as_raw_mut(), value);
-        self
-    }
 }
 
 impl<F> Pixel<F>
@@ -137,6 +166,16 @@ where
     /// Creates a new pixel from RGBA channel values.
     ///
     /// This method initializes the pixel with the specified red, green, blue, and alpha values.
+    ///
+    /// In a `const` context, use `with_rgba_const`, which each built-in concrete RGBA format
+    /// provides instead:
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::rgba::Rgba8888, pixel::Pixel};
+    ///
+    /// const RED: Pixel<Rgba8888> = Pixel::<Rgba8888>::with_rgba_const(0xFF, 0x00, 0x00, 0xFF);
+    /// assert_eq!(RED, Pixel::with_rgba(0xFF, 0x00, 0x00, 0xFF));
+    /// ```
     pub fn with_rgba(
         r: <F::RawPixel as RawPixel>::Channel,
         g: <F::RawPixel as RawPixel>::Channel,
@@ -147,12 +186,201 @@ where
         pixel.set_red(r).set_green(g).set_blue(b).set_alpha(a);
         pixel
     }
+
+    /// Creates a new opaque pixel from RGB channel values.
+    ///
+    /// The alpha channel is set to the channel type's maximum, [`Normalized::ONE`] (such as `255`
+    /// for 8-bit channels or `1.0` for floating point channels).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::rgba::{FloatRgba, Rgba8888}, pixel::Pixel};
+    ///
+    /// assert_eq!(Pixel::<Rgba8888>::with_rgb(0x10, 0x20, 0x30).alpha(), 0xFF);
+    /// assert_eq!(Pixel::<FloatRgba>::with_rgb(0.1, 0.2, 0.3).alpha(), 1.0);
+    /// ```
+    pub fn with_rgb(
+        r: <F::RawPixel as RawPixel>::Channel,
+        g: <F::RawPixel as RawPixel>::Channel,
+        b: <F::RawPixel as RawPixel>::Channel,
+    ) -> Self
+    where
+        <F::RawPixel as RawPixel>::Channel: Normalized,
+    {
+        Self::with_rgba(r, g, b, Normalized::ONE)
+    }
+
+    /// Creates a new pixel from channel values in RGBA order, regardless of the memory layout.
+    ///
+    /// This is the inverse of [`channels`](Pixel::channels).
+    pub fn from_channels(channels: [<F::RawPixel as RawPixel>::Channel; 4]) -> Self {
+        let [r, g, b, a] = channels;
+        Self::with_rgba(r, g, b, a)
+    }
+
+    /// Returns the channel values of the pixel in RGBA order, regardless of the memory layout.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::rgba::{Abgr8888, Rgba8888}, pixel::Pixel};
+    ///
+    /// let rgba = Pixel::<Rgba8888>::with_rgba(0x10, 0x20, 0x30, 0x40);
+    /// let abgr = Pixel::<Abgr8888>::with_rgba(0x10, 0x20, 0x30, 0x40);
+    /// assert_eq!(rgba.channels(), [0x10, 0x20, 0x30, 0x40]);
+    /// assert_eq!(abgr.channels(), rgba.channels());
+    /// ```
+    pub fn channels(&self) -> [<F::RawPixel as RawPixel>::Channel; 4] {
+        [self.red(), self.green(), self.blue(), self.alpha()]
+    }
+}
+
+impl<F, T> From<[T; 4]> for Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = T>,
+{
+    /// Creates a pixel from channel values in RGBA order, regardless of the memory layout.
+    fn from(channels: [T; 4]) -> Self {
+        Self::from_channels(channels)
+    }
+}
+
+impl<F, T> From<(T, T, T, T)> for Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = T>,
+{
+    /// Creates a pixel from channel values in RGBA order, regardless of the memory layout.
+    fn from((r, g, b, a): (T, T, T, T)) -> Self {
+        Self::with_rgba(r, g, b, a)
+    }
+}
+
+impl<F, T> From<Pixel<F>> for [T; 4]
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = T>,
+{
+    /// Returns the channel values of the pixel in RGBA order, regardless of the memory layout.
+    fn from(pixel: Pixel<F>) -> Self {
+        pixel.channels()
+    }
+}
+
+impl<F, T> From<Pixel<F>> for (T, T, T, T)
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = T>,
+{
+    /// Returns the channel values of the pixel in RGBA order, regardless of the memory layout.
+    fn from(pixel: Pixel<F>) -> Self {
+        let [r, g, b, a] = pixel.channels();
+        (r, g, b, a)
+    }
+}
+
+impl<F: RgbaFormat> Pixel<F> {
+    /// A fully transparent (black) pixel, with every channel set to zero.
+    pub const TRANSPARENT: Self = Self::zeroed();
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: ConstChannels,
+{
+    /// An opaque black pixel.
+    pub const BLACK: Self = Self::from_raw(F::RawPixel::MAX_AT[F::ALPHA_OFFSET]);
+
+    /// An opaque white pixel, with every channel set to its maximum.
+    pub const WHITE: Self = Self::from_raw(F::RawPixel::MAX);
+}
+
+/// Implements the `const` RGBA constructor and accessor for each built-in concrete RGBA format.
+///
+/// The generic [`Pixel::with_rgba`] and [`Pixel::channels`] cannot be `const`, as they call trait
+/// methods, so these are built from each raw pixel type's `const fn` channel conversions instead.
+macro_rules! impl_const_rgba {
+  ($($format:ty => $channel:ty;)*) => {
+    $(
+      impl Pixel<$format> {
+        /// Creates a new pixel from RGBA channel values in a `const` context.
+        ///
+        /// This is equivalent to [`with_rgba`](Pixel::with_rgba), which cannot be `const` as it is
+        /// generic over the format.
+        #[must_use]
+        pub const fn with_rgba_const(r: $channel, g: $channel, b: $channel, a: $channel) -> Self {
+          let mut raw = [r; 4];
+          raw[<$format as RgbaFormat>::RED_OFFSET] = r;
+          raw[<$format as RgbaFormat>::GREEN_OFFSET] = g;
+          raw[<$format as RgbaFormat>::BLUE_OFFSET] = b;
+          raw[<$format as AlphaFormat>::ALPHA_OFFSET] = a;
+          let [c0, c1, c2, c3] = raw;
+          Self::from_raw(<<$format as Format>::RawPixel>::from_channels(c0, c1, c2, c3))
+        }
+
+        /// Returns the channel values of the pixel in RGBA order in a `const` context.
+        ///
+        /// This is equivalent to [`channels`](Pixel::channels), which cannot be `const` as it is
+        /// generic over the format.
+        #[must_use]
+        pub const fn channels_const(&self) -> [$channel; 4] {
+          let raw = self.as_raw().to_channels();
+          [
+            raw[<$format as RgbaFormat>::RED_OFFSET],
+            raw[<$format as RgbaFormat>::GREEN_OFFSET],
+            raw[<$format as RgbaFormat>::BLUE_OFFSET],
+            raw[<$format as AlphaFormat>::ALPHA_OFFSET],
+          ]
+        }
+      }
+    )*
+  };
+}
+
+impl_const_rgba! {
+  Abgr8888 => u8;
+  Abgr8888Be => u8;
+  Abgr8888Le => u8;
+  DoubleRgba => f64;
+  FloatRgba => f32;
+  Rgba16161616 => u16;
+  Rgba8888 => u8;
+  Rgba8888Be => u8;
+  Rgba8888Le => u8;
+  Rgba8Snorm => i8;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn describe_matches_memory_order() {
+        use crate::pixel::raw::{PlainBytes, RawPixel};
+
+        fn check<F>()
+        where
+            F: RgbaFormat,
+            F::RawPixel: PlainBytes + RawPixel<Channel = u8>,
+        {
+            for channel in [Rgba::Red, Rgba::Green, Rgba::Blue, Rgba::Alpha] {
+                let mut pixel = Pixel::<F>::zeroed();
+                pixel.set(channel, 0xFF);
+                let descriptor = F::describe(channel);
+                let mut expected = [0; 4];
+                expected[descriptor.shift as usize / 8] = 0xFF;
+                assert_eq!(pixel.as_bytes(), expected, "{} {channel:?}", F::NAME);
+            }
+        }
+        check::<Rgba8888Be>();
+        check::<Rgba8888Le>();
+        check::<Abgr8888Be>();
+        check::<Abgr8888Le>();
+    }
+
     #[test]
     fn with_rgba() {
         let pixel = Pixel::<Rgba8888>::with_rgba(0xFF, 0x00, 0x00, 0xFF);
@@ -161,4 +389,83 @@ mod tests {
         assert_eq!(pixel.blue(), 0x00);
         assert_eq!(pixel.alpha(), 0xFF);
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn from_array_and_tuple() {
+        let pixel = Pixel::<Abgr8888>::from([0x10, 0x20, 0x30, 0x40]);
+        assert_eq!(pixel, Pixel::with_rgba(0x10, 0x20, 0x30, 0x40));
+        assert_eq!(Pixel::<Abgr8888>::from((0x10, 0x20, 0x30, 0x40)), pixel);
+
+        let array: [u8; 4] = pixel.into();
+        assert_eq!(array, [0x10, 0x20, 0x30, 0x40]);
+        let tuple: (u8, u8, u8, u8) = pixel.into();
+        assert_eq!(tuple, (0x10, 0x20, 0x30, 0x40));
+
+        let float: Pixel<FloatRgba> = [0.25, 0.5, 0.75, 1.0].into();
+        assert_eq!(<[f32; 4]>::from(float), [0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[test]
+    fn with_rgb_is_opaque() {
+        let pixel = Pixel::<Abgr8888>::with_rgb(0x10, 0x20, 0x30);
+        assert_eq!(pixel.channels(), [0x10, 0x20, 0x30, 0xFF]);
+
+        let snorm = Pixel::<Rgba8Snorm>::with_rgb(-0x10, 0x20, 0x30);
+        assert_eq!(snorm.alpha(), 0x7F);
+    }
+
+    #[test]
+    fn color_constants() {
+        fn check<F>(max: <F::RawPixel as RawPixel>::Channel, black: Pixel<F>, white: Pixel<F>)
+        where
+            F: RgbaFormat + PartialEq + core::fmt::Debug,
+            F::RawPixel: RawPixel<Channel: Normalized> + PartialEq + core::fmt::Debug,
+        {
+            let zero = Normalized::from_f32(0.0);
+            assert_eq!(black, Pixel::with_rgba(zero, zero, zero, max));
+            assert_eq!(white, Pixel::with_rgba(max, max, max, max));
+            assert_eq!(
+                Pixel::<F>::TRANSPARENT,
+                Pixel::with_rgba(zero, zero, zero, zero)
+            );
+        }
+
+        check(0xFF, Pixel::<Rgba8888>::BLACK, Pixel::WHITE);
+        check(0xFF, Pixel::<Abgr8888>::BLACK, Pixel::WHITE);
+        check(0xFF, Pixel::<Rgba8888Be>::BLACK, Pixel::WHITE);
+        check(0xFF, Pixel::<Abgr8888Le>::BLACK, Pixel::WHITE);
+        check(0xFFFF, Pixel::<Rgba16161616>::BLACK, Pixel::WHITE);
+        check(0x7F, Pixel::<Rgba8Snorm>::BLACK, Pixel::WHITE);
+        check(1.0, Pixel::<FloatRgba>::BLACK, Pixel::WHITE);
+        check(
+            0xFF,
+            Pixel::<Swizzled<Rgba8888, 3, 2, 1, 0>>::BLACK,
+            Pixel::WHITE,
+        );
+    }
+
+    #[test]
+    fn const_rgba_matches_generic() {
+        const RED: Pixel<Rgba8888> = Pixel::<Rgba8888>::with_rgba_const(0xFF, 0x00, 0x00, 0xFF);
+        const CHANNELS: [u8; 4] = RED.channels_const();
+        assert_eq!(RED, Pixel::with_rgba(0xFF, 0x00, 0x00, 0xFF));
+        assert_eq!(CHANNELS, RED.channels());
+
+        let abgr = Pixel::<Abgr8888Be>::with_rgba_const(0x01, 0x02, 0x03, 0x04);
+        assert_eq!(abgr, Pixel::with_rgba(0x01, 0x02, 0x03, 0x04));
+        assert_eq!(abgr.channels_const(), [0x01, 0x02, 0x03, 0x04]);
+
+        let wide = Pixel::<Rgba16161616>::with_rgba_const(1, 2, 3, 4);
+        assert_eq!(wide.channels(), [1, 2, 3, 4]);
+        assert_eq!(wide.channels_const(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn channels_round_trip() {
+        let pixel = Pixel::<Abgr8888>::from_channels([0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(pixel, Pixel::with_rgba(0x01, 0x02, 0x03, 0x04));
+        assert_eq!(pixel.channels(), [0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(Pixel::<Abgr8888>::from_channels(pixel.channels()), pixel);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    alpha::AlphaFormat,
     formats::rgba::{Rgba, RgbaFormat},
     pixel::{Format, raw::U32x8888},
 };
@@ -12,6 +13,12 @@ use crate::{
 /// - `R`ed (8 bits)
 ///
 /// The pixel is represented as a 32-bit unsigned integer, where each channel occupies 8 bits.
+///
+/// The integer is stored in the host's byte order; use [`Rgba8888Be`] or [`Rgba8888Le`], which
+/// number the channels the same way, for pixel data with a fixed byte order.
+///
+/// [`Rgba8888Be`]: crate::formats::rgba::Rgba8888Be
+/// [`Rgba8888Le`]: crate::formats::rgba::Rgba8888Le
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Abgr8888 {}
 
@@ -19,13 +26,16 @@ impl crate::internal::Sealed for Abgr8888 {}
 impl Format for Abgr8888 {
     type RawPixel = U32x8888;
     type Channels = Rgba;
+    const NAME: &'static str = "Abgr8888";
 }
 impl RgbaFormat for Abgr8888 {
-    const ALPHA_OFFSET: usize = 3;
     const BLUE_OFFSET: usize = 2;
     const GREEN_OFFSET: usize = 1;
     const RED_OFFSET: usize = 0;
 }
+impl AlphaFormat for Abgr8888 {
+    const ALPHA_OFFSET: usize = 3;
+}
 
 #[cfg(test)]
 mod tests {
@@ -60,6 +70,19 @@ mod tests {
         assert_eq!(pixels[0].alpha(), 0xFF);
     }
 
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn ref_from_bytes_zerocopy() {
+        use zerocopy::{FromBytes, IntoBytes};
+
+        let bytes = 0xFF00_00FFu32.to_ne_bytes();
+        let pixels = <[Pixel<Abgr8888>]>::ref_from_bytes(&bytes).unwrap();
+        assert_eq!(pixels.len(), 1);
+        assert_eq!(pixels[0].red(), 0xFF);
+        assert_eq!(pixels[0].alpha(), 0xFF);
+        assert_eq!(pixels.as_bytes(), &bytes);
+    }
+
     #[cfg(feature = "bytemuck")]
     #[test]
     fn cast_slice_mut() {
@@ -0,0 +1,71 @@
+use crate::{
+    alpha::AlphaFormat,
+    formats::rgba::{Rgba, RgbaFormat},
+    pixel::{Format, raw::U32x8888Le},
+};
+
+/// A 32-bit ABGR pixel format with four 8-bit channels, stored in little-endian byte order.
+///
+/// Channels are numbered from the least significant byte of the integer, in the order of the
+/// name: alpha is channel `0` and red is channel `3`, the reverse of [`Rgba8888Le`]. The 32-bit
+/// integer is always stored little-endian, so the alpha channel is the first byte in memory on
+/// any host.
+///
+/// [`Rgba8888Le`]: crate::formats::rgba::Rgba8888Le
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Abgr8888Le {}
+
+impl crate::internal::Sealed for Abgr8888Le {}
+
+impl Format for Abgr8888Le {
+    type RawPixel = U32x8888Le;
+    type Channels = Rgba;
+    const NAME: &'static str = "Abgr8888Le";
+}
+
+impl RgbaFormat for Abgr8888Le {
+    const RED_OFFSET: usize = 3;
+    const GREEN_OFFSET: usize = 2;
+    const BLUE_OFFSET: usize = 1;
+}
+
+impl AlphaFormat for Abgr8888Le {
+    const ALPHA_OFFSET: usize = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        formats::rgba::Rgba8888Le,
+        pixel::{Pixel, raw::RawPixel},
+    };
+
+    use super::*;
+
+    #[test]
+    fn memory_order() {
+        let pixel = Pixel::<Abgr8888Le>::with_rgba(0x01, 0x02, 0x03, 0x04);
+        assert_eq!(pixel.as_raw().into_inner(), [0x04, 0x03, 0x02, 0x01]);
+        assert_eq!(pixel.as_raw().to_u32(), 0x0102_0304);
+    }
+
+    #[test]
+    fn from_raw() {
+        let pixel = Pixel::<Abgr8888Le>::new(U32x8888Le::from([0x04, 0x03, 0x02, 0x01]));
+        assert_eq!(pixel.red(), 0x01);
+        assert_eq!(pixel.green(), 0x02);
+        assert_eq!(pixel.blue(), 0x03);
+        assert_eq!(pixel.alpha(), 0x04);
+    }
+
+    #[test]
+    fn differs_from_rgba() {
+        let abgr = Pixel::<Abgr8888Le>::with_rgba(0x01, 0x02, 0x03, 0x04);
+        let rgba = Pixel::<Rgba8888Le>::with_rgba(0x01, 0x02, 0x03, 0x04);
+        assert_ne!(abgr.as_raw().into_inner(), rgba.as_raw().into_inner());
+
+        let mut reversed = rgba.as_raw().into_inner();
+        reversed.reverse();
+        assert_eq!(abgr.as_raw().into_inner(), reversed);
+    }
+}
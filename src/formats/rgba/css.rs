@@ -0,0 +1,254 @@
+//! Parsing and formatting RGBA pixels as CSS-style color strings.
+
+use core::{
+    error::Error,
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use crate::{
+    colors,
+    formats::rgba::RgbaFormat,
+    norm::Normalized,
+    pixel::{Format, Pixel, raw::RawPixel},
+};
+
+/// An error returned when parsing a pixel from a string fails.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ParseColorError {
+    /// The string is neither a hex color (starting with `#`) nor a named color.
+    UnknownName,
+
+    /// The hex color does not have 3, 4, 6, or 8 digits.
+    InvalidLength {
+        /// The number of characters after the `#`.
+        length: usize,
+    },
+
+    /// The hex color contains a character that is not a hexadecimal digit.
+    InvalidDigit {
+        /// The first invalid character.
+        digit: char,
+    },
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownName => write!(f, "Unknown color name"),
+            Self::InvalidLength { length } => {
+                write!(f, "Hex color has {length} digits, expected 3, 4, 6, or 8")
+            }
+            Self::InvalidDigit { digit } => write!(f, "Invalid hex digit {digit:?}"),
+        }
+    }
+}
+
+impl Error for ParseColorError {}
+
+/// Parses a color with 8-bit channels from a string, returning the channels in RGBA order.
+fn parse(s: &str) -> Result<[u8; 4], ParseColorError> {
+    let Some(hex) = s.strip_prefix('#') else {
+        return colors::from_name(s).ok_or(ParseColorError::UnknownName);
+    };
+    if let Some(digit) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(ParseColorError::InvalidDigit { digit });
+    }
+
+    // Every character is an ASCII hex digit, so each is a single byte.
+    let digits = hex.as_bytes();
+    let short = |i: usize| hex_digit(digits[i]) * 0x11;
+    let long = |i: usize| hex_digit(digits[i * 2]) << 4 | hex_digit(digits[i * 2 + 1]);
+    match hex.len() {
+        3 => Ok([short(0), short(1), short(2), 0xFF]),
+        4 => Ok([short(0), short(1), short(2), short(3)]),
+        6 => Ok([long(0), long(1), long(2), 0xFF]),
+        8 => Ok([long(0), long(1), long(2), long(3)]),
+        length => Err(ParseColorError::InvalidLength { length }),
+    }
+}
+
+/// Returns the value of an ASCII hex digit.
+const fn hex_digit(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        _ => digit - b'A' + 10,
+    }
+}
+
+/// Parses a pixel from a CSS-style hex color or named color.
+///
+/// Hex colors start with `#`, followed by `RGB`, `RGBA`, `RRGGBB`, or `RRGGBBAA` hexadecimal
+/// digits (in either case); the shorthand forms repeat each digit, and the alpha channel defaults
+/// to opaque. Otherwise, the string is looked up as a [named color](crate::colors), ignoring case.
+///
+/// ## Example
+///
+/// ```rust
+/// use pxlfmt::{formats::rgba::{ParseColorError, Rgba8888}, pixel::Pixel};
+///
+/// let pixel: Pixel<Rgba8888> = "#FF8000".parse().unwrap();
+/// assert_eq!(pixel, Pixel::with_rgba(0xFF, 0x80, 0x00, 0xFF));
+/// assert_eq!("#f808".parse(), Ok(Pixel::<Rgba8888>::with_rgba(0xFF, 0x88, 0x00, 0x88)));
+/// assert_eq!("teal".parse(), Ok(Pixel::<Rgba8888>::with_rgba(0x00, 0x80, 0x80, 0xFF)));
+///
+/// let error = "#12345".parse::<Pixel<Rgba8888>>();
+/// assert_eq!(error, Err(ParseColorError::InvalidLength { length: 5 }));
+/// ```
+impl<F> FromStr for Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = u8>,
+{
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s).map(Self::from_channels)
+    }
+}
+
+/// Formats the pixel as a CSS `rgba()` color, such as `rgba(255, 128, 0, 1.0)`.
+///
+/// The color channels are scaled to `0..=255` and rounded, and the alpha channel is normalized to
+/// `0.0..=1.0` and rounded to 3 decimal places. Use [`Pixel::css_hex`] for the `#rrggbbaa` form.
+///
+/// ## Example
+///
+/// ```rust
+/// use pxlfmt::{formats::rgba::{FloatRgba, Rgba8888}, pixel::Pixel};
+///
+/// let pixel = Pixel::<Rgba8888>::with_rgba(0xFF, 0x80, 0x00, 0x80);
+/// assert_eq!(pixel.to_string(), "rgba(255, 128, 0, 0.502)");
+///
+/// let pixel = Pixel::<FloatRgba>::with_rgba(1.0, 0.5, 0.0, 1.0);
+/// assert_eq!(pixel.to_string(), "rgba(255, 128, 0, 1.0)");
+/// ```
+impl<F> Display for Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let [r, g, b, _] = to_u8(self);
+        let alpha = libm::roundf(self.alpha().to_f32().clamp(0.0, 1.0) * 1000.0) / 1000.0;
+        write!(f, "rgba({r}, {g}, {b}, {alpha:?})")
+    }
+}
+
+/// Returns the channels of a pixel in RGBA order, quantized to 8 bits.
+fn to_u8<F>(pixel: &Pixel<F>) -> [u8; 4]
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    pixel.channels().map(|c| u8::from_f32(c.to_f32()))
+}
+
+/// Formats a pixel as a CSS hex color, such as `#ff8000ff`.
+///
+/// Created by [`Pixel::css_hex`].
+pub struct CssHex<F: Format>(Pixel<F>);
+
+impl<F> Debug for CssHex<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("CssHex").field(&to_u8(&self.0)).finish()
+    }
+}
+
+impl<F> Display for CssHex<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let [red, green, blue, alpha] = to_u8(&self.0);
+        write!(f, "#{red:02x}{green:02x}{blue:02x}{alpha:02x}")
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    /// Returns a wrapper that formats the pixel as a CSS hex color, such as `#ff8000ff`.
+    ///
+    /// Channels are always written in RGBA order, regardless of the memory layout, and are
+    /// quantized to 8 bits. The output can be parsed back with [`FromStr`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::rgba::Abgr8888, pixel::Pixel};
+    ///
+    /// let pixel = Pixel::<Abgr8888>::with_rgba(0xFF, 0x80, 0x00, 0xFF);
+    /// assert_eq!(pixel.css_hex().to_string(), "#ff8000ff");
+    /// ```
+    #[must_use]
+    pub fn css_hex(&self) -> CssHex<F> {
+        CssHex(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::string::ToString;
+
+    use crate::formats::rgba::{FloatRgba, Rgba8888};
+
+    use super::*;
+
+    #[test]
+    fn display_rgba() {
+        let clear = Pixel::<Rgba8888>::TRANSPARENT;
+        assert_eq!(clear.to_string(), "rgba(0, 0, 0, 0.0)");
+
+        let hdr = Pixel::<FloatRgba>::with_rgba(2.0, -1.0, 0.25, 0.3333);
+        assert_eq!(hdr.to_string(), "rgba(255, 0, 64, 0.333)");
+    }
+
+    #[test]
+    fn css_hex_round_trip() {
+        let pixel = Pixel::<Rgba8888>::with_rgba(0x01, 0xAB, 0x7F, 0x00);
+        let hex = pixel.css_hex().to_string();
+        assert_eq!(hex, "#01ab7f00");
+        assert_eq!(hex.parse(), Ok(pixel));
+    }
+
+    #[test]
+    fn parse_hex_forms() {
+        assert_eq!(parse("#123"), Ok([0x11, 0x22, 0x33, 0xFF]));
+        assert_eq!(parse("#1234"), Ok([0x11, 0x22, 0x33, 0x44]));
+        assert_eq!(parse("#a0B1c2"), Ok([0xA0, 0xB1, 0xC2, 0xFF]));
+        assert_eq!(parse("#A0B1C2D3"), Ok([0xA0, 0xB1, 0xC2, 0xD3]));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse("#"),
+            Err(ParseColorError::InvalidLength { length: 0 })
+        );
+        assert_eq!(
+            parse("#12G"),
+            Err(ParseColorError::InvalidDigit { digit: 'G' })
+        );
+        assert_eq!(
+            parse("#+12"),
+            Err(ParseColorError::InvalidDigit { digit: '+' })
+        );
+        assert_eq!(
+            parse("#é"),
+            Err(ParseColorError::InvalidDigit { digit: 'é' })
+        );
+        assert_eq!(parse("123456"), Err(ParseColorError::UnknownName));
+        assert_eq!(parse(""), Err(ParseColorError::UnknownName));
+    }
+}
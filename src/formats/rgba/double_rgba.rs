@@ -0,0 +1,90 @@
+use crate::{
+    alpha::AlphaFormat,
+    formats::rgba::{FloatRgba, Rgba, RgbaFormat},
+    pixel::{Format, Pixel, raw::F64x4},
+};
+
+/// A 256-bit RGBA pixel format with four 64-bit floating point channels.
+///
+/// This format is used to represent pixels in the RGBA order:
+/// - `R`ed (64 bits)
+/// - `G`reen (64 bits)
+/// - `B`lue (64 bits)
+/// - `A`lpha (64 bits)
+///
+/// The pixel is represented as a 256-bit value, where each channel occupies 64 bits. This is
+/// intended for accumulation buffers in scientific imaging pipelines, where the precision of
+/// [`FloatRgba`] is not enough; pixels can be converted to and from [`FloatRgba`] using [`From`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum DoubleRgba {}
+
+impl crate::internal::Sealed for DoubleRgba {}
+
+impl Format for DoubleRgba {
+    type RawPixel = F64x4;
+    type Channels = Rgba;
+    const NAME: &'static str = "DoubleRgba";
+}
+
+impl RgbaFormat for DoubleRgba {
+    const RED_OFFSET: usize = 0;
+    const GREEN_OFFSET: usize = 1;
+    const BLUE_OFFSET: usize = 2;
+}
+
+impl AlphaFormat for DoubleRgba {
+    const ALPHA_OFFSET: usize = 3;
+}
+
+impl From<Pixel<FloatRgba>> for Pixel<DoubleRgba> {
+    fn from(rgba: Pixel<FloatRgba>) -> Self {
+        Pixel::with_rgba(
+            f64::from(rgba.red()),
+            f64::from(rgba.green()),
+            f64::from(rgba.blue()),
+            f64::from(rgba.alpha()),
+        )
+    }
+}
+
+impl From<Pixel<DoubleRgba>> for Pixel<FloatRgba> {
+    /// Converts the pixel to single precision, rounding each channel to the nearest `f32`.
+    #[allow(clippy::cast_possible_truncation)]
+    fn from(rgba: Pixel<DoubleRgba>) -> Self {
+        Pixel::with_rgba(
+            rgba.red() as f32,
+            rgba.green() as f32,
+            rgba.blue() as f32,
+            rgba.alpha() as f32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::raw::RawPixel;
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn from_raw() {
+        let mut pixel = Pixel::<DoubleRgba>::from_raw([0.5, 0.25, 0.75, 1.0].into());
+        assert_eq!(pixel.red(), 0.5);
+        assert_eq!(pixel.green(), 0.25);
+        assert_eq!(pixel.blue(), 0.75);
+        assert_eq!(pixel.alpha(), 1.0);
+
+        pixel.set_red(0.1).set_alpha(0.0);
+        assert_eq!(pixel.as_raw().into_inner(), [0.1, 0.25, 0.75, 0.0]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn float_round_trip() {
+        let float = Pixel::<FloatRgba>::with_rgba(0.1, 0.2, 0.3, 1.0);
+        let double = Pixel::<DoubleRgba>::from(float);
+        assert_eq!(double.red(), f64::from(0.1_f32));
+        assert_eq!(Pixel::<FloatRgba>::from(double), float);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    alpha::AlphaFormat,
     formats::rgba::{Rgba, RgbaFormat},
     pixel::{Format, raw::F32x4},
 };
@@ -20,12 +21,16 @@ impl crate::internal::Sealed for FloatRgba {}
 impl Format for FloatRgba {
     type RawPixel = F32x4;
     type Channels = Rgba;
+    const NAME: &'static str = "FloatRgba";
 }
 
 impl RgbaFormat for FloatRgba {
     const RED_OFFSET: usize = 0;
     const GREEN_OFFSET: usize = 1;
     const BLUE_OFFSET: usize = 2;
+}
+
+impl AlphaFormat for FloatRgba {
     const ALPHA_OFFSET: usize = 3;
 }
 
@@ -0,0 +1,193 @@
+use core::cell::Cell;
+
+use crate::{
+    formats::rgba::RgbaFormat,
+    pixel::{Pixel, raw::RawPixel},
+};
+
+/// Splits a slice of RGBA pixels into two lenses over the color and alpha channels, respectively.
+///
+/// Both lenses borrow the same pixels at the same time, but each one only ever reads and writes its
+/// own channels, so algorithms that process color and alpha in separate passes (or interleaved) can
+/// do so without copying the buffer.
+///
+/// Neither lens can be sent to or shared with another thread, which is what makes this safe.
+///
+/// ## Example
+///
+/// ```rust
+/// use pxlfmt::{formats::rgba::{Rgba8888, split_rgb_alpha_mut}, pixel::Pixel};
+///
+/// let mut pixels = [Pixel::<Rgba8888>::with_rgba(0x10, 0x20, 0x30, 0x80); 2];
+/// let (color, alpha) = split_rgb_alpha_mut(&mut pixels);
+///
+/// for i in 0..color.len() {
+///     if let Some((r, g, b)) = color.rgb(i) {
+///         color.set_rgb(i, r * 2, g * 2, b * 2);
+///     }
+///     alpha.set_alpha(i, 0xFF);
+/// }
+///
+/// assert_eq!(pixels[1], Pixel::with_rgba(0x20, 0x40, 0x60, 0xFF));
+/// ```
+pub fn split_rgb_alpha_mut<F: RgbaFormat>(
+    pixels: &mut [Pixel<F>],
+) -> (ColorLens<'_, F>, AlphaLens<'_, F>) {
+    let cells = Cell::from_mut(pixels).as_slice_of_cells();
+    (ColorLens { pixels: cells }, AlphaLens { pixels: cells })
+}
+
+/// A lens over the red, green, and blue channels of a slice of pixels.
+///
+/// Created by [`split_rgb_alpha_mut`].
+pub struct ColorLens<'a, F: RgbaFormat> {
+    pixels: &'a [Cell<Pixel<F>>],
+}
+
+impl<F: RgbaFormat> core::fmt::Debug for ColorLens<'_, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ColorLens")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: RgbaFormat> ColorLens<'_, F> {
+    /// Returns the number of pixels in the lens.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.pixels.len()
+    }
+
+    /// Returns `true` if the lens contains no pixels.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.pixels.is_empty()
+    }
+
+    /// Returns the red, green, and blue channels of the pixel at `index`, or `None` if out of bounds.
+    #[allow(clippy::type_complexity)]
+    #[must_use]
+    pub fn rgb(
+        &self,
+        index: usize,
+    ) -> Option<(
+        <F::RawPixel as RawPixel>::Channel,
+        <F::RawPixel as RawPixel>::Channel,
+        <F::RawPixel as RawPixel>::Channel,
+    )> {
+        let pixel = self.pixels.get(index)?.get();
+        Some((pixel.red(), pixel.green(), pixel.blue()))
+    }
+
+    /// Sets the red, green, and blue channels of the pixel at `index`, leaving alpha untouched.
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn set_rgb(
+        &self,
+        index: usize,
+        r: <F::RawPixel as RawPixel>::Channel,
+        g: <F::RawPixel as RawPixel>::Channel,
+        b: <F::RawPixel as RawPixel>::Channel,
+    ) {
+        let cell = &self.pixels[index];
+        let mut pixel = cell.get();
+        pixel.set_red(r).set_green(g).set_blue(b);
+        cell.set(pixel);
+    }
+}
+
+/// A lens over the alpha channel of a slice of pixels.
+///
+/// Created by [`split_rgb_alpha_mut`].
+pub struct AlphaLens<'a, F: RgbaFormat> {
+    pixels: &'a [Cell<Pixel<F>>],
+}
+
+impl<F: RgbaFormat> core::fmt::Debug for AlphaLens<'_, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AlphaLens")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F: RgbaFormat> AlphaLens<'_, F> {
+    /// Returns the number of pixels in the lens.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.pixels.len()
+    }
+
+    /// Returns `true` if the lens contains no pixels.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.pixels.is_empty()
+    }
+
+    /// Returns the alpha channel of the pixel at `index`, or `None` if out of bounds.
+    #[must_use]
+    pub fn alpha(&self, index: usize) -> Option<<F::RawPixel as RawPixel>::Channel> {
+        Some(self.pixels.get(index)?.get().alpha())
+    }
+
+    /// Sets the alpha channel of the pixel at `index`, leaving the color channels untouched.
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn set_alpha(&self, index: usize, value: <F::RawPixel as RawPixel>::Channel) {
+        let cell = &self.pixels[index];
+        let mut pixel = cell.get();
+        pixel.set_alpha(value);
+        cell.set(pixel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{FloatRgba, Rgba8888};
+
+    use super::*;
+
+    #[test]
+    fn interleaved_writes_do_not_clobber() {
+        let mut pixels = [Pixel::<Rgba8888>::zeroed(); 3];
+        let (color, alpha) = split_rgb_alpha_mut(&mut pixels);
+        assert_eq!(color.len(), 3);
+        assert_eq!(alpha.len(), 3);
+
+        alpha.set_alpha(1, 0xAA);
+        color.set_rgb(1, 0x01, 0x02, 0x03);
+        alpha.set_alpha(2, 0xBB);
+
+        assert_eq!(color.rgb(1), Some((0x01, 0x02, 0x03)));
+        assert_eq!(alpha.alpha(1), Some(0xAA));
+        assert_eq!(color.rgb(3), None);
+        assert_eq!(alpha.alpha(3), None);
+
+        assert_eq!(pixels[0], Pixel::zeroed());
+        assert_eq!(pixels[1], Pixel::with_rgba(0x01, 0x02, 0x03, 0xAA));
+        assert_eq!(pixels[2], Pixel::with_rgba(0x00, 0x00, 0x00, 0xBB));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn float_rgba() {
+        let mut pixels = [Pixel::<FloatRgba>::zeroed()];
+        let (color, alpha) = split_rgb_alpha_mut(&mut pixels);
+        color.set_rgb(0, 0.25, 0.5, 0.75);
+        alpha.set_alpha(0, 1.0);
+        assert_eq!(pixels[0].as_raw().into_inner(), [0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn set_out_of_bounds() {
+        let mut pixels = [Pixel::<Rgba8888>::zeroed()];
+        let (_, alpha) = split_rgb_alpha_mut(&mut pixels);
+        alpha.set_alpha(1, 0xFF);
+    }
+}
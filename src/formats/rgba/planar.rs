@@ -0,0 +1,165 @@
+//! Conversions between planar (channel-separated) RGB data and packed RGBA pixels.
+//!
+//! Scientific imaging libraries, and array libraries such as `NumPy`, often store each channel of an
+//! image in its own array. [`interleave_rgb`] packs such arrays into a slice of RGBA pixels, and
+//! [`deinterleave_rgb`] splits them back out:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::rgba::{Rgba8888, planar::{deinterleave_rgb, interleave_rgb}},
+//!     pixel::Pixel,
+//! };
+//!
+//! let (r, g, b) = ([0x10, 0x11], [0x20, 0x21], [0x30, 0x31]);
+//! let mut pixels = [Pixel::<Rgba8888>::zeroed(); 2];
+//! interleave_rgb(&r, &g, &b, None, &mut pixels);
+//! assert_eq!(pixels[1], Pixel::with_rgba(0x11, 0x21, 0x31, 0xFF));
+//!
+//! let mut a = [0; 2];
+//! let (mut r, mut g, mut b) = ([0; 2], [0; 2], [0; 2]);
+//! deinterleave_rgb(&pixels, &mut r, &mut g, &mut b, Some(&mut a));
+//! assert_eq!((r, a), ([0x10, 0x11], [0xFF, 0xFF]));
+//! ```
+//!
+//! Both functions are written as straight-line loops over equally sized slices, without bounds
+//! checks inside the loop, so they are readily auto-vectorized.
+
+use crate::{
+    formats::rgba::RgbaFormat,
+    norm::Normalized,
+    pixel::{Pixel, raw::RawPixel},
+};
+
+/// Packs separate red, green, blue, and (optionally) alpha channel slices into `dst`.
+///
+/// If `a` is `None`, every pixel is made fully opaque.
+///
+/// ## Panics
+///
+/// If any of the slices have a different length than `dst`.
+pub fn interleave_rgb<F, T>(r: &[T], g: &[T], b: &[T], a: Option<&[T]>, dst: &mut [Pixel<F>])
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = T>,
+    T: Normalized,
+{
+    if let Some(a) = a {
+        return interleave_rgba(r, g, b, a, dst);
+    }
+    let len = dst.len();
+    assert!(
+        r.len() == len && g.len() == len && b.len() == len,
+        "Slices must have the same length"
+    );
+    let (r, g, b) = (&r[..len], &g[..len], &b[..len]);
+    for i in 0..len {
+        dst[i] = Pixel::with_rgba(r[i], g[i], b[i], T::ONE);
+    }
+}
+
+/// Packs separate red, green, blue, and alpha channel slices into `dst`.
+///
+/// Unlike [`interleave_rgb`], the channels do not need to be [`Normalized`].
+///
+/// ## Panics
+///
+/// If any of the slices have a different length than `dst`.
+pub(crate) fn interleave_rgba<F, T>(r: &[T], g: &[T], b: &[T], a: &[T], dst: &mut [Pixel<F>])
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = T>,
+    T: Copy,
+{
+    let len = dst.len();
+    assert!(
+        r.len() == len && g.len() == len && b.len() == len && a.len() == len,
+        "Slices must have the same length"
+    );
+    let (r, g, b, a) = (&r[..len], &g[..len], &b[..len], &a[..len]);
+    for i in 0..len {
+        dst[i] = Pixel::with_rgba(r[i], g[i], b[i], a[i]);
+    }
+}
+
+/// Splits `src` into separate red, green, blue, and (optionally) alpha channel slices.
+///
+/// If `a` is `None`, the alpha channel is discarded.
+///
+/// ## Panics
+///
+/// If any of the slices have a different length than `src`.
+pub fn deinterleave_rgb<F, T>(
+    src: &[Pixel<F>],
+    r: &mut [T],
+    g: &mut [T],
+    b: &mut [T],
+    a: Option<&mut [T]>,
+) where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = T>,
+{
+    let len = src.len();
+    assert!(
+        r.len() == len && g.len() == len && b.len() == len,
+        "Slices must have the same length"
+    );
+    let (r, g, b) = (&mut r[..len], &mut g[..len], &mut b[..len]);
+    for i in 0..len {
+        r[i] = src[i].red();
+        g[i] = src[i].green();
+        b[i] = src[i].blue();
+    }
+    if let Some(a) = a {
+        assert_eq!(a.len(), len, "Slices must have the same length");
+        for (a, pixel) in a.iter_mut().zip(src) {
+            *a = pixel.alpha();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{Abgr8888, FloatRgba};
+
+    use super::*;
+
+    #[test]
+    fn round_trip_with_alpha() {
+        let (r, g, b, a) = ([1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]);
+        let mut pixels = [Pixel::<Abgr8888>::zeroed(); 3];
+        interleave_rgb(&r, &g, &b, Some(&a), &mut pixels);
+        assert_eq!(pixels[2], Pixel::with_rgba(3, 6, 9, 12));
+
+        let mut out = [[0; 3]; 4];
+        let [or, og, ob, oa] = &mut out;
+        deinterleave_rgb(&pixels, or, og, ob, Some(oa));
+        assert_eq!(out, [r, g, b, a]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn float_opaque() {
+        let mut pixels = [Pixel::<FloatRgba>::zeroed()];
+        interleave_rgb(&[0.25], &[0.5], &[0.75], None, &mut pixels);
+        assert_eq!(pixels[0].alpha(), 1.0);
+
+        let (mut r, mut g, mut b) = ([0.0], [0.0], [0.0]);
+        deinterleave_rgb(&pixels, &mut r, &mut g, &mut b, None);
+        assert_eq!((r, g, b), ([0.25], [0.5], [0.75]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Slices must have the same length")]
+    fn interleave_length_mismatch() {
+        let mut pixels = [Pixel::<Abgr8888>::zeroed(); 2];
+        interleave_rgb(&[0; 2], &[0; 2], &[0; 1], None, &mut pixels);
+    }
+
+    #[test]
+    #[should_panic(expected = "Slices must have the same length")]
+    fn deinterleave_alpha_length_mismatch() {
+        let pixels = [Pixel::<Abgr8888>::zeroed(); 2];
+        let (mut r, mut g, mut b, mut a) = ([0; 2], [0; 2], [0; 2], [0; 3]);
+        deinterleave_rgb(&pixels, &mut r, &mut g, &mut b, Some(&mut a));
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{
+    alpha::AlphaFormat,
+    formats::rgba::{Rgba, RgbaFormat},
+    pixel::{Format, raw::U16x4},
+};
+
+/// A 64-bit RGBA pixel format with four 16-bit unsigned normalized channels.
+///
+/// This format is used to represent pixels in the RGBA order:
+/// - `R`ed (16 bits)
+/// - `G`reen (16 bits)
+/// - `B`lue (16 bits)
+/// - `A`lpha (16 bits)
+///
+/// The pixel is represented as four 16-bit integers, in memory order, in the host's byte order.
+/// This is a common layout for HDR video frames, which are usually encoded with the PQ or HLG
+/// [`TransferFunction`](crate::transfer::TransferFunction).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Rgba16161616 {}
+
+impl crate::internal::Sealed for Rgba16161616 {}
+
+impl Format for Rgba16161616 {
+    type RawPixel = U16x4;
+    type Channels = Rgba;
+    const NAME: &'static str = "Rgba16161616";
+}
+
+impl RgbaFormat for Rgba16161616 {
+    const RED_OFFSET: usize = 0;
+    const GREEN_OFFSET: usize = 1;
+    const BLUE_OFFSET: usize = 2;
+}
+
+impl AlphaFormat for Rgba16161616 {
+    const ALPHA_OFFSET: usize = 3;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::{Pixel, raw::RawPixel};
+
+    use super::*;
+
+    #[test]
+    fn from_raw() {
+        let mut pixel = Pixel::<Rgba16161616>::new(U16x4::from([1, 2, 3, 0xFFFF]));
+        assert_eq!(pixel.red(), 1);
+        assert_eq!(pixel.green(), 2);
+        assert_eq!(pixel.blue(), 3);
+        assert_eq!(pixel.alpha(), 0xFFFF);
+
+        pixel.set_green(0x8000);
+        assert_eq!(pixel.as_raw().into_inner(), [1, 0x8000, 3, 0xFFFF]);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    alpha::AlphaFormat,
     formats::rgba::{Rgba, RgbaFormat},
     pixel::{Format, raw::U32x8888},
 };
@@ -12,6 +13,12 @@ use crate::{
 /// - `A`lpha (8 bits)
 ///
 /// The pixel is represented as a 32-bit unsigned integer, where each channel occupies 8 bits.
+///
+/// The integer is stored in the host's byte order; use [`Rgba8888Be`] or [`Rgba8888Le`] for pixel data
+/// with a fixed byte order.
+///
+/// [`Rgba8888Be`]: crate::formats::rgba::Rgba8888Be
+/// [`Rgba8888Le`]: crate::formats::rgba::Rgba8888Le
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Rgba8888 {}
 
@@ -20,12 +27,16 @@ impl crate::internal::Sealed for Rgba8888 {}
 impl Format for Rgba8888 {
     type RawPixel = U32x8888;
     type Channels = Rgba;
+    const NAME: &'static str = "Rgba8888";
 }
 
 impl RgbaFormat for Rgba8888 {
     const RED_OFFSET: usize = 0;
     const GREEN_OFFSET: usize = 1;
     const BLUE_OFFSET: usize = 2;
+}
+
+impl AlphaFormat for Rgba8888 {
     const ALPHA_OFFSET: usize = 3;
 }
 
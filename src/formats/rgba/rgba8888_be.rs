@@ -0,0 +1,56 @@
+use crate::{
+    alpha::AlphaFormat,
+    formats::rgba::{Rgba, RgbaFormat},
+    pixel::{Format, raw::U32x8888Be},
+};
+
+/// A 32-bit RGBA pixel format with four 8-bit channels, stored in big-endian byte order.
+///
+/// This format has the same channel layout as [`Rgba8888`], but the 32-bit integer is always
+/// stored big-endian, so the alpha channel is the first byte in memory on any host. Use it
+/// to read or write pixel data from sources with a fixed byte order.
+///
+/// [`Rgba8888`]: crate::formats::rgba::Rgba8888
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Rgba8888Be {}
+
+impl crate::internal::Sealed for Rgba8888Be {}
+
+impl Format for Rgba8888Be {
+    type RawPixel = U32x8888Be;
+    type Channels = Rgba;
+    const NAME: &'static str = "Rgba8888Be";
+}
+
+impl RgbaFormat for Rgba8888Be {
+    const RED_OFFSET: usize = 0;
+    const GREEN_OFFSET: usize = 1;
+    const BLUE_OFFSET: usize = 2;
+}
+
+impl AlphaFormat for Rgba8888Be {
+    const ALPHA_OFFSET: usize = 3;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::{Pixel, raw::RawPixel};
+
+    use super::*;
+
+    #[test]
+    fn memory_order() {
+        let pixel = Pixel::<Rgba8888Be>::with_rgba(0x01, 0x02, 0x03, 0x04);
+        assert_eq!(pixel.as_raw().into_inner(), [0x04, 0x03, 0x02, 0x01]);
+        assert_eq!(pixel.as_raw().to_u32(), 0x0403_0201);
+    }
+
+    #[test]
+    fn from_raw() {
+        let pixel = Pixel::<Rgba8888Be>::new(U32x8888Be::from([0x04, 0x03, 0x02, 0x01]));
+        assert_eq!(pixel.red(), 0x01);
+        assert_eq!(pixel.green(), 0x02);
+        assert_eq!(pixel.blue(), 0x03);
+        assert_eq!(pixel.alpha(), 0x04);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    alpha::AlphaFormat,
+    formats::rgba::{FloatRgba, Rgba, RgbaFormat},
+    norm::Normalized,
+    pixel::{Format, Pixel, raw::I8x4},
+};
+
+/// A 32-bit RGBA pixel format with four 8-bit signed normalized (snorm) channels.
+///
+/// This format is used to represent pixels in the RGBA order:
+/// - `R`ed (8 bits)
+/// - `G`reen (8 bits)
+/// - `B`lue (8 bits)
+/// - `A`lpha (8 bits)
+///
+/// The pixel is represented as four bytes, in memory order. Each channel maps `-127..=127` to
+/// `-1.0..=1.0` (`-128` is also `-1.0`), as with `DXGI_FORMAT_R8G8B8A8_SNORM`, and is commonly
+/// used for normal maps. Pixels can be converted to and from [`FloatRgba`] using [`From`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Rgba8Snorm {}
+
+impl crate::internal::Sealed for Rgba8Snorm {}
+
+impl Format for Rgba8Snorm {
+    type RawPixel = I8x4;
+    type Channels = Rgba;
+    const NAME: &'static str = "Rgba8Snorm";
+}
+
+impl RgbaFormat for Rgba8Snorm {
+    const RED_OFFSET: usize = 0;
+    const GREEN_OFFSET: usize = 1;
+    const BLUE_OFFSET: usize = 2;
+}
+
+impl AlphaFormat for Rgba8Snorm {
+    const ALPHA_OFFSET: usize = 3;
+}
+
+impl From<Pixel<FloatRgba>> for Pixel<Rgba8Snorm> {
+    fn from(rgba: Pixel<FloatRgba>) -> Self {
+        Pixel::with_rgba(
+            i8::from_f32(rgba.red()),
+            i8::from_f32(rgba.green()),
+            i8::from_f32(rgba.blue()),
+            i8::from_f32(rgba.alpha()),
+        )
+    }
+}
+
+impl From<Pixel<Rgba8Snorm>> for Pixel<FloatRgba> {
+    fn from(rgba: Pixel<Rgba8Snorm>) -> Self {
+        Pixel::with_rgba(
+            rgba.red().to_f32(),
+            rgba.green().to_f32(),
+            rgba.blue().to_f32(),
+            rgba.alpha().to_f32(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::raw::RawPixel;
+
+    use super::*;
+
+    #[test]
+    fn from_raw() {
+        let mut pixel = Pixel::<Rgba8Snorm>::new(I8x4::from([1, -1, 127, -128]));
+        assert_eq!(pixel.red(), 1);
+        assert_eq!(pixel.green(), -1);
+        assert_eq!(pixel.blue(), 127);
+        assert_eq!(pixel.alpha(), -128);
+
+        pixel.set_red(-2).set_alpha(0);
+        assert_eq!(pixel.as_raw().into_inner(), [-2, -1, 127, 0]);
+    }
+
+    #[test]
+    fn from_float() {
+        let pixel = Pixel::<Rgba8Snorm>::from(Pixel::<FloatRgba>::with_rgba(-1.0, 0.0, 0.5, 2.0));
+        assert_eq!(pixel, Pixel::with_rgba(-127, 0, 64, 127));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn into_float() {
+        let pixel = Pixel::<FloatRgba>::from(Pixel::<Rgba8Snorm>::with_rgba(-128, -127, 0, 127));
+        assert_eq!(pixel, Pixel::with_rgba(-1.0, -1.0, 0.0, 1.0));
+    }
+}
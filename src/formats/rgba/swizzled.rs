@@ -0,0 +1,138 @@
+use core::{convert::Infallible, marker::PhantomData};
+
+use crate::{
+    alpha::AlphaFormat,
+    formats::rgba::{Rgba, RgbaFormat},
+    pixel::Format,
+};
+
+/// An RGBA pixel format that reorders the channels of another RGBA format.
+///
+/// Each const parameter selects which channel of `F` is used for the corresponding channel of this
+/// format, where `0` is `F`'s red, `1` its green, `2` its blue, and `3` its alpha channel. The raw
+/// pixel is the same as `F`'s, so existing pixel data can be reinterpreted without copying:
+///
+/// ```rust
+/// use pxlfmt::{
+///     formats::rgba::{Rgba8888, Swizzled},
+///     pixel::Pixel,
+/// };
+///
+/// type Bgra8888 = Swizzled<Rgba8888, 2, 1, 0, 3>;
+///
+/// let rgba = Pixel::<Rgba8888>::with_rgba(0x11, 0x22, 0x33, 0x44);
+/// let bgra = Pixel::<Bgra8888>::from_raw(rgba.into_raw());
+/// assert_eq!((bgra.red(), bgra.green(), bgra.blue()), (0x33, 0x22, 0x11));
+/// assert_eq!(bgra.alpha(), 0x44);
+/// ```
+///
+/// The parameters must be a permutation of `0..4`; anything else fails to compile when the format
+/// is used:
+///
+/// ```rust,compile_fail
+/// use pxlfmt::{
+///     formats::rgba::{Rgba8888, Swizzled},
+///     pixel::Pixel,
+/// };
+///
+/// let pixel = Pixel::<Swizzled<Rgba8888, 0, 0, 1, 2>>::with_rgba(1, 2, 3, 4);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Swizzled<F, const R: usize, const G: usize, const B: usize, const A: usize>(
+    Infallible,
+    PhantomData<F>,
+);
+
+impl<F: RgbaFormat, const R: usize, const G: usize, const B: usize, const A: usize>
+    Swizzled<F, R, G, B, A>
+{
+    /// The raw offsets of the red, green, blue, and alpha channels.
+    const OFFSETS: [usize; 4] = {
+        let source = [
+            F::RED_OFFSET,
+            F::GREEN_OFFSET,
+            F::BLUE_OFFSET,
+            F::ALPHA_OFFSET,
+        ];
+        let selected = [R, G, B, A];
+        let mut seen = [false; 4];
+        let mut i = 0;
+        while i < 4 {
+            assert!(selected[i] < 4, "Swizzle index out of bounds");
+            assert!(!seen[selected[i]], "Swizzle indices must be unique");
+            seen[selected[i]] = true;
+            i += 1;
+        }
+        [source[R], source[G], source[B], source[A]]
+    };
+}
+
+impl<F, const R: usize, const G: usize, const B: usize, const A: usize> crate::internal::Sealed
+    for Swizzled<F, R, G, B, A>
+{
+}
+
+impl<F: RgbaFormat, const R: usize, const G: usize, const B: usize, const A: usize> Format
+    for Swizzled<F, R, G, B, A>
+{
+    type RawPixel = F::RawPixel;
+    type Channels = Rgba;
+    const NAME: &'static str = "Swizzled";
+}
+
+impl<F: RgbaFormat, const R: usize, const G: usize, const B: usize, const A: usize> RgbaFormat
+    for Swizzled<F, R, G, B, A>
+{
+    const RED_OFFSET: usize = Self::OFFSETS[0];
+    const GREEN_OFFSET: usize = Self::OFFSETS[1];
+    const BLUE_OFFSET: usize = Self::OFFSETS[2];
+}
+
+impl<F: RgbaFormat, const R: usize, const G: usize, const B: usize, const A: usize> AlphaFormat
+    for Swizzled<F, R, G, B, A>
+{
+    const ALPHA_OFFSET: usize = Self::OFFSETS[3];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        formats::rgba::{Abgr8888, FloatRgba, Rgba8888},
+        pixel::{Pixel, raw::RawPixel},
+    };
+
+    use super::*;
+
+    #[test]
+    fn identity() {
+        let rgba = Pixel::<Rgba8888>::with_rgba(1, 2, 3, 4);
+        let same = Pixel::<Swizzled<Rgba8888, 0, 1, 2, 3>>::from_raw(rgba.into_raw());
+        assert_eq!(same.as_raw(), rgba.as_raw());
+        assert_eq!(
+            (same.red(), same.green(), same.blue(), same.alpha()),
+            (1, 2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn selects_channels_of_source_format() {
+        // Channel indices refer to the source format's channels, not raw offsets.
+        let mut pixel = Pixel::<Swizzled<Abgr8888, 3, 2, 1, 0>>::zeroed();
+        pixel
+            .set_red(0x11)
+            .set_green(0x22)
+            .set_blue(0x33)
+            .set_alpha(0x44);
+
+        let abgr = Pixel::<Abgr8888>::from_raw(pixel.into_raw());
+        assert_eq!((abgr.red(), abgr.green(), abgr.blue()), (0x44, 0x33, 0x22));
+        assert_eq!(abgr.alpha(), 0x11);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn float() {
+        let pixel = Pixel::<Swizzled<FloatRgba, 3, 0, 1, 2>>::with_rgba(0.1, 0.2, 0.3, 0.4);
+        assert_eq!(pixel.as_raw().into_inner(), [0.2, 0.3, 0.4, 0.1]);
+    }
+}
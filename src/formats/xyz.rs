@@ -0,0 +1,86 @@
+//! CIE XYZ pixel formats.
+//!
+//! XYZ is device-independent, and is used as the hub when converting between color spaces: for
+//! example, [`LabF32`] is defined relative to XYZ, and both can be reached from linear sRGB
+//! [`FloatRgba`] pixels using [`From`]:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::{lab::LabF32, rgba::FloatRgba, xyz::XyzaF32},
+//!     pixel::Pixel,
+//! };
+//!
+//! let white = Pixel::<XyzaF32>::from(Pixel::<FloatRgba>::with_rgba(1.0, 1.0, 1.0, 1.0));
+//! assert!((white.y() - 1.0).abs() < 1e-6);
+//!
+//! let lab = Pixel::<LabF32>::from(white);
+//! assert!((lab.l() - 100.0).abs() < 1e-3);
+//! ```
+//!
+//! [`LabF32`]: crate::formats::lab::LabF32
+//! [`FloatRgba`]: crate::formats::rgba::FloatRgba
+
+mod xyza_f32;
+pub use xyza_f32::XyzaF32;
+
+/// Channels representing `X`, `Y` (luminance), `Z`, and `Alpha` components of a pixel.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Xyza {
+    X,
+    Y,
+    Z,
+    Alpha,
+}
+
+/// Row-major matrix converting linear sRGB to CIE XYZ, with a D65 white point.
+pub const SRGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.412_456_4, 0.357_576_1, 0.180_437_5],
+    [0.212_672_9, 0.715_152_2, 0.072_175],
+    [0.019_333_9, 0.119_192, 0.950_304_1],
+];
+
+/// Row-major matrix converting CIE XYZ, with a D65 white point, to linear sRGB.
+///
+/// This is the inverse of [`SRGB_TO_XYZ`].
+pub const XYZ_TO_SRGB: [[f32; 3]; 3] = [
+    [3.240_454_2, -1.537_138_5, -0.498_531_4],
+    [-0.969_266, 1.876_010_8, 0.041_556],
+    [0.055_643_4, -0.204_025_9, 1.057_225_2],
+];
+
+/// The D65 reference white in CIE XYZ, normalized to a luminance of `1.0`.
+pub const D65_WHITE: [f32; 3] = [0.950_47, 1.0, 1.088_83];
+
+/// Multiplies a 3x3 row-major matrix by a column vector.
+pub(crate) fn mul_mat3(matrix: &[[f32; 3]; 3], vector: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn mul_mat3_identity() {
+        let identity = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        assert_eq!(mul_mat3(&identity, [1.0, 2.0, 3.0]), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn srgb_white_is_d65() {
+        let white = mul_mat3(&SRGB_TO_XYZ, [1.0, 1.0, 1.0]);
+        for (actual, expected) in white.iter().zip(D65_WHITE) {
+            assert!((actual - expected).abs() < 1e-6, "{white:?}");
+        }
+    }
+
+    #[test]
+    fn srgb_matrices_are_inverses() {
+        let rgb = mul_mat3(&XYZ_TO_SRGB, mul_mat3(&SRGB_TO_XYZ, [0.25, 0.5, 0.75]));
+        for (actual, expected) in rgb.iter().zip([0.25, 0.5, 0.75]) {
+            assert!((actual - expected).abs() < 1e-6, "{rgb:?}");
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    alpha::AlphaFormat,
+    formats::{
+        rgba::FloatRgba,
+        xyz::{SRGB_TO_XYZ, XYZ_TO_SRGB, Xyza, mul_mat3},
+    },
+    pixel::{
+        ChannelOf, Format, Pixel,
+        raw::{F32x4, RawPixel},
+    },
+};
+
+/// A 128-bit CIE XYZ pixel format with four 32-bit floating point channels.
+///
+/// This format is used to represent pixels in the XYZA order:
+/// - `X` (32 bits)
+/// - `Y` (32 bits, relative luminance)
+/// - `Z` (32 bits)
+/// - `A`lpha (32 bits)
+///
+/// The pixel is represented as a 128-bit value, where each channel occupies 32 bits.
+///
+/// Conversions to and from [`FloatRgba`] assume linear sRGB primaries and a D65 white point.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum XyzaF32 {}
+
+impl crate::internal::Sealed for XyzaF32 {}
+
+impl Format for XyzaF32 {
+    type RawPixel = F32x4;
+    type Channels = Xyza;
+    const NAME: &'static str = "XyzaF32";
+}
+
+impl ChannelOf<XyzaF32> for Xyza {
+    const ALL: &'static [Self] = &[Self::X, Self::Y, Self::Z, Self::Alpha];
+
+    fn offset(self) -> usize {
+        self as usize
+    }
+}
+
+impl AlphaFormat for XyzaF32 {
+    const ALPHA_OFFSET: usize = 3;
+}
+
+impl Pixel<XyzaF32> {
+    /// Returns the `X` channel value of the pixel.
+    #[must_use]
+    pub fn x(&self) -> f32 {
+        self.as_raw().get_channel(0)
+    }
+
+    /// Sets the `X` channel value of the pixel.
+    pub fn set_x(&mut self, value: f32) -> &mut Self {
+        self.as_raw_mut().set_channel(0, value);
+        self
+    }
+
+    /// Returns the `Y` (relative luminance) channel value of the pixel.
+    #[must_use]
+    pub fn y(&self) -> f32 {
+        self.as_raw().get_channel(1)
+    }
+
+    /// Sets the `Y` (relative luminance) channel value of the pixel.
+    pub fn set_y(&mut self, value: f32) -> &mut Self {
+        self.as_raw_mut().set_channel(1, value);
+        self
+    }
+
+    /// Returns the `Z` channel value of the pixel.
+    #[must_use]
+    pub fn z(&self) -> f32 {
+        self.as_raw().get_channel(2)
+    }
+
+    /// Sets the `Z` channel value of the pixel.
+    pub fn set_z(&mut self, value: f32) -> &mut Self {
+        self.as_raw_mut().set_channel(2, value);
+        self
+    }
+
+    /// Creates a new pixel from XYZ and alpha channel values.
+    #[must_use]
+    pub fn with_xyza(x: f32, y: f32, z: f32, alpha: f32) -> Self {
+        Self::from_raw([x, y, z, alpha].into())
+    }
+}
+
+impl From<Pixel<FloatRgba>> for Pixel<XyzaF32> {
+    fn from(rgba: Pixel<FloatRgba>) -> Self {
+        let [x, y, z] = mul_mat3(&SRGB_TO_XYZ, [rgba.red(), rgba.green(), rgba.blue()]);
+        Pixel::with_xyza(x, y, z, rgba.alpha())
+    }
+}
+
+impl From<Pixel<XyzaF32>> for Pixel<FloatRgba> {
+    fn from(xyza: Pixel<XyzaF32>) -> Self {
+        let [r, g, b] = mul_mat3(&XYZ_TO_SRGB, [xyza.x(), xyza.y(), xyza.z()]);
+        Pixel::with_rgba(r, g, b, xyza.alpha())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn with_xyza() {
+        let mut pixel = Pixel::<XyzaF32>::with_xyza(0.25, 0.5, 0.75, 1.0);
+        assert_eq!(pixel.as_raw().into_inner(), [0.25, 0.5, 0.75, 1.0]);
+        assert_eq!((pixel.x(), pixel.y(), pixel.z()), (0.25, 0.5, 0.75));
+        assert_eq!(pixel.alpha(), 1.0);
+
+        pixel.set_x(1.0).set_y(2.0).set_z(3.0).set_alpha(0.0);
+        assert_eq!(pixel.as_raw().into_inner(), [1.0, 2.0, 3.0, 0.0]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn from_rgba() {
+        let red = Pixel::<XyzaF32>::from(Pixel::<FloatRgba>::with_rgba(1.0, 0.0, 0.0, 0.5));
+        assert_eq!(
+            red,
+            Pixel::with_xyza(0.412_456_4, 0.212_672_9, 0.019_333_9, 0.5)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for rgba in [
+            [0.2, 0.4, 0.6, 1.0],
+            [0.9, 0.1, 0.3, 0.5],
+            [0.0, 0.0, 0.0, 0.0],
+        ] {
+            let pixel = Pixel::<FloatRgba>::from_raw(rgba.into());
+            let back = Pixel::<FloatRgba>::from(Pixel::<XyzaF32>::from(pixel));
+            for (a, e) in back.as_raw().into_inner().iter().zip(rgba) {
+                assert!((a - e).abs() < 1e-6, "{back:?} != {rgba:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! `YCoCg` pixel formats.
+//!
+//! `YCoCg` separates a pixel into luma (`Y`), orange chrominance (`Co`) and green chrominance
+//! (`Cg`) using only additions and shifts, which decorrelates the channels cheaply and is commonly
+//! used as a preprocessing step for texture and image compression.
+//!
+//! The [`YcocgR`] format uses the reversible `YCoCg-R` lifting transform, so converting an 8-bit
+//! RGBA pixel to it and back is exact:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::{rgba::Rgba8888, ycocg::YcocgR},
+//!     pixel::Pixel,
+//! };
+//!
+//! let rgba = Pixel::<Rgba8888>::with_rgba(0xFF, 0x80, 0x00, 0xFF);
+//! let ycocg = Pixel::<YcocgR>::from(rgba);
+//! assert_eq!((ycocg.y(), ycocg.co(), ycocg.cg()), (0x7F, 0xFF, 0x01));
+//!
+//! let back = Pixel::<Rgba8888>::from(ycocg);
+//! assert_eq!(back, rgba);
+//! ```
+
+mod ycocg_r;
+pub use ycocg_r::YcocgR;
+
+/// Channels representing `Y` (luma), `Co` (orange chroma), `Cg` (green chroma), and `Alpha`
+/// components of a pixel.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Ycocga {
+    Y,
+    Co,
+    Cg,
+    Alpha,
+}
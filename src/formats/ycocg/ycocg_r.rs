@@ -0,0 +1,177 @@
+use crate::{
+    alpha::AlphaFormat,
+    formats::{rgba::RgbaFormat, ycocg::Ycocga},
+    pixel::{
+        ChannelOf, Format, Pixel,
+        raw::{I16x4, RawPixel},
+    },
+};
+
+/// A 64-bit `YCoCg-R` pixel format with four 16-bit signed integer channels.
+///
+/// This format is used to represent pixels in the `YCoCg` order:
+/// - `Y` (luma, `0..=255`)
+/// - `Co` (orange chroma, `-255..=255`)
+/// - `Cg` (green chroma, `-255..=255`)
+/// - `A`lpha (`0..=255`)
+///
+/// The chroma channels need 9 bits to be lossless, so each channel is stored as an `i16`. Pixels
+/// can be converted to and from any 8-bit RGBA format using [`From`], and the round trip is exact.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum YcocgR {}
+
+impl crate::internal::Sealed for YcocgR {}
+
+impl Format for YcocgR {
+    type RawPixel = I16x4;
+    type Channels = Ycocga;
+    const NAME: &'static str = "YcocgR";
+}
+
+impl ChannelOf<YcocgR> for Ycocga {
+    const ALL: &'static [Self] = &[Self::Y, Self::Co, Self::Cg, Self::Alpha];
+
+    fn offset(self) -> usize {
+        self as usize
+    }
+}
+
+impl AlphaFormat for YcocgR {
+    const ALPHA_OFFSET: usize = 3;
+}
+
+impl Pixel<YcocgR> {
+    /// Returns the luma channel value of the pixel.
+    #[must_use]
+    pub fn y(&self) -> i16 {
+        self.as_raw().get_channel(0)
+    }
+
+    /// Sets the luma channel value of the pixel.
+    pub fn set_y(&mut self, value: i16) -> &mut Self {
+        self.as_raw_mut().set_channel(0, value);
+        self
+    }
+
+    /// Returns the orange chroma channel value of the pixel.
+    #[must_use]
+    pub fn co(&self) -> i16 {
+        self.as_raw().get_channel(1)
+    }
+
+    /// Sets the orange chroma channel value of the pixel.
+    pub fn set_co(&mut self, value: i16) -> &mut Self {
+        self.as_raw_mut().set_channel(1, value);
+        self
+    }
+
+    /// Returns the green chroma channel value of the pixel.
+    #[must_use]
+    pub fn cg(&self) -> i16 {
+        self.as_raw().get_channel(2)
+    }
+
+    /// Sets the green chroma channel value of the pixel.
+    pub fn set_cg(&mut self, value: i16) -> &mut Self {
+        self.as_raw_mut().set_channel(2, value);
+        self
+    }
+
+    /// Creates a new pixel from `YCoCg` and alpha channel values.
+    #[must_use]
+    pub fn with_ycocga(y: i16, co: i16, cg: i16, a: i16) -> Self {
+        Self::new(I16x4::from_channels(y, co, cg, a))
+    }
+}
+
+impl<F> From<Pixel<F>> for Pixel<YcocgR>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = u8>,
+{
+    fn from(rgba: Pixel<F>) -> Self {
+        let r = i16::from(rgba.red());
+        let g = i16::from(rgba.green());
+        let b = i16::from(rgba.blue());
+
+        let co = r - b;
+        let base = b + (co >> 1);
+        let cg = g - base;
+        let y = base + (cg >> 1);
+        Self::with_ycocga(y, co, cg, i16::from(rgba.alpha()))
+    }
+}
+
+impl<F> From<Pixel<YcocgR>> for Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = u8>,
+{
+    fn from(ycocg: Pixel<YcocgR>) -> Self {
+        let base = ycocg.y() - (ycocg.cg() >> 1);
+        let g = ycocg.cg() + base;
+        let b = base - (ycocg.co() >> 1);
+        let r = b + ycocg.co();
+        Pixel::with_rgba(
+            clamp_u8(r),
+            clamp_u8(g),
+            clamp_u8(b),
+            clamp_u8(ycocg.alpha()),
+        )
+    }
+}
+
+/// Clamps a value to the `0..=255` range.
+///
+/// Only pixels that did not originate from an 8-bit RGBA pixel can be out of range.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn clamp_u8(value: i16) -> u8 {
+    value.clamp(0, 0xFF) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{Abgr8888, Rgba8888};
+
+    use super::*;
+
+    #[test]
+    fn with_ycocga() {
+        let mut pixel = Pixel::<YcocgR>::with_ycocga(0x80, -255, 255, 0xFF);
+        assert_eq!(pixel.as_raw().into_inner(), [0x80, -255, 255, 0xFF]);
+        assert_eq!((pixel.y(), pixel.co(), pixel.cg()), (0x80, -255, 255));
+
+        pixel.set_y(1).set_co(2).set_cg(3).set_alpha(4);
+        assert_eq!(pixel.as_raw().into_inner(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn from_rgba() {
+        let gray = Pixel::<YcocgR>::from(Pixel::<Rgba8888>::with_rgba(0x40, 0x40, 0x40, 0x80));
+        assert_eq!(gray.as_raw().into_inner(), [0x40, 0, 0, 0x80]);
+
+        let blue = Pixel::<YcocgR>::from(Pixel::<Abgr8888>::with_rgba(0, 0, 0xFF, 0xFF));
+        assert_eq!(blue.as_raw().into_inner(), [0x3F, -255, -127, 0xFF]);
+    }
+
+    #[test]
+    fn round_trip_is_lossless() {
+        for r in (0..=0xFF).step_by(5) {
+            for g in (0..=0xFF).step_by(3) {
+                for b in [0x00, 0x01, 0x7F, 0x80, 0xFE, 0xFF] {
+                    let rgba = Pixel::<Rgba8888>::with_rgba(r, g, b, 0xFF);
+                    let back = Pixel::<Rgba8888>::from(Pixel::<YcocgR>::from(rgba));
+                    assert_eq!(back, rgba);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn to_rgba_clamps() {
+        let pixel = Pixel::<YcocgR>::with_ycocga(0xFF, 0xFF, 0xFF, 0x1FF);
+        let rgba = Pixel::<Rgba8888>::from(pixel);
+        assert_eq!(rgba.green(), 0xFF);
+        assert_eq!(rgba.alpha(), 0xFF);
+    }
+}
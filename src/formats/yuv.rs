@@ -0,0 +1,486 @@
+//! YUV (luma and chroma) pixel formats.
+//!
+//! ## Packed 4:2:2
+//!
+//! Packed 4:2:2 formats, such as [`Yuyv422`] and [`Uyvy422`], store _macropixels_: two horizontally
+//! adjacent pixels share a single pair of chroma (`U` and `V`) samples, while each pixel keeps its
+//! own luma (`Y0` and `Y1`) sample. A single [`Pixel`] of these formats represents one macropixel,
+//! which covers two pixels of the image.
+//!
+//! ```rust
+//! use pxlfmt::{formats::yuv::Yuyv422, pixel::Pixel};
+//!
+//! let pixel = Pixel::<Yuyv422>::with_yuv422(0x10, 0x80, 0xEB, 0x80);
+//! assert_eq!(pixel.y0(), 0x10);
+//! assert_eq!(pixel.y1(), 0xEB);
+//! assert_eq!(pixel.u(), 0x80);
+//! assert_eq!(pixel.v(), 0x80);
+//! ```
+//!
+//! ## Packed 4:4:4
+//!
+//! Packed 4:4:4 formats, such as [`Ycbcr444`], store a full set of luma and chroma samples for every
+//! pixel, and can be converted to and from RGBA formats using a [`YcbcrMatrix`]:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::{rgba::Rgba8888, yuv::{Ycbcr444, YcbcrMatrix}},
+//!     pixel::Pixel,
+//! };
+//!
+//! let red = Pixel::<Rgba8888>::with_rgba(0xFF, 0x00, 0x00, 0xFF);
+//! let ycbcr = Pixel::<Ycbcr444>::from_rgba(red, YcbcrMatrix::Bt601);
+//! assert_eq!((ycbcr.y(), ycbcr.cb(), ycbcr.cr()), (76, 85, 255));
+//!
+//! let rgba = ycbcr.to_rgba::<Rgba8888>(YcbcrMatrix::Bt601);
+//! assert_eq!((rgba.red(), rgba.green(), rgba.blue()), (0xFE, 0x00, 0x00));
+//! ```
+//!
+//! ## High bit depth
+//!
+//! [`P010`] and [`P016`] describe the 16-bit samples of HDR video decode surfaces. P010 keeps 10
+//! significant bits in the high bits of each sample, and its channels are accessed already shifted
+//! down:
+//!
+//! ```rust
+//! use pxlfmt::{formats::yuv::P010, pixel::{Pixel, raw::RawPixel}};
+//!
+//! let pixel = Pixel::<P010>::with_ycbcr(0x3FF, 0x200, 0x200);
+//! assert_eq!(pixel.y(), 0x3FF);
+//! assert_eq!(pixel.as_raw().into_inner(), [0xFFC0, 0x8000, 0x8000]);
+//! ```
+
+pub mod planar;
+
+mod p010;
+pub use p010::P010;
+
+mod p016;
+pub use p016::P016;
+
+mod uyvy422;
+pub use uyvy422::Uyvy422;
+
+mod ycbcr444;
+pub use ycbcr444::Ycbcr444;
+
+mod yuyv422;
+pub use yuyv422::Yuyv422;
+
+use crate::{
+    formats::rgba::RgbaFormat,
+    pixel::{ChannelOf, Format, Pixel, raw::RawPixel},
+};
+
+/// Channels of a packed 4:2:2 macropixel.
+///
+/// `Y0` and `Y1` are the luma samples of the first and second pixel, respectively, and `U` and `V`
+/// are the chroma samples shared by both pixels.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Yuv422 {
+    Y0,
+    U,
+    Y1,
+    V,
+}
+
+/// A packed 4:2:2 pixel format, where each pixel is a macropixel of two luma and two chroma samples.
+///
+/// The offsets of individual channels in the pixel's raw representation are defined by this trait:
+/// - `Y0_OFFSET`: Offset of the luma sample of the first pixel.
+/// - `U_OFFSET`: Offset of the shared blue-difference chroma sample.
+/// - `Y1_OFFSET`: Offset of the luma sample of the second pixel.
+/// - `V_OFFSET`: Offset of the shared red-difference chroma sample.
+pub trait Yuv422Format: Format<Channels = Yuv422> {
+    /// The offset of the first luma sample in the pixel's raw representation.
+    const Y0_OFFSET: usize;
+
+    /// The offset of the blue-difference chroma sample in the pixel's raw representation.
+    const U_OFFSET: usize;
+
+    /// The offset of the second luma sample in the pixel's raw representation.
+    const Y1_OFFSET: usize;
+
+    /// The offset of the red-difference chroma sample in the pixel's raw representation.
+    const V_OFFSET: usize;
+
+    /// Returns the first luma sample of a pixel.
+    fn get_y0(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::Y0_OFFSET)
+    }
+
+    /// Sets the first luma sample of a pixel.
+    fn set_y0(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::Y0_OFFSET, value);
+    }
+
+    /// Returns the blue-difference chroma sample of a pixel.
+    fn get_u(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::U_OFFSET)
+    }
+
+    /// Sets the blue-difference chroma sample of a pixel.
+    fn set_u(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::U_OFFSET, value);
+    }
+
+    /// Returns the second luma sample of a pixel.
+    fn get_y1(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::Y1_OFFSET)
+    }
+
+    /// Sets the second luma sample of a pixel.
+    fn set_y1(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::Y1_OFFSET, value);
+    }
+
+    /// Returns the red-difference chroma sample of a pixel.
+    fn get_v(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::V_OFFSET)
+    }
+
+    /// Sets the red-difference chroma sample of a pixel.
+    fn set_v(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::V_OFFSET, value);
+    }
+}
+
+impl<F: Yuv422Format> ChannelOf<F> for Yuv422 {
+    const ALL: &'static [Self] = &[Self::Y0, Self::U, Self::Y1, Self::V];
+
+    fn offset(self) -> usize {
+        match self {
+            Self::Y0 => F::Y0_OFFSET,
+            Self::U => F::U_OFFSET,
+            Self::Y1 => F::Y1_OFFSET,
+            Self::V => F::V_OFFSET,
+        }
+    }
+}
+
+impl<F: Yuv422Format> Pixel<F> {
+    /// Returns the luma sample of the first pixel in the macropixel.
+    pub fn y0(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_y0(self.as_raw())
+    }
+
+    /// Sets the luma sample of the first pixel in the macropixel.
+    pub fn set_y0(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_y0(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Returns the shared blue-difference chroma sample of the macropixel.
+    pub fn u(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_u(self.as_raw())
+    }
+
+    /// Sets the shared blue-difference chroma sample of the macropixel.
+    pub fn set_u(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_u(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Returns the luma sample of the second pixel in the macropixel.
+    pub fn y1(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_y1(self.as_raw())
+    }
+
+    /// Sets the luma sample of the second pixel in the macropixel.
+    pub fn set_y1(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_y1(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Returns the shared red-difference chroma sample of the macropixel.
+    pub fn v(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_v(self.as_raw())
+    }
+
+    /// Sets the shared red-difference chroma sample of the macropixel.
+    pub fn set_v(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_v(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Creates a new macropixel from its two luma and two chroma samples.
+    ///
+    /// The arguments are always in `Y0, U, Y1, V` order, regardless of the format's memory layout.
+    pub fn with_yuv422(
+        y0: <F::RawPixel as RawPixel>::Channel,
+        u: <F::RawPixel as RawPixel>::Channel,
+        y1: <F::RawPixel as RawPixel>::Channel,
+        v: <F::RawPixel as RawPixel>::Channel,
+    ) -> Self {
+        let mut pixel = Self::from_raw(F::RawPixel::DEFAULT);
+        pixel.set_y0(y0).set_u(u).set_y1(y1).set_v(v);
+        pixel
+    }
+}
+
+/// Channels representing the luma (`Y`), blue-difference (`Cb`), and red-difference (`Cr`) components.
+///
+/// Used in pixel formats that store a full set of samples for every pixel (4:4:4).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[repr(u8)]
+pub enum Ycbcr {
+    Y,
+    Cb,
+    Cr,
+}
+
+/// A pixel format with luma, blue-difference, and red-difference channels.
+///
+/// The offsets of individual channels in the pixel's raw representation are defined by this trait:
+/// - `Y_OFFSET`: Offset of the luma channel.
+/// - `CB_OFFSET`: Offset of the blue-difference chroma channel.
+/// - `CR_OFFSET`: Offset of the red-difference chroma channel.
+pub trait YcbcrFormat: Format<Channels = Ycbcr> {
+    /// The offset of the luma channel in the pixel's raw representation.
+    const Y_OFFSET: usize;
+
+    /// The offset of the blue-difference chroma channel in the pixel's raw representation.
+    const CB_OFFSET: usize;
+
+    /// The offset of the red-difference chroma channel in the pixel's raw representation.
+    const CR_OFFSET: usize;
+
+    /// Returns the luma channel value of a pixel.
+    fn get_y(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::Y_OFFSET)
+    }
+
+    /// Sets the luma channel value of a pixel.
+    fn set_y(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::Y_OFFSET, value);
+    }
+
+    /// Returns the blue-difference chroma channel value of a pixel.
+    fn get_cb(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::CB_OFFSET)
+    }
+
+    /// Sets the blue-difference chroma channel value of a pixel.
+    fn set_cb(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::CB_OFFSET, value);
+    }
+
+    /// Returns the red-difference chroma channel value of a pixel.
+    fn get_cr(pixel: &Self::RawPixel) -> <Self::RawPixel as RawPixel>::Channel {
+        pixel.get_channel(Self::CR_OFFSET)
+    }
+
+    /// Sets the red-difference chroma channel value of a pixel.
+    fn set_cr(pixel: &mut Self::RawPixel, value: <Self::RawPixel as RawPixel>::Channel) {
+        pixel.set_channel(Self::CR_OFFSET, value);
+    }
+}
+
+impl<F: YcbcrFormat> ChannelOf<F> for Ycbcr {
+    const ALL: &'static [Self] = &[Self::Y, Self::Cb, Self::Cr];
+
+    fn offset(self) -> usize {
+        match self {
+            Self::Y => F::Y_OFFSET,
+            Self::Cb => F::CB_OFFSET,
+            Self::Cr => F::CR_OFFSET,
+        }
+    }
+}
+
+impl<F: YcbcrFormat> Pixel<F> {
+    /// Returns the luma channel value of the pixel.
+    pub fn y(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_y(self.as_raw())
+    }
+
+    /// Sets the luma channel value of the pixel.
+    pub fn set_y(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_y(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Returns the blue-difference chroma channel value of the pixel.
+    pub fn cb(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_cb(self.as_raw())
+    }
+
+    /// Sets the blue-difference chroma channel value of the pixel.
+    pub fn set_cb(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_cb(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Returns the red-difference chroma channel value of the pixel.
+    pub fn cr(&self) -> <F::RawPixel as RawPixel>::Channel {
+        F::get_cr(self.as_raw())
+    }
+
+    /// Sets the red-difference chroma channel value of the pixel.
+    pub fn set_cr(&mut self, value: <F::RawPixel as RawPixel>::Channel) -> &mut Self {
+        F::set_cr(self.as_raw_mut(), value);
+        self
+    }
+
+    /// Creates a new pixel from luma and chroma channel values.
+    pub fn with_ycbcr(
+        y: <F::RawPixel as RawPixel>::Channel,
+        cb: <F::RawPixel as RawPixel>::Channel,
+        cr: <F::RawPixel as RawPixel>::Channel,
+    ) -> Self {
+        let mut pixel = Self::from_raw(F::RawPixel::DEFAULT);
+        pixel.set_y(y).set_cb(cb).set_cr(cr);
+        pixel
+    }
+}
+
+/// The matrix coefficients used to convert between RGB and `YCbCr`.
+///
+/// Conversions use the full `0..=255` range for all channels, with chroma centered on `128`, which
+/// is what JPEG (JFIF) uses.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum YcbcrMatrix {
+    /// ITU-R BT.601, used by JPEG and standard-definition video.
+    #[default]
+    Bt601,
+
+    /// ITU-R BT.709, used by high-definition video.
+    Bt709,
+}
+
+impl YcbcrMatrix {
+    /// Returns the `(Kr, Kb)` luma weights of the red and blue channels.
+    pub(crate) const fn weights(self) -> (f32, f32) {
+        match self {
+            Self::Bt601 => (0.299, 0.114),
+            Self::Bt709 => (0.2126, 0.0722),
+        }
+    }
+}
+
+/// Rounds and clamps a value to the `0..=255` range.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn round_u8(value: f32) -> u8 {
+    (value.clamp(0.0, 255.0) + 0.5) as u8
+}
+
+impl<F> Pixel<F>
+where
+    F: YcbcrFormat,
+    F::RawPixel: RawPixel<Channel = u8>,
+{
+    /// Converts an 8-bit RGBA pixel to `YCbCr` using the given matrix.
+    ///
+    /// The alpha channel is discarded.
+    pub fn from_rgba<T>(rgba: Pixel<T>, matrix: YcbcrMatrix) -> Self
+    where
+        T: RgbaFormat,
+        T::RawPixel: RawPixel<Channel = u8>,
+    {
+        let (kr, kb) = matrix.weights();
+        let kg = 1.0 - kr - kb;
+        let r = f32::from(rgba.red());
+        let g = f32::from(rgba.green());
+        let b = f32::from(rgba.blue());
+
+        let y = kr * r + kg * g + kb * b;
+        let cb = (b - y) / (2.0 * (1.0 - kb)) + 128.0;
+        let cr = (r - y) / (2.0 * (1.0 - kr)) + 128.0;
+        Self::with_ycbcr(round_u8(y), round_u8(cb), round_u8(cr))
+    }
+
+    /// Converts the pixel to an opaque 8-bit RGBA pixel using the given matrix.
+    ///
+    /// The matrix should be the same one that was used to encode the pixel.
+    #[must_use]
+    pub fn to_rgba<T>(&self, matrix: YcbcrMatrix) -> Pixel<T>
+    where
+        T: RgbaFormat,
+        T::RawPixel: RawPixel<Channel = u8>,
+    {
+        let (kr, kb) = matrix.weights();
+        let kg = 1.0 - kr - kb;
+        let y = f32::from(self.y());
+        let cb = f32::from(self.cb()) - 128.0;
+        let cr = f32::from(self.cr()) - 128.0;
+
+        let r = y + 2.0 * (1.0 - kr) * cr;
+        let b = y + 2.0 * (1.0 - kb) * cb;
+        let g = (y - kr * r - kb * b) / kg;
+        Pixel::with_rgba(round_u8(r), round_u8(g), round_u8(b), 0xFF)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_yuv422() {
+        let pixel = Pixel::<Uyvy422>::with_yuv422(0x10, 0x20, 0x30, 0x40);
+        assert_eq!(pixel.y0(), 0x10);
+        assert_eq!(pixel.u(), 0x20);
+        assert_eq!(pixel.y1(), 0x30);
+        assert_eq!(pixel.v(), 0x40);
+    }
+
+    #[test]
+    fn with_ycbcr() {
+        let pixel = Pixel::<Ycbcr444>::with_ycbcr(0x10, 0x20, 0x30);
+        assert_eq!(pixel.y(), 0x10);
+        assert_eq!(pixel.cb(), 0x20);
+        assert_eq!(pixel.cr(), 0x30);
+    }
+
+    #[test]
+    fn from_rgba_bt601() {
+        use crate::formats::rgba::Rgba8888;
+
+        let white = Pixel::<Rgba8888>::with_rgba(0xFF, 0xFF, 0xFF, 0xFF);
+        let pixel = Pixel::<Ycbcr444>::from_rgba(white, YcbcrMatrix::Bt601);
+        assert_eq!((pixel.y(), pixel.cb(), pixel.cr()), (255, 128, 128));
+
+        let red = Pixel::<Rgba8888>::with_rgba(0xFF, 0x00, 0x00, 0xFF);
+        let pixel = Pixel::<Ycbcr444>::from_rgba(red, YcbcrMatrix::Bt601);
+        assert_eq!((pixel.y(), pixel.cb(), pixel.cr()), (76, 85, 255));
+
+        let blue = Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0xFF, 0xFF);
+        let pixel = Pixel::<Ycbcr444>::from_rgba(blue, YcbcrMatrix::Bt601);
+        assert_eq!((pixel.y(), pixel.cb(), pixel.cr()), (29, 255, 107));
+    }
+
+    #[test]
+    fn from_rgba_bt709() {
+        use crate::formats::rgba::Rgba8888;
+
+        let green = Pixel::<Rgba8888>::with_rgba(0x00, 0xFF, 0x00, 0xFF);
+        let pixel = Pixel::<Ycbcr444>::from_rgba(green, YcbcrMatrix::Bt709);
+        assert_eq!((pixel.y(), pixel.cb(), pixel.cr()), (182, 30, 12));
+    }
+
+    #[test]
+    fn round_trip() {
+        use crate::formats::rgba::Rgba8888;
+
+        for matrix in [YcbcrMatrix::Bt601, YcbcrMatrix::Bt709] {
+            for (r, g, b) in [
+                (0, 0, 0),
+                (255, 255, 255),
+                (255, 0, 0),
+                (12, 200, 99),
+                (1, 2, 3),
+            ] {
+                let rgba = Pixel::<Rgba8888>::with_rgba(r, g, b, 0xFF);
+                let back = Pixel::<Ycbcr444>::from_rgba(rgba, matrix).to_rgba::<Rgba8888>(matrix);
+                assert!(back.red().abs_diff(r) <= 1, "{matrix:?} {rgba:?} {back:?}");
+                assert!(
+                    back.green().abs_diff(g) <= 1,
+                    "{matrix:?} {rgba:?} {back:?}"
+                );
+                assert!(back.blue().abs_diff(b) <= 1, "{matrix:?} {rgba:?} {back:?}");
+                assert_eq!(back.alpha(), 0xFF);
+            }
+        }
+    }
+}
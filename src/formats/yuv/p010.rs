@@ -0,0 +1,50 @@
+use crate::{
+    formats::yuv::{Ycbcr, YcbcrFormat},
+    pixel::{Format, raw::U16x3Msb10},
+};
+
+/// A 48-bit `YCbCr` pixel format with three 10-bit channels, as stored in P010 surfaces.
+///
+/// This format is used to represent pixels in the `YCbCr` order:
+/// - `Y`, luma (10 bits in the high bits of 16)
+/// - `Cb`, blue-difference chroma (10 bits in the high bits of 16)
+/// - `Cr`, red-difference chroma (10 bits in the high bits of 16)
+///
+/// P010 is the usual output of 10-bit (HDR) hardware video decoders: a 4:2:0 image with a plane of
+/// 16-bit luma samples and a plane of interleaved 16-bit chroma samples, where the low 6 bits of
+/// every sample are zero. A [`Pixel`](crate::pixel::Pixel) of this format holds one set of
+/// samples read from those planes; channels are accessed as `0..=1023`, already shifted down.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum P010 {}
+
+impl crate::internal::Sealed for P010 {}
+
+impl Format for P010 {
+    type RawPixel = U16x3Msb10;
+    type Channels = Ycbcr;
+    const NAME: &'static str = "P010";
+}
+
+impl YcbcrFormat for P010 {
+    const Y_OFFSET: usize = 0;
+    const CB_OFFSET: usize = 1;
+    const CR_OFFSET: usize = 2;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::{Pixel, raw::RawPixel};
+
+    use super::*;
+
+    #[test]
+    fn from_raw() {
+        let mut pixel: Pixel<P010> = Pixel::new(U16x3Msb10::from([0xFFC0, 0x8000, 0x0040]));
+        assert_eq!(pixel.y(), 0x3FF);
+        assert_eq!(pixel.cb(), 0x200);
+        assert_eq!(pixel.cr(), 0x001);
+
+        pixel.set_y(0x040).set_cb(0x200).set_cr(0x3C0);
+        assert_eq!(pixel.as_raw().into_inner(), [0x1000, 0x8000, 0xF000]);
+    }
+}
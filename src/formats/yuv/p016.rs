@@ -0,0 +1,49 @@
+use crate::{
+    formats::yuv::{Ycbcr, YcbcrFormat},
+    pixel::{Format, raw::U16x3},
+};
+
+/// A 48-bit `YCbCr` pixel format with three 16-bit channels, as stored in P016 surfaces.
+///
+/// This format is used to represent pixels in the `YCbCr` order:
+/// - `Y`, luma (16 bits)
+/// - `Cb`, blue-difference chroma (16 bits)
+/// - `Cr`, red-difference chroma (16 bits)
+///
+/// P016 has the same 4:2:0 plane layout as [`P010`](super::P010), but every bit of each sample
+/// is significant. A [`Pixel`](crate::pixel::Pixel) of this format holds one set of samples read
+/// from those planes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum P016 {}
+
+impl crate::internal::Sealed for P016 {}
+
+impl Format for P016 {
+    type RawPixel = U16x3;
+    type Channels = Ycbcr;
+    const NAME: &'static str = "P016";
+}
+
+impl YcbcrFormat for P016 {
+    const Y_OFFSET: usize = 0;
+    const CB_OFFSET: usize = 1;
+    const CR_OFFSET: usize = 2;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::{Pixel, raw::RawPixel};
+
+    use super::*;
+
+    #[test]
+    fn from_raw() {
+        let mut pixel: Pixel<P016> = Pixel::new(U16x3::from([0x1000, 0x8000, 0xFFFF]));
+        assert_eq!(pixel.y(), 0x1000);
+        assert_eq!(pixel.cb(), 0x8000);
+        assert_eq!(pixel.cr(), 0xFFFF);
+
+        pixel.set_y(0x0001).set_cr(0x7FFF);
+        assert_eq!(pixel.as_raw().into_inner(), [0x0001, 0x8000, 0x7FFF]);
+    }
+}
@@ -0,0 +1,401 @@
+//! Planar `YCbCr` formats, where each channel (or group of channels) is stored in its own plane.
+//!
+//! Unlike the other formats in this crate, a planar format does not describe a single [`Pixel`],
+//! but rather how the samples of an entire image are spread across multiple buffers. Use a
+//! [`PlanarView`] to borrow those buffers and read individual pixels from them, as pixels of the
+//! format's packed [`PlanarFormat::Pixel`] (such as [`Ycbcr444`] for 8-bit planes).
+//!
+//! # Example
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::{
+//!         rgba::Rgba8888,
+//!         yuv::{YcbcrMatrix, planar::{Nv12, PlanarView}},
+//!     },
+//! };
+//!
+//! // A 2x2 image: four luma samples, and one pair of interleaved chroma samples.
+//! let y = [0x00, 0x40, 0x80, 0xFF];
+//! let uv = [0x80, 0x80];
+//!
+//! let view = PlanarView::<Nv12>::new(2, 2, &[&y, &uv], &[2, 2]).unwrap();
+//! assert_eq!(view.get(1, 1).y(), 0xFF);
+//!
+//! let rgba = view.get_rgba::<Rgba8888>(1, 0, YcbcrMatrix::Bt601);
+//! assert_eq!((rgba.red(), rgba.green(), rgba.blue()), (0x40, 0x40, 0x40));
+//! ```
+
+mod i420;
+pub use i420::I420;
+
+mod nv12;
+pub use nv12::Nv12;
+
+mod p010;
+pub use p010::P010;
+
+use core::{error::Error, fmt::Display, marker::PhantomData};
+
+use crate::{
+    formats::{
+        rgba::RgbaFormat,
+        yuv::{Ycbcr, YcbcrFormat, YcbcrMatrix},
+    },
+    pixel::{ChannelOf, Format, Pixel, raw::RawPixel},
+};
+
+#[cfg(doc)]
+use crate::formats::yuv::Ycbcr444;
+
+/// Describes a single plane of a planar format.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Plane {
+    /// The channels stored in this plane, in the order they are interleaved within an element.
+    pub channels: &'static [Ycbcr],
+
+    /// The number of significant bits in each sample.
+    ///
+    /// Samples are stored in the smallest whole number of bytes (one byte for 8 bits, or a
+    /// little-endian `u16` for up to 16 bits), with the significant bits in the high bits.
+    pub bits_per_sample: u32,
+
+    /// How many horizontally adjacent pixels share a single element of this plane.
+    pub horizontal_subsampling: usize,
+
+    /// How many vertically adjacent pixels share a single element of this plane.
+    pub vertical_subsampling: usize,
+}
+
+impl Plane {
+    /// Returns the number of bytes in each sample of this plane.
+    #[must_use]
+    pub const fn bytes_per_sample(&self) -> usize {
+        self.bits_per_sample.div_ceil(8) as usize
+    }
+
+    /// Returns the number of bytes in each element of this plane.
+    #[must_use]
+    pub const fn bytes_per_element(&self) -> usize {
+        self.channels.len() * self.bytes_per_sample()
+    }
+
+    /// Returns the width and height, in elements, of this plane for an image of the given size.
+    ///
+    /// Subsampled dimensions are rounded up, so odd-sized images are fully covered.
+    #[must_use]
+    pub const fn dimensions(&self, width: usize, height: usize) -> (usize, usize) {
+        (
+            width.div_ceil(self.horizontal_subsampling),
+            height.div_ceil(self.vertical_subsampling),
+        )
+    }
+}
+
+/// A planar `YCbCr` format.
+///
+/// Each plane is described by a [`Plane`], in the order the planes are passed to [`PlanarView`].
+#[allow(private_bounds)]
+pub trait PlanarFormat: 'static + Copy + crate::internal::Sealed {
+    /// The packed format that pixels are read as.
+    ///
+    /// Each channel of its raw pixel is stored like a sample of the planes, in channel order.
+    type Pixel: YcbcrFormat<RawPixel: RawPixel<Bytes: AsMut<[u8]>>>;
+
+    /// The planes of the format.
+    const PLANES: &'static [Plane];
+}
+
+/// An error returned when a [`PlanarView`] cannot be created over a set of planes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PlanarViewError {
+    /// The number of planes does not match the format.
+    PlaneCount {
+        /// The number of planes of the format.
+        expected: usize,
+
+        /// The number of planes given.
+        actual: usize,
+    },
+
+    /// The number of strides does not match the format.
+    StrideCount {
+        /// The number of planes of the format.
+        expected: usize,
+
+        /// The number of strides given.
+        actual: usize,
+    },
+
+    /// The stride of a plane is smaller than a row of its elements, so rows would overlap.
+    StrideTooSmall {
+        /// The index of the plane.
+        plane: usize,
+
+        /// The stride, in bytes.
+        stride: usize,
+
+        /// The size of a row of the plane, in bytes.
+        row_bytes: usize,
+    },
+
+    /// A plane is too short for the dimensions and stride of the view.
+    TooFewBytes {
+        /// The index of the plane.
+        plane: usize,
+
+        /// The number of bytes required.
+        expected: usize,
+
+        /// The number of bytes in the plane.
+        actual: usize,
+    },
+}
+
+impl Display for PlanarViewError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PlaneCount { expected, actual } => {
+                write!(f, "Got {actual} planes, expected {expected}")
+            }
+            Self::StrideCount { expected, actual } => {
+                write!(f, "Got {actual} strides, expected {expected}")
+            }
+            Self::StrideTooSmall {
+                plane,
+                stride,
+                row_bytes,
+            } => {
+                write!(
+                    f,
+                    "Stride {stride} of plane {plane} is smaller than its rows of {row_bytes} bytes"
+                )
+            }
+            Self::TooFewBytes {
+                plane,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "Plane {plane} has {actual} bytes, expected at least {expected}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for PlanarViewError {}
+
+/// A borrowed view of an image in a planar format.
+///
+/// Each plane is a byte slice with its own stride (the number of bytes between the start of two
+/// consecutive rows), which may include padding at the end of each row.
+#[derive(Copy, Clone, Debug)]
+pub struct PlanarView<'a, F: PlanarFormat> {
+    width: usize,
+    height: usize,
+    planes: [&'a [u8]; 3],
+    strides: [usize; 3],
+    format: PhantomData<F>,
+}
+
+impl<'a, F: PlanarFormat> PlanarView<'a, F> {
+    /// Creates a new view over the given planes.
+    ///
+    /// `planes` and `strides` must be in the order of [`PlanarFormat::PLANES`].
+    ///
+    /// ## Errors
+    ///
+    /// If the number of planes or strides does not match the format, if a stride is shorter than a
+    /// row of its plane, or if a plane is too short to contain all of its rows.
+    pub fn new(
+        width: usize,
+        height: usize,
+        planes: &[&'a [u8]],
+        strides: &[usize],
+    ) -> Result<Self, PlanarViewError> {
+        let expected = F::PLANES.len();
+        if planes.len() != expected {
+            return Err(PlanarViewError::PlaneCount {
+                expected,
+                actual: planes.len(),
+            });
+        }
+        if strides.len() != expected {
+            return Err(PlanarViewError::StrideCount {
+                expected,
+                actual: strides.len(),
+            });
+        }
+
+        let mut view = Self {
+            width,
+            height,
+            planes: [&[]; 3],
+            strides: [0; 3],
+            format: PhantomData,
+        };
+        for (i, plane) in F::PLANES.iter().enumerate() {
+            let (columns, rows) = plane.dimensions(width, height);
+            let row_bytes = columns.saturating_mul(plane.bytes_per_element());
+            if strides[i] < row_bytes {
+                return Err(PlanarViewError::StrideTooSmall {
+                    plane: i,
+                    stride: strides[i],
+                    row_bytes,
+                });
+            }
+            // As with image views, the last row does not need to be padded.
+            let expected = match rows {
+                0 => 0,
+                _ => (rows - 1)
+                    .checked_mul(strides[i])
+                    .and_then(|n| n.checked_add(row_bytes))
+                    .unwrap_or(usize::MAX),
+            };
+            if planes[i].len() < expected {
+                return Err(PlanarViewError::TooFewBytes {
+                    plane: i,
+                    expected,
+                    actual: planes[i].len(),
+                });
+            }
+            view.planes[i] = planes[i];
+            view.strides[i] = strides[i];
+        }
+        Ok(view)
+    }
+
+    /// Returns the width of the image, in pixels.
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the image, in pixels.
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the `YCbCr` samples of the pixel at the given coordinates.
+    ///
+    /// ## Panics
+    ///
+    /// If `x` or `y` are out of bounds.
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Pixel<F::Pixel> {
+        assert!(
+            x < self.width && y < self.height,
+            "Coordinates out of bounds"
+        );
+        let mut bytes = <F::Pixel as Format>::RawPixel::DEFAULT.to_le_bytes();
+        for (i, plane) in F::PLANES.iter().enumerate() {
+            let size = plane.bytes_per_sample();
+            let row = y / plane.vertical_subsampling;
+            let column = x / plane.horizontal_subsampling;
+            let start = row * self.strides[i] + column * plane.bytes_per_element();
+            let element = &self.planes[i][start..][..plane.bytes_per_element()];
+            for (&channel, sample) in plane.channels.iter().zip(element.chunks_exact(size)) {
+                let offset = ChannelOf::<F::Pixel>::offset(channel) * size;
+                bytes.as_mut()[offset..][..size].copy_from_slice(sample);
+            }
+        }
+        Pixel::from_raw(RawPixel::from_le_bytes(bytes))
+    }
+
+    /// Returns the pixel at the given coordinates, converted to an opaque 8-bit RGBA pixel.
+    ///
+    /// ## Panics
+    ///
+    /// If `x` or `y` are out of bounds.
+    #[must_use]
+    pub fn get_rgba<T>(&self, x: usize, y: usize, matrix: YcbcrMatrix) -> Pixel<T>
+    where
+        <F::Pixel as Format>::RawPixel: RawPixel<Channel = u8>,
+        T: RgbaFormat,
+        T::RawPixel: RawPixel<Channel = u8>,
+    {
+        self.get(x, y).to_rgba(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn plane_dimensions_round_up() {
+        let plane = Nv12::PLANES[1];
+        assert_eq!(plane.dimensions(3, 5), (2, 3));
+        assert_eq!(plane.bytes_per_element(), 2);
+        assert_eq!(P010::PLANES[1].bytes_per_element(), 4);
+    }
+
+    #[test]
+    fn new_invalid_plane_count() {
+        assert_eq!(
+            PlanarView::<I420>::new(2, 2, &[&[0; 4], &[0; 1]], &[2, 1, 1]).unwrap_err(),
+            PlanarViewError::PlaneCount {
+                expected: 3,
+                actual: 2
+            }
+        );
+        assert_eq!(
+            PlanarView::<Nv12>::new(2, 2, &[&[0; 4], &[0; 2]], &[2]).unwrap_err(),
+            PlanarViewError::StrideCount {
+                expected: 2,
+                actual: 1
+            }
+        );
+    }
+
+    #[test]
+    fn new_plane_too_short() {
+        let error = PlanarView::<Nv12>::new(4, 4, &[&[0; 16], &[0; 6]], &[4, 4]).unwrap_err();
+        assert_eq!(
+            error,
+            PlanarViewError::TooFewBytes {
+                plane: 1,
+                expected: 8,
+                actual: 6
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Plane 1 has 6 bytes, expected at least 8"
+        );
+    }
+
+    #[test]
+    fn new_stride_too_short() {
+        assert_eq!(
+            PlanarView::<Nv12>::new(4, 2, &[&[0; 8], &[0; 4]], &[3, 4]).unwrap_err(),
+            PlanarViewError::StrideTooSmall {
+                plane: 0,
+                stride: 3,
+                row_bytes: 4
+            }
+        );
+        assert_eq!(
+            PlanarView::<P010>::new(2, 2, &[&[0; 8], &[0; 4]], &[2, 4]).unwrap_err(),
+            PlanarViewError::StrideTooSmall {
+                plane: 0,
+                stride: 2,
+                row_bytes: 4
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Coordinates out of bounds")]
+    fn get_out_of_bounds() {
+        let view = PlanarView::<Nv12>::new(2, 2, &[&[0; 4], &[0; 2]], &[2, 2]).unwrap();
+        let _ = view.get(2, 0);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::formats::yuv::{
+    Ycbcr, Ycbcr444,
+    planar::{PlanarFormat, Plane},
+};
+
+/// An 8-bit 4:2:0 planar format with separate luma and chroma planes, also known as `YUV420P`.
+///
+/// The format has three planes:
+/// - `Y`, one luma sample per pixel
+/// - `Cb`, one blue-difference chroma sample per 2x2 block of pixels
+/// - `Cr`, one red-difference chroma sample per 2x2 block of pixels
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum I420 {}
+
+impl crate::internal::Sealed for I420 {}
+
+impl PlanarFormat for I420 {
+    type Pixel = Ycbcr444;
+
+    const PLANES: &'static [Plane] = &[
+        Plane {
+            channels: &[Ycbcr::Y],
+            bits_per_sample: 8,
+            horizontal_subsampling: 1,
+            vertical_subsampling: 1,
+        },
+        Plane {
+            channels: &[Ycbcr::Cb],
+            bits_per_sample: 8,
+            horizontal_subsampling: 2,
+            vertical_subsampling: 2,
+        },
+        Plane {
+            channels: &[Ycbcr::Cr],
+            bits_per_sample: 8,
+            horizontal_subsampling: 2,
+            vertical_subsampling: 2,
+        },
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::yuv::planar::PlanarView;
+
+    use super::*;
+
+    #[test]
+    fn get() {
+        // A 3x3 image, so the chroma planes are 2x2.
+        #[rustfmt::skip]
+        let y = [
+            0x01, 0x02, 0x03,
+            0x04, 0x05, 0x06,
+            0x07, 0x08, 0x09,
+        ];
+        let u = [0x10, 0x11, 0x12, 0x13];
+        let v = [0x20, 0x21, 0x22, 0x23];
+        let view = PlanarView::<I420>::new(3, 3, &[&y, &u, &v], &[3, 2, 2]).unwrap();
+
+        let pixel = view.get(0, 0);
+        assert_eq!((pixel.y(), pixel.cb(), pixel.cr()), (0x01, 0x10, 0x20));
+
+        let pixel = view.get(2, 2);
+        assert_eq!((pixel.y(), pixel.cb(), pixel.cr()), (0x09, 0x13, 0x23));
+    }
+}
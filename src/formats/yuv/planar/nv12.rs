@@ -0,0 +1,59 @@
+use crate::formats::yuv::{
+    Ycbcr, Ycbcr444,
+    planar::{PlanarFormat, Plane},
+};
+
+/// An 8-bit 4:2:0 planar format with a luma plane and an interleaved chroma plane.
+///
+/// The format has two planes:
+/// - `Y`, one luma sample per pixel
+/// - `CbCr`, one pair of interleaved chroma samples per 2x2 block of pixels
+///
+/// This is the most common output format of hardware video decoders.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Nv12 {}
+
+impl crate::internal::Sealed for Nv12 {}
+
+impl PlanarFormat for Nv12 {
+    type Pixel = Ycbcr444;
+
+    const PLANES: &'static [Plane] = &[
+        Plane {
+            channels: &[Ycbcr::Y],
+            bits_per_sample: 8,
+            horizontal_subsampling: 1,
+            vertical_subsampling: 1,
+        },
+        Plane {
+            channels: &[Ycbcr::Cb, Ycbcr::Cr],
+            bits_per_sample: 8,
+            horizontal_subsampling: 2,
+            vertical_subsampling: 2,
+        },
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::yuv::planar::PlanarView;
+
+    use super::*;
+
+    #[test]
+    fn get() {
+        #[rustfmt::skip]
+        let y = [
+            0x01, 0x02, 0x03, 0x04, 0xEE,
+            0x05, 0x06, 0x07, 0x08, 0xEE,
+        ];
+        let uv = [0x10, 0x20, 0x30, 0x40];
+        let view = PlanarView::<Nv12>::new(4, 2, &[&y, &uv], &[5, 4]).unwrap();
+
+        let pixel = view.get(1, 1);
+        assert_eq!((pixel.y(), pixel.cb(), pixel.cr()), (0x06, 0x10, 0x20));
+
+        let pixel = view.get(2, 0);
+        assert_eq!((pixel.y(), pixel.cb(), pixel.cr()), (0x03, 0x30, 0x40));
+    }
+}
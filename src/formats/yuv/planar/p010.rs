@@ -0,0 +1,61 @@
+use crate::formats::yuv::{
+    self, Ycbcr,
+    planar::{PlanarFormat, Plane},
+};
+
+/// A 10-bit 4:2:0 planar format with a luma plane and an interleaved chroma plane.
+///
+/// The format has the same two planes as [`Nv12`](super::Nv12), with 16-bit little-endian samples
+/// holding 10 significant bits in their high bits:
+/// - `Y`, one luma sample per pixel
+/// - `CbCr`, one pair of interleaved chroma samples per 2x2 block of pixels
+///
+/// Pixels are read as [`yuv::P010`] pixels, with channels in the range `0..=1023`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum P010 {}
+
+impl crate::internal::Sealed for P010 {}
+
+impl PlanarFormat for P010 {
+    type Pixel = yuv::P010;
+
+    const PLANES: &'static [Plane] = &[
+        Plane {
+            channels: &[Ycbcr::Y],
+            bits_per_sample: 10,
+            horizontal_subsampling: 1,
+            vertical_subsampling: 1,
+        },
+        Plane {
+            channels: &[Ycbcr::Cb, Ycbcr::Cr],
+            bits_per_sample: 10,
+            horizontal_subsampling: 2,
+            vertical_subsampling: 2,
+        },
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::yuv::planar::PlanarView;
+
+    use super::*;
+
+    #[test]
+    fn get() {
+        // A 2x2 image of 16-bit little-endian samples, with a padded luma stride.
+        #[rustfmt::skip]
+        let y = [
+            0x40, 0x00, 0xC0, 0xFF, 0xEE, 0xEE,
+            0x80, 0x00, 0x00, 0x80,
+        ];
+        let uv = [0x00, 0x40, 0x00, 0xC0];
+        let view = PlanarView::<P010>::new(2, 2, &[&y, &uv], &[6, 4]).unwrap();
+
+        let pixel = view.get(1, 0);
+        assert_eq!((pixel.y(), pixel.cb(), pixel.cr()), (0x3FF, 0x100, 0x300));
+
+        let pixel = view.get(0, 1);
+        assert_eq!((pixel.y(), pixel.cb(), pixel.cr()), (0x002, 0x100, 0x300));
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{
+    formats::yuv::{Yuv422, Yuv422Format},
+    pixel::{Format, raw::U8x4},
+};
+
+/// A 32-bit packed 4:2:2 YUV macropixel format, also known as `Y422`.
+///
+/// This format is used to represent macropixels in the UYVY byte order:
+/// - `U`, shared blue-difference chroma (8 bits)
+/// - `Y0`, luma of the first pixel (8 bits)
+/// - `V`, shared red-difference chroma (8 bits)
+/// - `Y1`, luma of the second pixel (8 bits)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Uyvy422 {}
+
+impl crate::internal::Sealed for Uyvy422 {}
+
+impl Format for Uyvy422 {
+    type RawPixel = U8x4;
+    type Channels = Yuv422;
+    const NAME: &'static str = "Uyvy422";
+}
+
+impl Yuv422Format for Uyvy422 {
+    const U_OFFSET: usize = 0;
+    const Y0_OFFSET: usize = 1;
+    const V_OFFSET: usize = 2;
+    const Y1_OFFSET: usize = 3;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::{Pixel, raw::RawPixel};
+
+    use super::*;
+
+    #[test]
+    fn from_raw() {
+        let mut pixel: Pixel<Uyvy422> = Pixel::new(U8x4::from([0x80, 0x10, 0x70, 0xEB]));
+        assert_eq!(pixel.u(), 0x80);
+        assert_eq!(pixel.y0(), 0x10);
+        assert_eq!(pixel.v(), 0x70);
+        assert_eq!(pixel.y1(), 0xEB);
+
+        pixel.set_y0(0x01).set_u(0x02).set_y1(0x03).set_v(0x04);
+        assert_eq!(pixel.as_raw().into_inner(), [0x02, 0x01, 0x04, 0x03]);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{
+    formats::yuv::{Ycbcr, YcbcrFormat},
+    pixel::{Format, raw::U8x3},
+};
+
+/// A 24-bit packed 4:4:4 `YCbCr` pixel format with three 8-bit channels.
+///
+/// This format is used to represent pixels in the `YCbCr` byte order:
+/// - `Y`, luma (8 bits)
+/// - `Cb`, blue-difference chroma (8 bits)
+/// - `Cr`, red-difference chroma (8 bits)
+///
+/// This is the layout of interleaved JPEG MCU output before chroma subsampling is applied.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Ycbcr444 {}
+
+impl crate::internal::Sealed for Ycbcr444 {}
+
+impl Format for Ycbcr444 {
+    type RawPixel = U8x3;
+    type Channels = Ycbcr;
+    const NAME: &'static str = "Ycbcr444";
+}
+
+impl YcbcrFormat for Ycbcr444 {
+    const Y_OFFSET: usize = 0;
+    const CB_OFFSET: usize = 1;
+    const CR_OFFSET: usize = 2;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::{Pixel, raw::RawPixel};
+
+    use super::*;
+
+    #[test]
+    fn from_raw() {
+        let mut pixel: Pixel<Ycbcr444> = Pixel::new(U8x3::from([0x10, 0x80, 0x70]));
+        assert_eq!(pixel.y(), 0x10);
+        assert_eq!(pixel.cb(), 0x80);
+        assert_eq!(pixel.cr(), 0x70);
+
+        pixel.set_y(0x01).set_cb(0x02).set_cr(0x03);
+        assert_eq!(pixel.as_raw().into_inner(), [0x01, 0x02, 0x03]);
+    }
+}
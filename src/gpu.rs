@@ -0,0 +1,194 @@
+//! Helpers for uploading pixel data to graphics APIs.
+//!
+//! Graphics APIs each have their own rules for the _row pitch_ (the number of bytes between the
+//! start of two consecutive rows) of texture data uploaded from CPU memory. Uploading rows with an
+//! invalid pitch usually does not fail loudly, but produces a sheared or otherwise corrupted
+//! texture; [`validate_upload`] checks the pitch up front, where the bytes per pixel are known.
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::rgba::Rgba8888,
+//!     gpu::{Api, PitchError, validate_upload},
+//! };
+//!
+//! assert_eq!(validate_upload::<Rgba8888>(100, 400, Api::Vulkan), Ok(()));
+//! assert_eq!(
+//!     validate_upload::<Rgba8888>(100, 400, Api::D3D12),
+//!     Err(PitchError::Misaligned { row_pitch: 400, alignment: 256 }),
+//! );
+//! assert_eq!(validate_upload::<Rgba8888>(100, 512, Api::D3D12), Ok(()));
+//! ```
+
+use core::{error::Error, fmt::Display};
+
+use crate::pixel::Format;
+
+/// A graphics API with its own row pitch rules for texture uploads.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Api {
+    /// Vulkan, where rows are described in texels (`bufferRowLength`), so the pitch must be a
+    /// multiple of the pixel size.
+    Vulkan,
+
+    /// Direct3D 12, where the pitch must be a multiple of `D3D12_TEXTURE_DATA_PITCH_ALIGNMENT`
+    /// (256 bytes).
+    D3D12,
+
+    /// Metal, where the pitch (`bytesPerRow`) must be a multiple of the pixel size.
+    Metal,
+}
+
+impl Api {
+    /// Returns the required row pitch alignment, in bytes, for pixels of format `F`.
+    #[must_use]
+    pub const fn row_pitch_alignment<F: Format>(self) -> usize {
+        match self {
+            Self::Vulkan | Self::Metal => size_of::<F::RawPixel>(),
+            Self::D3D12 => 256,
+        }
+    }
+}
+
+/// An error returned when a row pitch is not valid for a texture upload.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PitchError {
+    /// The row pitch is shorter than a row of pixels.
+    TooShort {
+        /// The row pitch that was provided.
+        row_pitch: usize,
+
+        /// The minimum row pitch, in bytes, for a row of pixels.
+        required: usize,
+    },
+
+    /// The row pitch is not a multiple of the alignment required by the API.
+    Misaligned {
+        /// The row pitch that was provided.
+        row_pitch: usize,
+
+        /// The required alignment, in bytes.
+        alignment: usize,
+    },
+}
+
+impl Display for PitchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooShort {
+                row_pitch,
+                required,
+            } => write!(
+                f,
+                "Row pitch of {row_pitch} bytes is shorter than a row of {required} bytes"
+            ),
+            Self::Misaligned {
+                row_pitch,
+                alignment,
+            } => write!(
+                f,
+                "Row pitch of {row_pitch} bytes is not a multiple of {alignment} bytes"
+            ),
+        }
+    }
+}
+
+impl Error for PitchError {}
+
+/// Validates the row pitch of a texture upload of `width` pixels of format `F` per row.
+///
+/// The width is measured in [`Pixel`] values; for formats where a single value covers multiple
+/// pixels of the image (such as packed 4:2:2 macropixels), it is the number of such values.
+///
+/// ## Errors
+///
+/// Returns [`PitchError::TooShort`] if `row_pitch` is less than `width` pixels, and
+/// [`PitchError::Misaligned`] if it does not meet the API's [alignment][].
+///
+/// [`Pixel`]: crate::pixel::Pixel
+/// [alignment]: Api::row_pitch_alignment
+pub fn validate_upload<F: Format>(
+    width: usize,
+    row_pitch: usize,
+    api: Api,
+) -> Result<(), PitchError> {
+    let required = width.saturating_mul(size_of::<F::RawPixel>());
+    if row_pitch < required {
+        return Err(PitchError::TooShort {
+            row_pitch,
+            required,
+        });
+    }
+    let alignment = api.row_pitch_alignment::<F>();
+    if !row_pitch.is_multiple_of(alignment) {
+        return Err(PitchError::Misaligned {
+            row_pitch,
+            alignment,
+        });
+    }
+    Ok(())
+}
+
+/// Returns the smallest valid row pitch for a texture upload of `width` pixels of format `F`.
+///
+/// Returns `None` if the row pitch would overflow `usize`.
+#[must_use]
+pub fn aligned_row_pitch<F: Format>(width: usize, api: Api) -> Option<usize> {
+    let required = width.checked_mul(size_of::<F::RawPixel>())?;
+    required.checked_next_multiple_of(api.row_pitch_alignment::<F>())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::{depth::D16, rgba::FloatRgba, yuv::Ycbcr444};
+
+    use super::*;
+
+    #[test]
+    fn too_short() {
+        assert_eq!(
+            validate_upload::<FloatRgba>(4, 63, Api::Metal),
+            Err(PitchError::TooShort {
+                row_pitch: 63,
+                required: 64
+            })
+        );
+    }
+
+    #[test]
+    fn vulkan_and_metal_align_to_pixel_size() {
+        for api in [Api::Vulkan, Api::Metal] {
+            assert_eq!(validate_upload::<Ycbcr444>(3, 12, api), Ok(()));
+            assert_eq!(
+                validate_upload::<Ycbcr444>(3, 10, api),
+                Err(PitchError::Misaligned {
+                    row_pitch: 10,
+                    alignment: 3
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn d3d12_aligns_to_256() {
+        assert_eq!(validate_upload::<D16>(128, 256, Api::D3D12), Ok(()));
+        assert_eq!(
+            validate_upload::<D16>(129, 258, Api::D3D12),
+            Err(PitchError::Misaligned {
+                row_pitch: 258,
+                alignment: 256
+            })
+        );
+    }
+
+    #[test]
+    fn aligned_row_pitch_is_valid() {
+        for api in [Api::Vulkan, Api::D3D12, Api::Metal] {
+            for width in [0, 1, 3, 100, 1000] {
+                let pitch = aligned_row_pitch::<Ycbcr444>(width, api).unwrap();
+                assert_eq!(validate_upload::<Ycbcr444>(width, pitch, api), Ok(()));
+            }
+        }
+        assert_eq!(aligned_row_pitch::<D16>(129, Api::D3D12), Some(512));
+        assert_eq!(aligned_row_pitch::<D16>(usize::MAX, Api::Vulkan), None);
+    }
+}
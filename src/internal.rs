@@ -1,3 +1,39 @@
+use crate::pixel::raw::RawPixel;
+
 /// Used to seal traits for the crate.
+///
+/// Only reachable outside of the crate through [`define_format!`](crate::define_format) and the
+/// `PixelFormat` derive macro.
 #[doc(hidden)]
-pub(super) trait Sealed {}
+pub trait Sealed {}
+
+/// Seals [`PlainBytes`](crate::pixel::raw::PlainBytes).
+///
+/// Unlike [`Sealed`], this is never re-exported: safe byte casts rely on every implementation
+/// having an alignment of `1`, no padding, and no invalid bit patterns.
+pub trait SealedBytes {}
+
+/// Seals [`ConstChannels`](crate::pixel::raw::ConstChannels).
+///
+/// Unlike [`Sealed`], this is never re-exported: constant pixels such as
+/// [`Pixel::BLACK`](crate::pixel::Pixel::BLACK) rely on `MAX` and `MAX_AT` being the true channel
+/// extremes of the built-in raw pixel types.
+pub trait SealedConstChannels {}
+
+/// Asserts that every offset is in bounds for `R`, and that no two offsets are the same.
+///
+/// Called in a `const` context by [`define_format!`](crate::define_format), so invalid layouts
+/// fail to compile.
+#[doc(hidden)]
+pub const fn validate_offsets<R: RawPixel>(offsets: &[usize]) {
+    let mut i = 0;
+    while i < offsets.len() {
+        assert!(offsets[i] < R::CHANNELS, "Channel offset out of bounds");
+        let mut j = i + 1;
+        while j < offsets.len() {
+            assert!(offsets[i] != offsets[j], "Channel offsets must be unique");
+            j += 1;
+        }
+        i += 1;
+    }
+}
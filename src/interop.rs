@@ -0,0 +1,12 @@
+//! Interoperability with other pixel crates.
+//!
+//! Each integration is behind a feature of the same name as the crate it integrates with.
+
+#[cfg(any(feature = "pix", feature = "rgb"))]
+pub mod migrate;
+
+#[cfg(feature = "rand")]
+mod rand;
+
+#[cfg(feature = "serde")]
+mod serde;
@@ -0,0 +1,20 @@
+//! Adapters for migrating a codebase from another pixel crate to `pxlfmt`.
+//!
+//! Each submodule mirrors the names of another crate's types with aliases to the equivalent
+//! `pxlfmt` types, and, where it depends on that crate, provides conversions in both directions, so
+//! that a codebase can be migrated one file at a time: converted code uses `pxlfmt`, and converts at
+//! the boundaries with code that has not been migrated yet.
+//!
+//! | Crate   | Feature | Module   |
+//! |---------|---------|----------|
+//! | [`pix`] | `pix`   | [`pix`]  |
+//! | [`rgb`] | `rgb`   | [`rgb`]  |
+//!
+//! [`pix`]: https://crates.io/crates/pix
+//! [`rgb`]: https://crates.io/crates/rgb
+
+#[cfg(feature = "pix")]
+pub mod pix;
+
+#[cfg(feature = "rgb")]
+pub mod rgb;
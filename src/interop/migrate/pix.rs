@@ -0,0 +1,142 @@
+//! Migration adapters for the [`pix`](https://crates.io/crates/pix) crate.
+//!
+//! Unlike the `rgb` adapters, this module does not depend on `pix`: it mirrors the names of its
+//! common pixel types and channel accessors, so that call sites can be ported by changing their
+//! imports first. The aliased formats share the memory layout of the `pix` types,
+//! so rasters can be exchanged as bytes with [`Pixel::slice_from_bytes`] and
+//! [`Pixel::slice_as_bytes`].
+//!
+//! `pix` distinguishes linear and sRGB encodings in the type (e.g. `Rgba8` and `SRgba8`), while
+//! `pxlfmt` formats do not track the encoding; both map to the same format here.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pxlfmt::interop::migrate::pix::{Rgb, Rgba8};
+//!
+//! let pixel = Rgba8::with_rgba(0xFF, 0x80, 0x00, 0xFF);
+//! assert_eq!(Rgb::green(pixel), 0x80);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    formats::{
+        gray::{Gray8 as Gray8Format, Gray16 as Gray16Format, GrayFormat},
+        rgba::{FloatRgba, Rgba8888Le, Rgba16161616, RgbaFormat},
+    },
+    pixel::{Pixel, raw::RawPixel},
+};
+
+#[cfg(feature = "alloc")]
+use crate::pixel::{Format, buffer::PixelBuffer};
+
+/// The equivalent of `pix::rgb::Rgba8`.
+pub type Rgba8 = Pixel<Rgba8888Le>;
+
+/// The equivalent of `pix::rgb::SRgba8`.
+pub type SRgba8 = Pixel<Rgba8888Le>;
+
+/// The equivalent of `pix::rgb::Rgba16`.
+pub type Rgba16 = Pixel<Rgba16161616>;
+
+/// The equivalent of `pix::rgb::Rgba32`.
+pub type Rgba32 = Pixel<FloatRgba>;
+
+/// The equivalent of `pix::gray::Gray8`.
+pub type Gray8 = Pixel<Gray8Format>;
+
+/// The equivalent of `pix::gray::Gray16`.
+pub type Gray16 = Pixel<Gray16Format>;
+
+/// The equivalent of `pix::Raster`.
+#[cfg(feature = "alloc")]
+pub type Raster<F> = PixelBuffer<F>;
+
+/// Channel accessors mirroring `pix::rgb::Rgb`.
+pub enum Rgb {}
+
+impl Rgb {
+    /// Returns the red channel of the pixel, like `pix::rgb::Rgb::red`.
+    pub fn red<F: RgbaFormat>(pixel: Pixel<F>) -> <F::RawPixel as RawPixel>::Channel {
+        pixel.red()
+    }
+
+    /// Returns the green channel of the pixel, like `pix::rgb::Rgb::green`.
+    pub fn green<F: RgbaFormat>(pixel: Pixel<F>) -> <F::RawPixel as RawPixel>::Channel {
+        pixel.green()
+    }
+
+    /// Returns the blue channel of the pixel, like `pix::rgb::Rgb::blue`.
+    pub fn blue<F: RgbaFormat>(pixel: Pixel<F>) -> <F::RawPixel as RawPixel>::Channel {
+        pixel.blue()
+    }
+}
+
+/// Channel accessors mirroring `pix::gray::Gray`.
+pub enum Gray {}
+
+impl Gray {
+    /// Returns the gray channel of the pixel, like `pix::gray::Gray::value`.
+    pub fn value<F: GrayFormat>(pixel: Pixel<F>) -> <F::RawPixel as RawPixel>::Channel {
+        pixel.gray()
+    }
+}
+
+/// Creates a raster with all pixels zeroed, like `pix::Raster::with_clear`.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn with_clear<F: Format>(width: u32, height: u32) -> Raster<F> {
+    PixelBuffer::new(width as usize, height as usize)
+}
+
+/// Creates a raster from a vector of pixels, like `pix::Raster::with_pixels`.
+///
+/// ## Panics
+///
+/// If the number of pixels does not match `width * height`.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn with_pixels<F: Format>(width: u32, height: u32, pixels: Vec<Pixel<F>>) -> Raster<F> {
+    PixelBuffer::from_vec(width as usize, height as usize, pixels)
+        .expect("Pixels must have width * height elements")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accessors() {
+        let pixel = Rgba8::with_rgba(0x01, 0x02, 0x03, 0x04);
+        assert_eq!(
+            (Rgb::red(pixel), Rgb::green(pixel), Rgb::blue(pixel)),
+            (0x01, 0x02, 0x03)
+        );
+        assert_eq!(Gray::value(Gray16::with_gray(0x1234)), 0x1234);
+    }
+
+    #[test]
+    fn layout_matches_pix() {
+        let pixel = Rgba8::with_rgba(0x01, 0x02, 0x03, 0x04);
+        assert_eq!(pixel.as_bytes(), [0x01, 0x02, 0x03, 0x04]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rasters() {
+        let clear = with_clear::<Rgba8888Le>(2, 3);
+        assert_eq!((clear.width(), clear.height()), (2, 3));
+
+        let raster = with_pixels(2, 1, alloc::vec![Gray8::with_gray(1), Gray8::with_gray(2)]);
+        assert_eq!(Gray::value(raster[(1, 0)]), 2);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "Pixels must have width * height elements")]
+    fn with_pixels_wrong_length() {
+        let _ = with_pixels(2, 2, alloc::vec![Gray8::with_gray(1)]);
+    }
+}
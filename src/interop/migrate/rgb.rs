@@ -0,0 +1,133 @@
+//! Migration adapters for the [`rgb`](https://crates.io/crates/rgb) crate.
+//!
+//! Conversions are implemented with [`From`] in both directions for [`rgb::Rgba`] and any RGBA
+//! format with matching channel types; channels are always mapped by name (red to red, and so on),
+//! regardless of the format's memory layout.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pxlfmt::{formats::rgba::Rgba8888, interop::migrate::rgb::RGBA8, pixel::Pixel};
+//!
+//! // Code that has not been migrated yet.
+//! fn legacy() -> rgb::RGBA8 {
+//!     rgb::RGBA8::new(0xFF, 0x80, 0x00, 0xFF)
+//! }
+//!
+//! let pixel: RGBA8 = legacy().into();
+//! assert_eq!(pixel.green(), 0x80);
+//!
+//! let back: rgb::RGBA8 = pixel.into();
+//! assert_eq!(back, legacy());
+//! ```
+
+use crate::{
+    formats::rgba::{FloatRgba, Rgba8888, RgbaFormat},
+    pixel::{Pixel, raw::RawPixel},
+};
+
+/// The equivalent of [`rgb::RGBA8`].
+pub type RGBA8 = Pixel<Rgba8888>;
+
+/// The equivalent of `rgb::RGBA<f32>`.
+pub type RGBAF32 = Pixel<FloatRgba>;
+
+impl<F, T> From<rgb::Rgba<T>> for Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = T>,
+{
+    fn from(value: rgb::Rgba<T>) -> Self {
+        Pixel::with_rgba(value.r, value.g, value.b, value.a)
+    }
+}
+
+impl<F, T> From<Pixel<F>> for rgb::Rgba<T>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = T>,
+{
+    fn from(value: Pixel<F>) -> Self {
+        rgb::Rgba {
+            r: value.red(),
+            g: value.green(),
+            b: value.blue(),
+            a: value.alpha(),
+        }
+    }
+}
+
+/// Copies pixels from a slice of [`rgb::Rgba`] into a slice of [`Pixel`]s.
+///
+/// ## Panics
+///
+/// If the slices have different lengths.
+pub fn copy_from_rgb<F, T>(src: &[rgb::Rgba<T>], dst: &mut [Pixel<F>])
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = T>,
+    T: Copy,
+{
+    assert_eq!(src.len(), dst.len(), "Slices must have the same length");
+    for (dst, src) in dst.iter_mut().zip(src) {
+        *dst = (*src).into();
+    }
+}
+
+/// Copies pixels from a slice of [`Pixel`]s into a slice of [`rgb::Rgba`].
+///
+/// ## Panics
+///
+/// If the slices have different lengths.
+pub fn copy_into_rgb<F, T>(src: &[Pixel<F>], dst: &mut [rgb::Rgba<T>])
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = T>,
+{
+    assert_eq!(src.len(), dst.len(), "Slices must have the same length");
+    for (dst, src) in dst.iter_mut().zip(src) {
+        *dst = (*src).into();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::Abgr8888;
+
+    use super::*;
+
+    #[test]
+    fn from_rgba() {
+        let pixel: Pixel<Abgr8888> = rgb::RGBA8::new(0x01, 0x02, 0x03, 0x04).into();
+        assert_eq!(pixel, Pixel::with_rgba(0x01, 0x02, 0x03, 0x04));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn into_rgba_f32() {
+        let pixel = RGBAF32::with_rgba(0.25, 0.5, 0.75, 1.0);
+        let rgba: rgb::Rgba<f32> = pixel.into();
+        assert_eq!(rgba, rgb::Rgba::new(0.25, 0.5, 0.75, 1.0));
+    }
+
+    #[test]
+    fn copy_slices() {
+        let src = [
+            rgb::RGBA8::new(0x01, 0x02, 0x03, 0x04),
+            rgb::RGBA8::new(0x05, 0x06, 0x07, 0x08),
+        ];
+        let mut pixels = [RGBA8::zeroed(); 2];
+        copy_from_rgb(&src, &mut pixels);
+        assert_eq!(pixels[1], RGBA8::with_rgba(0x05, 0x06, 0x07, 0x08));
+
+        let mut back = [rgb::RGBA8::default(); 2];
+        copy_into_rgb(&pixels, &mut back);
+        assert_eq!(back, src);
+    }
+
+    #[test]
+    #[should_panic(expected = "Slices must have the same length")]
+    fn copy_length_mismatch() {
+        copy_from_rgb(&[rgb::RGBA8::default()], &mut [RGBA8::zeroed(); 2]);
+    }
+}
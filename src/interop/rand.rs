@@ -0,0 +1,116 @@
+//! `rand` support for pixels and raw pixels.
+//!
+//! The [`StandardUniform`] distribution is implemented for every raw pixel (by sampling its
+//! [storage](RawPixel::Storage)) and for [`Pixel`], so random pixels can be generated with
+//! `rng.random()`. Floating point channels are sampled in `[0, 1)`.
+//!
+//! [`Pixel::random_opaque`] instead generates a random color with a maximum alpha channel, which is
+//! more useful for debug visualization:
+//!
+//! ```rust
+//! use pxlfmt::{formats::rgba::Rgba8888, pixel::Pixel};
+//! use rand::{RngExt, SeedableRng};
+//! use rand_chacha::ChaCha8Rng;
+//!
+//! let mut rng = ChaCha8Rng::seed_from_u64(0);
+//! let _: Pixel<Rgba8888> = rng.random();
+//!
+//! let pixel = Pixel::<Rgba8888>::random_opaque(&mut rng);
+//! assert_eq!(pixel.alpha(), 0xFF);
+//! ```
+
+use ::rand::{
+    Rng, RngExt,
+    distr::{Distribution, StandardUniform},
+};
+
+use crate::{
+    formats::rgba::RgbaFormat,
+    norm::Normalized,
+    pixel::{
+        Format, Pixel,
+        raw::{
+            F32x1, F32x4, F64x4, I8x2, I8x4, I16x4, RawPixel, U8x1, U8x3, U8x4, U16x1, U16x3,
+            U16x3Msb10, U16x4, U16x565, U32x248, U32x8888, U32x8888Be, U32x8888Le, U32x9995,
+            U32x111110,
+        },
+    },
+};
+
+impl<F> Distribution<Pixel<F>> for StandardUniform
+where
+    F: Format,
+    StandardUniform: Distribution<F::RawPixel>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Pixel<F> {
+        Pixel::from_raw(self.sample(rng))
+    }
+}
+
+macro_rules! impl_standard_uniform {
+  ($($raw:ty),*) => {
+    $(
+      impl Distribution<$raw> for StandardUniform {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $raw {
+          <$raw>::from(Distribution::<<$raw as RawPixel>::Storage>::sample(self, rng))
+        }
+      }
+    )*
+  };
+}
+
+impl_standard_uniform!(
+    F32x1, F32x4, F64x4, I8x2, I8x4, I16x4, U8x1, U8x3, U8x4, U16x1, U16x3, U16x3Msb10, U16x4,
+    U16x565, U32x248, U32x8888, U32x8888Be, U32x8888Le, U32x111110, U32x9995
+);
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+    StandardUniform: Distribution<<F::RawPixel as RawPixel>::Channel>,
+{
+    /// Returns a random opaque pixel.
+    ///
+    /// The color channels are sampled from [`StandardUniform`], and the alpha channel is set to
+    /// [`Normalized::ONE`].
+    pub fn random_opaque<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::with_rgb(rng.random(), rng.random(), rng.random())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use crate::formats::rgba::{FloatRgba, Rgba8888};
+
+    use super::*;
+
+    #[test]
+    fn standard_uniform_is_seeded() {
+        let a: [Pixel<Rgba8888>; 4] = ChaCha8Rng::seed_from_u64(1).random();
+        let b: [Pixel<Rgba8888>; 4] = ChaCha8Rng::seed_from_u64(1).random();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn standard_uniform_float() {
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+        for _ in 0..64 {
+            let pixel: Pixel<FloatRgba> = rng.random();
+            assert!(pixel.channels().iter().all(|c| (0.0..1.0).contains(c)));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn random_opaque() {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        for _ in 0..64 {
+            assert_eq!(Pixel::<Rgba8888>::random_opaque(&mut rng).alpha(), 0xFF);
+            assert_eq!(Pixel::<FloatRgba>::random_opaque(&mut rng).alpha(), 1.0);
+        }
+    }
+}
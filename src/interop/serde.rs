@@ -0,0 +1,255 @@
+//! `serde` support for pixels and raw pixels.
+//!
+//! Raw pixels (and [`Pixel`], which serializes as its raw pixel) are serialized as their
+//! [storage](RawPixel::Storage). Human-readable formats (such as JSON) instead use a hex string for
+//! raw pixels with integer channels, with every element of the storage written as a fixed number of
+//! big-endian digits in memory order:
+//!
+//! ```rust
+//! use pxlfmt::{formats::rgba::{FloatRgba, Rgba8888}, pixel::Pixel};
+//!
+//! let pixel = Pixel::<Rgba8888>::new(0xFF00_80FF);
+//! assert_eq!(serde_json::to_string(&pixel).unwrap(), r#""ff0080ff""#);
+//! assert_eq!(serde_json::from_str::<Pixel<Rgba8888>>(r#""ff0080ff""#).unwrap(), pixel);
+//!
+//! let pixel = Pixel::<FloatRgba>::with_rgba(1.0, 0.5, 0.0, 1.0);
+//! assert_eq!(serde_json::to_string(&pixel).unwrap(), "[1.0,0.5,0.0,1.0]");
+//! ```
+
+use core::{
+    fmt::{Display, Formatter},
+    marker::PhantomData,
+};
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::pixel::{
+    Format, Pixel,
+    raw::{
+        F32x1, F32x4, F64x4, I8x2, I8x4, I16x4, RawPixel, U8x1, U8x3, U8x4, U16x1, U16x3,
+        U16x3Msb10, U16x4, U16x565, U32x248, U32x8888, U32x8888Be, U32x8888Le, U32x9995,
+        U32x111110,
+    },
+};
+
+impl<F> Serialize for Pixel<F>
+where
+    F: Format,
+    F::RawPixel: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_raw().serialize(serializer)
+    }
+}
+
+impl<'de, F> Deserialize<'de> for Pixel<F>
+where
+    F: Format,
+    F::RawPixel: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        F::RawPixel::deserialize(deserializer).map(Self::from_raw)
+    }
+}
+
+/// An integer that is written as a fixed number of hex digits.
+trait HexInt: Copy {
+    /// The number of hex digits.
+    const DIGITS: usize;
+
+    /// Returns the bits of the integer.
+    fn to_bits(self) -> u32;
+
+    /// Parses the integer from exactly [`Self::DIGITS`] hex digits.
+    fn parse(digits: &str) -> Option<Self>;
+}
+
+macro_rules! impl_hex_int {
+  ($($t:ty => $u:ty),*) => {
+    $(
+      impl HexInt for $t {
+        const DIGITS: usize = size_of::<$t>() * 2;
+
+        #[allow(clippy::cast_sign_loss)]
+        fn to_bits(self) -> u32 {
+          u32::from(self as $u)
+        }
+
+        #[allow(clippy::cast_possible_wrap)]
+        fn parse(digits: &str) -> Option<Self> {
+          if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+          }
+          <$u>::from_str_radix(digits, 16).ok().map(|value| value as $t)
+        }
+      }
+    )*
+  };
+}
+
+impl_hex_int!(u8 => u8, u16 => u16, u32 => u32, i8 => u8, i16 => u16);
+
+/// Storage of a raw pixel with integer channels, which is written as a hex string.
+trait HexStorage: Sized {
+    /// Writes the storage as hex digits.
+    fn write_hex(&self, f: &mut Formatter<'_>) -> core::fmt::Result;
+
+    /// Parses the storage from hex digits.
+    fn parse_hex(digits: &str) -> Option<Self>;
+}
+
+impl<T: HexInt> HexStorage for T {
+    fn write_hex(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:01$x}", self.to_bits(), T::DIGITS)
+    }
+
+    fn parse_hex(digits: &str) -> Option<Self> {
+        (digits.len() == T::DIGITS)
+            .then(|| T::parse(digits))
+            .flatten()
+    }
+}
+
+impl<T: HexInt + Default, const N: usize> HexStorage for [T; N] {
+    fn write_hex(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.iter().try_for_each(|element| element.write_hex(f))
+    }
+
+    fn parse_hex(digits: &str) -> Option<Self> {
+        if digits.len() != T::DIGITS * N {
+            return None;
+        }
+        let mut storage = [T::default(); N];
+        for (i, element) in storage.iter_mut().enumerate() {
+            *element = T::parse(digits.get(i * T::DIGITS..(i + 1) * T::DIGITS)?)?;
+        }
+        Some(storage)
+    }
+}
+
+/// Displays storage as a hex string.
+struct Hex<'a, T>(&'a T);
+
+impl<T: HexStorage> Display for Hex<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.0.write_hex(f)
+    }
+}
+
+/// Deserializes storage from a hex string.
+struct HexVisitor<T>(PhantomData<T>);
+
+impl<T: HexStorage> de::Visitor<'_> for HexVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "a hex string of a raw pixel")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        T::parse_hex(value).ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+/// Implements `Serialize` and `Deserialize` for raw pixels with integer channels.
+macro_rules! impl_serde_hex {
+  ($($raw:ty),*) => {
+    $(
+      impl Serialize for $raw {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+          if serializer.is_human_readable() {
+            serializer.collect_str(&Hex(self.as_inner()))
+          } else {
+            self.as_inner().serialize(serializer)
+          }
+        }
+      }
+
+      impl<'de> Deserialize<'de> for $raw {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+          if deserializer.is_human_readable() {
+            deserializer
+              .deserialize_str(HexVisitor::<<$raw as RawPixel>::Storage>(PhantomData))
+              .map(Self::from)
+          } else {
+            <$raw as RawPixel>::Storage::deserialize(deserializer).map(Self::from)
+          }
+        }
+      }
+    )*
+  };
+}
+
+impl_serde_hex!(
+    I8x2, I8x4, I16x4, U8x1, U8x3, U8x4, U16x1, U16x3, U16x3Msb10, U16x4, U16x565, U32x248,
+    U32x8888, U32x8888Be, U32x8888Le, U32x111110, U32x9995
+);
+
+/// Implements `Serialize` and `Deserialize` for raw pixels with floating point channels.
+macro_rules! impl_serde_float {
+  ($($raw:ty),*) => {
+    $(
+      impl Serialize for $raw {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+          self.as_inner().serialize(serializer)
+        }
+      }
+
+      impl<'de> Deserialize<'de> for $raw {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+          <$raw as RawPixel>::Storage::deserialize(deserializer).map(Self::from)
+        }
+      }
+    )*
+  };
+}
+
+impl_serde_float!(F32x1, F32x4, F64x4);
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{Configure, Readable, Token, assert_de_tokens_error, assert_tokens};
+
+    use crate::formats::{rgba::Rgba8888, ycocg::YcocgR};
+
+    use super::*;
+
+    #[test]
+    fn readable_hex() {
+        let pixel = Pixel::<Rgba8888>::new(0x0000_80FF);
+        assert_tokens(&pixel.readable(), &[Token::Str("000080ff")]);
+
+        let raw = U8x4::from([0x11, 0x22, 0x33, 0x44]);
+        assert_tokens(&raw.readable(), &[Token::Str("11223344")]);
+
+        let pixel = Pixel::<YcocgR>::with_ycocga(1, -1, 0x100, i16::MIN);
+        assert_tokens(&pixel.readable(), &[Token::Str("0001ffff01008000")]);
+    }
+
+    #[test]
+    fn compact_storage() {
+        let pixel = Pixel::<Rgba8888>::new(0x0000_80FF);
+        assert_tokens(&pixel.compact(), &[Token::U32(0x0000_80FF)]);
+
+        let raw = U8x3::from([1, 2, 3]);
+        assert_tokens(
+            &raw.compact(),
+            &[
+                Token::Tuple { len: 3 },
+                Token::U8(1),
+                Token::U8(2),
+                Token::U8(3),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn readable_invalid() {
+        let expected = "invalid value: string \"80ff\", expected a hex string of a raw pixel";
+        assert_de_tokens_error::<Readable<U32x8888>>(&[Token::Str("80ff")], expected);
+
+        let expected = "invalid value: string \"+000000\", expected a hex string of a raw pixel";
+        assert_de_tokens_error::<Readable<U32x8888>>(&[Token::Str("+000000")], expected);
+    }
+}
@@ -2,10 +2,56 @@
 //!
 //! ## Features
 //!
+//! ### `alloc`
+//!
+//! Owned pixel storage using the `alloc` crate, such as `pixel::buffer::PixelBuffer`
+//!
+//! ### `arbitrary`
+//!
+//! Implements `arbitrary::Arbitrary` for raw pixel wrappers and `Pixel`, for fuzzing (requires
+//! `std`)
+//!
 //! ### `bytemuck`
 //!
 //! Implements `bytemuck::{Pod, Zeroable}` for raw pixel wrappers and `Pixel`
 //!
+//! ### `defmt`
+//!
+//! Implements `defmt::Format` for raw pixel wrappers and `Pixel`, for logging on embedded targets
+//!
+//! ### `derive`
+//!
+//! The `PixelFormat` derive macro, for declaring custom packed RGBA formats
+//!
+//! ### `pix`
+//!
+//! Migration adapters mirroring the `pix` crate's types in `interop::migrate::pix`, without
+//! depending on it
+//!
+//! ### `rand`
+//!
+//! Implements the `StandardUniform` distribution for raw pixel wrappers and `Pixel`, and adds
+//! `Pixel::random_opaque`
+//!
+//! ### `rgb`
+//!
+//! Conversions to and from the `rgb` crate's types, and migration adapters in `interop::migrate`
+//!
+//! ### `serde`
+//!
+//! Implements `serde::{Serialize, Deserialize}` for raw pixel wrappers and `Pixel`
+//!
+//! ### `zerocopy`
+//!
+//! Implements `zerocopy::{FromBytes, IntoBytes, Immutable, KnownLayout}` for raw pixel wrappers and
+//! `Pixel`, as an alternative to `bytemuck`
+//!
+//! ## Custom formats
+//!
+//! [`Format`](pixel::Format) is sealed, but additional RGBA layouts over any raw pixel type can be
+//! declared with [`define_format!`], or, with the `derive` feature, packed integer layouts can be
+//! generated with `#[derive(PixelFormat)]`.
+//!
 //! # Example
 //!
 //! ```rust
@@ -28,9 +74,41 @@
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+// `arbitrary` and its derive require `std`.
+#[cfg(feature = "arbitrary")]
+extern crate std;
+
+pub mod accum;
+pub mod alpha;
+pub mod blend;
+pub mod colors;
+pub mod convert;
+pub mod dither;
+pub mod dynamic;
 pub mod formats;
+pub mod gpu;
+pub mod interop;
+pub mod norm;
+pub mod ops;
+pub mod orient;
+pub mod pipeline;
 pub mod pixel;
 pub mod prelude;
+pub mod stats;
+pub mod transfer;
 pub mod uint;
 
+#[cfg(feature = "derive")]
+pub use pxlfmt_derive::PixelFormat;
+
 pub(crate) mod internal;
+mod macros;
+
+/// Implementation details used by exported macros; not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::internal::{Sealed, validate_offsets};
+}
@@ -0,0 +1,130 @@
+/// Defines a custom RGBA pixel format on top of an existing raw pixel type.
+///
+/// [`Format`](crate::pixel::Format) is sealed, so formats cannot be implemented by hand outside of
+/// this crate. This macro generates the format type along with its `Format`,
+/// [`RgbaFormat`](crate::formats::rgba::RgbaFormat), and [`AlphaFormat`](crate::alpha::AlphaFormat)
+/// implementations from the offset of each channel in the raw pixel.
+///
+/// The offsets are validated at compile time: each must be less than the raw pixel's number of
+/// channels, and no two channels may share an offset.
+///
+/// ## Example
+///
+/// ```rust
+/// use pxlfmt::{define_format, pixel::{Pixel, raw::{RawPixel, U32x8888}}};
+///
+/// define_format! {
+///     /// A 32-bit ARGB pixel format with four 8-bit channels.
+///     pub enum Argb8888: U32x8888 {
+///         red: 1,
+///         green: 2,
+///         blue: 3,
+///         alpha: 0,
+///     }
+/// }
+///
+/// let pixel = Pixel::<Argb8888>::with_rgba(0x11, 0x22, 0x33, 0xFF);
+/// assert_eq!(pixel.as_raw().into_inner(), 0x3322_11FF);
+/// ```
+///
+/// Invalid layouts fail to compile:
+///
+/// ```rust,compile_fail
+/// use pxlfmt::{define_format, pixel::raw::U32x8888};
+///
+/// define_format! {
+///     pub enum Broken: U32x8888 {
+///         red: 0,
+///         green: 0,
+///         blue: 1,
+///         alpha: 4,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_format {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident: $raw:ty {
+            red: $red:expr,
+            green: $green:expr,
+            blue: $blue:expr,
+            alpha: $alpha:expr $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+        $vis enum $name {}
+
+        impl $crate::__private::Sealed for $name {}
+
+        impl $crate::pixel::Format for $name {
+            type RawPixel = $raw;
+            type Channels = $crate::formats::rgba::Rgba;
+            const NAME: &'static str = stringify!($name);
+        }
+
+        impl $crate::formats::rgba::RgbaFormat for $name {
+            const RED_OFFSET: usize = $red;
+            const GREEN_OFFSET: usize = $green;
+            const BLUE_OFFSET: usize = $blue;
+        }
+
+        impl $crate::alpha::AlphaFormat for $name {
+            const ALPHA_OFFSET: usize = $alpha;
+        }
+
+        const _: () = $crate::__private::validate_offsets::<$raw>(&[$red, $green, $blue, $alpha]);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pixel::{
+        Pixel,
+        raw::{F32x4, RawPixel, U8x4},
+    };
+
+    define_format! {
+        /// A test format.
+        pub(crate) enum Bgra8: U8x4 {
+            red: 2,
+            green: 1,
+            blue: 0,
+            alpha: 3,
+        }
+    }
+
+    define_format! {
+        enum FloatArgb: F32x4 { red: 1, green: 2, blue: 3, alpha: 0 }
+    }
+
+    #[test]
+    fn bytes() {
+        let mut pixel = Pixel::<Bgra8>::with_rgba(0x11, 0x22, 0x33, 0x44);
+        assert_eq!(pixel.as_raw().into_inner(), [0x33, 0x22, 0x11, 0x44]);
+
+        pixel.set_alpha(0xFF);
+        assert_eq!(pixel.as_raw().into_inner(), [0x33, 0x22, 0x11, 0xFF]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn float() {
+        let pixel = Pixel::<FloatArgb>::with_rgba(0.25, 0.5, 0.75, 1.0);
+        assert_eq!(pixel.as_raw().into_inner(), [1.0, 0.25, 0.5, 0.75]);
+        assert_eq!(pixel.green(), 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Channel offsets must be unique")]
+    fn validate_duplicate() {
+        crate::internal::validate_offsets::<U8x4>(&[0, 1, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Channel offset out of bounds")]
+    fn validate_out_of_bounds() {
+        crate::internal::validate_offsets::<U8x4>(&[0, 1, 2, 4]);
+    }
+}
@@ -0,0 +1,141 @@
+//! Normalized channel values.
+
+use crate::internal::Sealed;
+
+/// A channel type that represents a value in the normalized `0.0..=1.0` range.
+///
+/// Unsigned integer channels map `0` to `0.0` and their maximum value to `1.0`; floating point
+/// channels are already normalized, and are passed through as-is (including values outside of the
+/// range, such as in HDR content).
+///
+/// Signed integer channels are _signed normalized_ (snorm), and extend the range to `-1.0..=1.0`:
+/// their maximum value maps to `1.0`, and both their minimum value and the value above it map to
+/// `-1.0`, so that `0` is exactly `0.0`.
+///
+/// This trait is _sealed_, and is implemented for `u8`, `u16`, `i8`, and `f32`.
+#[allow(private_bounds)]
+pub trait Normalized: Sealed + Sized + Copy {
+    /// The channel value representing `0.0`.
+    const ZERO: Self;
+
+    /// The channel value representing `1.0`.
+    const ONE: Self;
+
+    /// The normalized distance between two adjacent channel values.
+    ///
+    /// This is `0.0` for floating point channels, which are treated as continuous.
+    const STEP: f32;
+
+    /// Converts the channel value to a normalized `f32`.
+    fn to_f32(self) -> f32;
+
+    /// Converts a normalized `f32` to a channel value.
+    ///
+    /// Integer channels clamp the value to their range and round to the nearest integer.
+    fn from_f32(value: f32) -> Self;
+}
+
+macro_rules! impl_normalized_uint {
+  ($($t:ty),*) => {
+    $(
+      impl Normalized for $t {
+        const ZERO: Self = 0;
+        const ONE: Self = <$t>::MAX;
+        const STEP: f32 = 1.0 / <$t>::MAX as f32;
+
+        fn to_f32(self) -> f32 {
+          f32::from(self) / f32::from(<$t>::MAX)
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        fn from_f32(value: f32) -> Self {
+          (value.clamp(0.0, 1.0) * f32::from(<$t>::MAX) + 0.5) as $t
+        }
+      }
+    )*
+  };
+}
+
+impl_normalized_uint!(u8, u16);
+
+impl Sealed for i8 {}
+
+impl Normalized for i8 {
+    const ZERO: Self = 0;
+    const ONE: Self = i8::MAX;
+    const STEP: f32 = 1.0 / i8::MAX as f32;
+
+    fn to_f32(self) -> f32 {
+        (f32::from(self) / f32::from(i8::MAX)).max(-1.0)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_f32(value: f32) -> Self {
+        let scaled = value.clamp(-1.0, 1.0) * f32::from(i8::MAX);
+        if scaled < 0.0 {
+            (scaled - 0.5) as i8
+        } else {
+            (scaled + 0.5) as i8
+        }
+    }
+}
+
+impl Sealed for f32 {}
+
+impl Normalized for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const STEP: f32 = 0.0;
+
+    fn to_f32(self) -> f32 {
+        self
+    }
+
+    fn from_f32(value: f32) -> Self {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn u8_round_trip() {
+        assert_eq!(0u8.to_f32(), 0.0);
+        assert_eq!(255u8.to_f32(), 1.0);
+        for value in 0..=u8::MAX {
+            assert_eq!(u8::from_f32(value.to_f32()), value);
+        }
+    }
+
+    #[test]
+    fn u16_from_f32() {
+        assert_eq!(u16::from_f32(0.5), 0x8000);
+        assert_eq!(u16::from_f32(-1.0), 0);
+        assert_eq!(u16::from_f32(2.0), u16::MAX);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn i8_snorm() {
+        assert_eq!(0i8.to_f32(), 0.0);
+        assert_eq!(127i8.to_f32(), 1.0);
+        assert_eq!((-127i8).to_f32(), -1.0);
+        assert_eq!((-128i8).to_f32(), -1.0);
+        assert_eq!(i8::from_f32(-2.0), -127);
+        assert_eq!(i8::from_f32(0.5), 64);
+        assert_eq!(i8::from_f32(-0.5), -64);
+        for value in -127..=i8::MAX {
+            assert_eq!(i8::from_f32(value.to_f32()), value);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn f32_passthrough() {
+        assert_eq!(f32::from_f32(1.5), 1.5);
+        assert_eq!((-0.5f32).to_f32(), -0.5);
+    }
+}
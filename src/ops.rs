@@ -0,0 +1,235 @@
+//! Channel-wise arithmetic on pixels.
+//!
+//! Pixels with integer channels implement [`Add`], [`Sub`], and [`Mul`] by a scalar (as well as
+//! their assigning variants), applied to every channel independently (including alpha) with
+//! _saturating_ semantics, so results are clamped to the channel's range instead of wrapping:
+//!
+//! ```rust
+//! use pxlfmt::{formats::rgba::Rgba8888, pixel::Pixel};
+//!
+//! let base = Pixel::<Rgba8888>::with_rgba(0x80, 0xF0, 0x10, 0xFF);
+//! let glow = Pixel::<Rgba8888>::with_rgba(0x40, 0x40, 0x40, 0x00);
+//!
+//! assert_eq!(base + glow, Pixel::with_rgba(0xC0, 0xFF, 0x50, 0xFF));
+//! assert_eq!(base - glow, Pixel::with_rgba(0x40, 0xB0, 0x00, 0xFF));
+//! assert_eq!(glow * 3, Pixel::with_rgba(0xC0, 0xC0, 0xC0, 0x00));
+//! ```
+//!
+//! Channels saturate at the bounds of the bits they are stored in, as described by
+//! [`Format::channel_descriptor`], so formats that store fewer bits than their channel type (such
+//! as [`Rgb565`](crate::formats::rgb::Rgb565) or [`P010`](crate::formats::yuv::P010)) saturate at
+//! their own maximum rather than wrapping.
+
+use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::{
+    internal::Sealed,
+    pixel::{Format, Pixel, raw::RawPixel},
+};
+
+/// A channel type that supports saturating arithmetic.
+///
+/// This trait is _sealed_, and is implemented for `u8`, `u16`, `i8`, and `i16`.
+#[allow(private_bounds)]
+pub trait SaturatingChannel: Sealed + Sized + Copy {
+    /// Adds two channel values, saturating at the bounds of the type.
+    #[must_use]
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Subtracts two channel values, saturating at the bounds of the type.
+    #[must_use]
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    /// Multiplies two channel values, saturating at the bounds of the type.
+    #[must_use]
+    fn saturating_mul(self, rhs: Self) -> Self;
+
+    /// Clamps the value to the range of a channel stored in `bits` bits.
+    #[must_use]
+    fn saturate_to_bits(self, bits: u32) -> Self;
+}
+
+impl Sealed for i16 {}
+
+macro_rules! impl_saturating_channel {
+  ($($t:ty),*) => {
+    $(
+      impl SaturatingChannel for $t {
+        fn saturating_add(self, rhs: Self) -> Self {
+          <$t>::saturating_add(self, rhs)
+        }
+
+        fn saturating_sub(self, rhs: Self) -> Self {
+          <$t>::saturating_sub(self, rhs)
+        }
+
+        fn saturating_mul(self, rhs: Self) -> Self {
+          <$t>::saturating_mul(self, rhs)
+        }
+
+        fn saturate_to_bits(self, bits: u32) -> Self {
+          let shift = <$t>::BITS.saturating_sub(bits);
+          self.clamp(<$t>::MIN >> shift, <$t>::MAX >> shift)
+        }
+      }
+    )*
+  };
+}
+
+impl_saturating_channel!(u8, u16, i8, i16);
+
+/// Returns a new pixel with `f` applied to the offset and value of every channel, clamping each
+/// result to the range of the bits the format stores it in before it is stored.
+fn saturating_map<F>(
+    mut pixel: Pixel<F>,
+    mut f: impl FnMut(usize, <F::RawPixel as RawPixel>::Channel) -> <F::RawPixel as RawPixel>::Channel,
+) -> Pixel<F>
+where
+    F: Format,
+    F::RawPixel: RawPixel<Channel: SaturatingChannel>,
+{
+    for offset in 0..F::CHANNEL_COUNT {
+        let bits = F::channel_descriptor(offset).bits;
+        let value = f(offset, pixel.as_raw().get_channel(offset)).saturate_to_bits(bits);
+        pixel.as_raw_mut().set_channel(offset, value);
+    }
+    pixel
+}
+
+impl<F> AddAssign for Pixel<F>
+where
+    F: Format,
+    F::RawPixel: RawPixel<Channel: SaturatingChannel>,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = saturating_map(*self, |offset, c| {
+            c.saturating_add(rhs.as_raw().get_channel(offset))
+        });
+    }
+}
+
+impl<F> Add for Pixel<F>
+where
+    F: Format,
+    F::RawPixel: RawPixel<Channel: SaturatingChannel>,
+{
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<F> SubAssign for Pixel<F>
+where
+    F: Format,
+    F::RawPixel: RawPixel<Channel: SaturatingChannel>,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = saturating_map(*self, |offset, c| {
+            c.saturating_sub(rhs.as_raw().get_channel(offset))
+        });
+    }
+}
+
+impl<F> Sub for Pixel<F>
+where
+    F: Format,
+    F::RawPixel: RawPixel<Channel: SaturatingChannel>,
+{
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<F> MulAssign<<F::RawPixel as RawPixel>::Channel> for Pixel<F>
+where
+    F: Format,
+    F::RawPixel: RawPixel<Channel: SaturatingChannel>,
+{
+    fn mul_assign(&mut self, rhs: <F::RawPixel as RawPixel>::Channel) {
+        *self = saturating_map(*self, |_, c| c.saturating_mul(rhs));
+    }
+}
+
+impl<F> Mul<<F::RawPixel as RawPixel>::Channel> for Pixel<F>
+where
+    F: Format,
+    F::RawPixel: RawPixel<Channel: SaturatingChannel>,
+{
+    type Output = Self;
+
+    fn mul(mut self, rhs: <F::RawPixel as RawPixel>::Channel) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::{
+        gray::{Gray4, Gray16},
+        rgb::Rgb565,
+        ycocg::YcocgR,
+        yuv::P010,
+    };
+
+    use super::*;
+
+    #[test]
+    fn unsigned_saturates() {
+        let a = Pixel::<Gray16>::with_gray(0xFF00);
+        let b = Pixel::<Gray16>::with_gray(0x0200);
+        assert_eq!((a + b).gray(), 0xFFFF);
+        assert_eq!((b - a).gray(), 0x0000);
+        assert_eq!((a - b).gray(), 0xFD00);
+        assert_eq!((b * 0x100).gray(), 0xFFFF);
+    }
+
+    #[test]
+    fn signed_saturates() {
+        let mut pixel = Pixel::<YcocgR>::with_ycocga(0x100, i16::MAX, i16::MIN, 0x10);
+        pixel += Pixel::with_ycocga(0x100, 1, -1, 0x10);
+        assert_eq!(
+            pixel.as_raw().into_inner(),
+            [0x200, i16::MAX, i16::MIN, 0x20]
+        );
+
+        pixel *= -2;
+        assert_eq!(
+            pixel.as_raw().into_inner(),
+            [-0x400, i16::MIN, i16::MAX, -0x40]
+        );
+
+        pixel -= Pixel::with_ycocga(0, 1, -1, 0);
+        assert_eq!(
+            pixel.as_raw().into_inner(),
+            [-0x400, i16::MIN, i16::MAX, -0x40]
+        );
+    }
+
+    #[test]
+    fn packed_saturates_at_channel_width() {
+        let red = Pixel::<Rgb565>::with_rgb(0x1F, 0x3E, 0x00);
+        let sum = red + Pixel::<Rgb565>::with_rgb(0x01, 0x01, 0x01);
+        assert_eq!((sum.red(), sum.green(), sum.blue()), (0x1F, 0x3F, 0x01));
+        assert_eq!((red * 2).green(), 0x3F);
+
+        let gray = Pixel::<Gray4>::with_gray(0x0F);
+        assert_eq!((gray + Pixel::with_gray(0x01)).gray(), 0x0F);
+        assert_eq!((gray * 3).gray(), 0x0F);
+        assert_eq!((Pixel::<Gray4>::with_gray(0x01) - gray).gray(), 0x00);
+    }
+
+    #[test]
+    fn msb_aligned_saturates_at_channel_width() {
+        let pixel = Pixel::<P010>::with_ycbcr(0x3FF, 0x200, 0x001);
+        let sum = pixel + Pixel::with_ycbcr(0x001, 0x200, 0x001);
+        assert_eq!(sum.as_raw().into_inner(), [0xFFC0, 0xFFC0, 0x0080]);
+        assert_eq!((pixel * 4).as_raw().into_inner(), [0xFFC0, 0xFFC0, 0x0100]);
+    }
+}
@@ -0,0 +1,272 @@
+//! Image orientation, as described by the EXIF `Orientation` tag.
+//!
+//! Cameras usually store pixels in the sensor's orientation, and record how the image should be
+//! rotated or flipped for display in metadata. [`Orientation`] represents that metadata exactly,
+//! so that it can be carried (and [composed](Orientation::compose) with further rotations or flips)
+//! through a pipeline, and [applied](Orientation::apply) to the pixels once:
+//!
+//! ```rust
+//! use pxlfmt::orient::Orientation;
+//!
+//! // A 3x2 image, stored sideways.
+//! let src = [1, 2, 3, 4, 5, 6];
+//! let orientation = Orientation::from_exif(6).unwrap_or_default();
+//! assert_eq!(orientation, Orientation::Rotate90);
+//!
+//! let (width, height) = orientation.oriented_size(3, 2);
+//! assert_eq!((width, height), (2, 3));
+//!
+//! let mut dst = [0; 6];
+//! orientation.apply(&src, 3, 2, &mut dst);
+//! assert_eq!(dst, [4, 1, 5, 2, 6, 3]);
+//! ```
+
+/// One of the eight orientations of an image, with the values of the EXIF `Orientation` tag.
+///
+/// Each orientation describes the transformation from the stored pixels to the displayed image.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[repr(u8)]
+pub enum Orientation {
+    /// Displayed as stored.
+    #[default]
+    Normal = 1,
+
+    /// Mirrored horizontally.
+    FlipHorizontal = 2,
+
+    /// Rotated 180 degrees.
+    Rotate180 = 3,
+
+    /// Mirrored vertically.
+    FlipVertical = 4,
+
+    /// Mirrored across the top-left to bottom-right diagonal.
+    Transpose = 5,
+
+    /// Rotated 90 degrees clockwise.
+    Rotate90 = 6,
+
+    /// Mirrored across the top-right to bottom-left diagonal.
+    Transverse = 7,
+
+    /// Rotated 270 degrees clockwise (90 degrees counter-clockwise).
+    Rotate270 = 8,
+}
+
+impl Orientation {
+    /// All orientations, in EXIF order.
+    pub const ALL: [Self; 8] = [
+        Self::Normal,
+        Self::FlipHorizontal,
+        Self::Rotate180,
+        Self::FlipVertical,
+        Self::Transpose,
+        Self::Rotate90,
+        Self::Transverse,
+        Self::Rotate270,
+    ];
+
+    /// Returns the orientation for a value of the EXIF `Orientation` tag.
+    ///
+    /// Returns `None` if the value is not in `1..=8`.
+    #[must_use]
+    pub const fn from_exif(value: u16) -> Option<Self> {
+        match value {
+            1..=8 => Some(Self::ALL[value as usize - 1]),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the EXIF `Orientation` tag for this orientation.
+    #[must_use]
+    pub const fn to_exif(self) -> u16 {
+        self as u16
+    }
+
+    /// Returns the orientation as a transpose followed by horizontal and vertical flips.
+    const fn to_parts(self) -> (bool, bool, bool) {
+        match self {
+            Self::Normal => (false, false, false),
+            Self::FlipHorizontal => (false, true, false),
+            Self::Rotate180 => (false, true, true),
+            Self::FlipVertical => (false, false, true),
+            Self::Transpose => (true, false, false),
+            Self::Rotate90 => (true, true, false),
+            Self::Transverse => (true, true, true),
+            Self::Rotate270 => (true, false, true),
+        }
+    }
+
+    /// Returns the orientation for a transpose followed by horizontal and vertical flips.
+    const fn from_parts(parts: (bool, bool, bool)) -> Self {
+        match parts {
+            (false, false, false) => Self::Normal,
+            (false, true, false) => Self::FlipHorizontal,
+            (false, true, true) => Self::Rotate180,
+            (false, false, true) => Self::FlipVertical,
+            (true, false, false) => Self::Transpose,
+            (true, true, false) => Self::Rotate90,
+            (true, true, true) => Self::Transverse,
+            (true, false, true) => Self::Rotate270,
+        }
+    }
+
+    /// Returns whether the orientation swaps the width and height of the image.
+    #[must_use]
+    pub const fn swaps_dimensions(self) -> bool {
+        self.to_parts().0
+    }
+
+    /// Returns the displayed `(width, height)` of a stored image of the given size.
+    #[must_use]
+    pub const fn oriented_size(self, width: usize, height: usize) -> (usize, usize) {
+        if self.swaps_dimensions() {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    /// Returns the orientation equivalent to applying `self`, and then `then`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::orient::Orientation;
+    ///
+    /// let rotated = Orientation::Rotate90.compose(Orientation::Rotate90);
+    /// assert_eq!(rotated, Orientation::Rotate180);
+    /// ```
+    #[must_use]
+    pub const fn compose(self, then: Self) -> Self {
+        let (s1, x1, y1) = self.to_parts();
+        let (s2, x2, y2) = then.to_parts();
+
+        // Flipping before a transpose is the same as flipping the other axis after it.
+        let (x1, y1) = if s2 { (y1, x1) } else { (x1, y1) };
+        Self::from_parts((s1 ^ s2, x1 ^ x2, y1 ^ y2))
+    }
+
+    /// Returns the orientation that undoes this orientation.
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        match self {
+            Self::Rotate90 => Self::Rotate270,
+            Self::Rotate270 => Self::Rotate90,
+            other => other,
+        }
+    }
+
+    /// Returns the position in the stored image of the pixel displayed at (`x`, `y`).
+    ///
+    /// `width` and `height` are the dimensions of the stored image.
+    #[must_use]
+    pub const fn source_position(
+        self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> (usize, usize) {
+        let (transpose, flip_x, flip_y) = self.to_parts();
+        let (width, height) = self.oriented_size(width, height);
+        let x = if flip_x { width - 1 - x } else { x };
+        let y = if flip_y { height - 1 - y } else { y };
+        if transpose { (y, x) } else { (x, y) }
+    }
+
+    /// Copies the stored image `src` into `dst`, in displayed orientation.
+    ///
+    /// Both images are tightly packed rows of pixels; `width` and `height` are the dimensions of
+    /// `src`, and `dst` has the dimensions returned by [`Orientation::oriented_size`].
+    ///
+    /// ## Panics
+    ///
+    /// If `src` or `dst` is not exactly `width * height` pixels.
+    pub fn apply<T: Copy>(self, src: &[T], width: usize, height: usize, dst: &mut [T]) {
+        assert_eq!(
+            src.len(),
+            width * height,
+            "Source size does not match dimensions"
+        );
+        assert_eq!(
+            dst.len(),
+            src.len(),
+            "Destination size does not match source"
+        );
+
+        let (dst_width, _) = self.oriented_size(width, height);
+        for (i, pixel) in dst.iter_mut().enumerate() {
+            let (x, y) = self.source_position(i % dst_width, i / dst_width, width, height);
+            *pixel = src[y * width + x];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 3x2 image, with each pixel identified by its stored position.
+    const SRC: [u8; 6] = [1, 2, 3, 4, 5, 6];
+
+    fn apply(orientation: Orientation) -> [u8; 6] {
+        let mut dst = [0; 6];
+        orientation.apply(&SRC, 3, 2, &mut dst);
+        dst
+    }
+
+    #[test]
+    fn exif_round_trip() {
+        for orientation in Orientation::ALL {
+            assert_eq!(
+                Orientation::from_exif(orientation.to_exif()),
+                Some(orientation)
+            );
+        }
+        assert_eq!(Orientation::from_exif(0), None);
+        assert_eq!(Orientation::from_exif(9), None);
+    }
+
+    #[test]
+    fn apply_each() {
+        // 1 2 3
+        // 4 5 6
+        assert_eq!(apply(Orientation::Normal), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(apply(Orientation::FlipHorizontal), [3, 2, 1, 6, 5, 4]);
+        assert_eq!(apply(Orientation::Rotate180), [6, 5, 4, 3, 2, 1]);
+        assert_eq!(apply(Orientation::FlipVertical), [4, 5, 6, 1, 2, 3]);
+        assert_eq!(apply(Orientation::Transpose), [1, 4, 2, 5, 3, 6]);
+        assert_eq!(apply(Orientation::Rotate90), [4, 1, 5, 2, 6, 3]);
+        assert_eq!(apply(Orientation::Transverse), [6, 3, 5, 2, 4, 1]);
+        assert_eq!(apply(Orientation::Rotate270), [3, 6, 2, 5, 1, 4]);
+    }
+
+    #[test]
+    fn compose_matches_apply() {
+        for a in Orientation::ALL {
+            for b in Orientation::ALL {
+                let (width, height) = a.oriented_size(3, 2);
+                let mut expected = [0; 6];
+                b.apply(&apply(a), width, height, &mut expected);
+                assert_eq!(apply(a.compose(b)), expected, "{a:?} then {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        for orientation in Orientation::ALL {
+            assert_eq!(
+                orientation.compose(orientation.inverse()),
+                Orientation::Normal
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Destination size does not match source")]
+    fn apply_size_mismatch() {
+        Orientation::Normal.apply(&SRC, 3, 2, &mut [0; 5]);
+    }
+}
@@ -0,0 +1,200 @@
+//! Fused per-pixel operations.
+//!
+//! Applying several per-pixel operations to a large buffer one after another traverses the
+//! buffer once per operation, which quickly becomes memory-bound. A [`Builder`] instead composes
+//! the operations at compile time into a single [`Pipeline`], which applies all of them to each
+//! pixel in one traversal:
+//!
+//! ```rust
+//! use pxlfmt::{formats::rgba::Rgba8888, pipeline::Builder, pixel::Pixel};
+//!
+//! let invert = |pixel: Pixel<Rgba8888>| {
+//!     Pixel::with_rgba(!pixel.red(), !pixel.green(), !pixel.blue(), pixel.alpha())
+//! };
+//! let opaque = |mut pixel: Pixel<Rgba8888>| {
+//!     pixel.set_alpha(0xFF);
+//!     pixel
+//! };
+//!
+//! let pipeline = Builder::new().then(invert).then(opaque).build();
+//!
+//! let mut pixels = [Pixel::<Rgba8888>::with_rgba(0x00, 0x80, 0xFF, 0x00); 4];
+//! pipeline.run(&mut pixels);
+//! assert_eq!(pixels[3], Pixel::with_rgba(0xFF, 0x7F, 0x00, 0xFF));
+//! ```
+//!
+//! Each operation is a closure (or any other type implementing [`Op`]) that takes a pixel and
+//! returns the transformed pixel. Since the composed pipeline is a concrete type, the compiler can
+//! inline every operation into the loop.
+
+use core::marker::PhantomData;
+
+use crate::pixel::{Format, Pixel};
+
+/// A per-pixel operation.
+///
+/// Implemented for every closure that takes and returns a [`Pixel`] of the same format.
+pub trait Op<F: Format> {
+    /// Applies the operation to a single pixel.
+    #[must_use]
+    fn apply(&self, pixel: Pixel<F>) -> Pixel<F>;
+}
+
+impl<F: Format, T: Fn(Pixel<F>) -> Pixel<F>> Op<F> for T {
+    fn apply(&self, pixel: Pixel<F>) -> Pixel<F> {
+        self(pixel)
+    }
+}
+
+/// The operation that returns the pixel unchanged; the start of every [`Builder`].
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Identity;
+
+impl<F: Format> Op<F> for Identity {
+    fn apply(&self, pixel: Pixel<F>) -> Pixel<F> {
+        pixel
+    }
+}
+
+/// An operation that applies `A`, and then `B` to the result.
+///
+/// Created by [`Builder::then`].
+#[derive(Copy, Clone, Debug)]
+pub struct Then<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<F: Format, A: Op<F>, B: Op<F>> Op<F> for Then<A, B> {
+    fn apply(&self, pixel: Pixel<F>) -> Pixel<F> {
+        self.second.apply(self.first.apply(pixel))
+    }
+}
+
+/// Composes per-pixel operations into a [`Pipeline`].
+#[derive(Copy, Clone)]
+pub struct Builder<F: Format, O: Op<F> = Identity> {
+    op: O,
+    format: PhantomData<F>,
+}
+
+impl<F: Format, O: Op<F>> core::fmt::Debug for Builder<F, O> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Builder").finish_non_exhaustive()
+    }
+}
+
+impl<F: Format> Builder<F> {
+    /// Creates a new builder, without any operations.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            op: Identity,
+            format: PhantomData,
+        }
+    }
+}
+
+impl<F: Format> Default for Builder<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Format, O: Op<F>> Builder<F, O> {
+    /// Adds an operation, applied after all previously added operations.
+    #[must_use]
+    pub fn then<P: Op<F>>(self, op: P) -> Builder<F, Then<O, P>> {
+        Builder {
+            op: Then {
+                first: self.op,
+                second: op,
+            },
+            format: PhantomData,
+        }
+    }
+
+    /// Finishes composing operations.
+    #[must_use]
+    pub fn build(self) -> Pipeline<F, O> {
+        Pipeline {
+            op: self.op,
+            format: PhantomData,
+        }
+    }
+}
+
+/// A composed sequence of per-pixel operations.
+///
+/// Created by [`Builder::build`].
+#[derive(Copy, Clone)]
+pub struct Pipeline<F: Format, O: Op<F>> {
+    op: O,
+    format: PhantomData<F>,
+}
+
+impl<F: Format, O: Op<F>> core::fmt::Debug for Pipeline<F, O> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pipeline").finish_non_exhaustive()
+    }
+}
+
+impl<F: Format, O: Op<F>> Pipeline<F, O> {
+    /// Applies every operation, in order, to a single pixel.
+    #[must_use]
+    pub fn apply(&self, pixel: Pixel<F>) -> Pixel<F> {
+        self.op.apply(pixel)
+    }
+
+    /// Applies every operation, in order, to each pixel in `pixels`, in a single traversal.
+    pub fn run(&self, pixels: &mut [Pixel<F>]) {
+        for pixel in pixels {
+            *pixel = self.op.apply(*pixel);
+        }
+    }
+}
+
+impl<F: Format, O: Op<F>> Op<F> for Pipeline<F, O> {
+    fn apply(&self, pixel: Pixel<F>) -> Pixel<F> {
+        self.op.apply(pixel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{FloatRgba, Rgba8888};
+
+    use super::*;
+
+    #[test]
+    fn empty_pipeline_is_identity() {
+        let pipeline = Builder::<Rgba8888>::new().build();
+        let pixel = Pixel::with_rgba(1, 2, 3, 4);
+        assert_eq!(pipeline.apply(pixel), pixel);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn operations_apply_in_order() {
+        let double = |mut pixel: Pixel<FloatRgba>| {
+            pixel.set_red(pixel.red() * 2.0);
+            pixel
+        };
+        let add = |mut pixel: Pixel<FloatRgba>| {
+            pixel.set_red(pixel.red() + 1.0);
+            pixel
+        };
+
+        let pipeline = Builder::new().then(double).then(add).build();
+        assert_eq!(
+            pipeline.apply(Pixel::with_rgba(1.0, 0.0, 0.0, 0.0)).red(),
+            3.0
+        );
+
+        // Pipelines are operations themselves, and can be nested.
+        let nested = Builder::new().then(add).then(pipeline).build();
+        let mut pixels = [Pixel::with_rgba(1.0, 0.0, 0.0, 0.0); 2];
+        nested.run(&mut pixels);
+        assert_eq!(pixels[1].red(), 5.0);
+    }
+}
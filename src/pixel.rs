@@ -1,12 +1,19 @@
 //! [`Pixel`] is the organization and characteristics of pixel data in memory.
 
-use crate::pixel::raw::RawPixel;
+use crate::pixel::raw::{ChannelDescriptor, ChannelRef, PlainBytes, RawPixel};
 use core::{
     fmt::{LowerHex, UpperHex},
+    hash::{Hash, Hasher},
     marker::PhantomData,
+    ops::{Index, IndexMut},
 };
 
+#[cfg(feature = "alloc")]
+pub mod buffer;
+pub mod packed;
 pub mod raw;
+pub mod slice;
+pub mod view;
 
 /// Describes the organization and characteristics of pixel data in memory.
 #[allow(private_bounds)]
@@ -20,6 +27,74 @@ pub trait Format: 'static + Copy + crate::internal::Sealed {
     ///
     /// [`Rgba`]: crate::formats::rgba::Rgba
     type Channels: Copy + Eq + Ord;
+
+    /// The name of the format, which is the name of its type without generic parameters (e.g.
+    /// `"Rgba8888"`).
+    const NAME: &'static str;
+
+    /// The number of bits each pixel occupies in memory.
+    ///
+    /// Defaults to the size of the raw pixel. Formats that are packed several pixels to a byte
+    /// (see [`PackedFormat`](packed::PackedFormat)) occupy fewer bits, always a divisor of 8.
+    #[allow(clippy::cast_possible_truncation)]
+    const BITS_PER_PIXEL: u32 = (size_of::<Self::RawPixel>() * 8) as u32;
+
+    /// The number of channels of the raw pixel.
+    const CHANNEL_COUNT: usize = <Self::RawPixel as RawPixel>::CHANNELS;
+
+    /// Describes where the channel at the provided offset is stored within the raw pixel.
+    ///
+    /// Defaults to [`RawPixel::channel_descriptor`]. Packed formats describe the bits of the
+    /// unpacked pixel that are used.
+    ///
+    /// ## Panics
+    ///
+    /// If `offset` is not less than [`CHANNEL_COUNT`](Format::CHANNEL_COUNT).
+    #[must_use]
+    fn channel_descriptor(offset: usize) -> ChannelDescriptor {
+        Self::RawPixel::channel_descriptor(offset)
+    }
+
+    /// Describes where the given channel is stored within the raw pixel.
+    ///
+    /// This is the descriptor of the channel's [offset](ChannelOf::offset), for interop with APIs
+    /// that describe formats by channel masks and shifts.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{
+    ///     formats::rgba::{Rgba, Rgba16161616},
+    ///     pixel::{Format, raw::ChannelDescriptor},
+    /// };
+    ///
+    /// let blue = Rgba16161616::describe(Rgba::Blue);
+    /// assert_eq!(blue, ChannelDescriptor { shift: 32, bits: 16 });
+    /// assert_eq!(blue.mask(), Some(0xFFFF_0000_0000));
+    /// ```
+    #[must_use]
+    fn describe(channel: Self::Channels) -> ChannelDescriptor
+    where
+        Self::Channels: ChannelOf<Self>,
+    {
+        Self::channel_descriptor(channel.offset())
+    }
+}
+
+/// A channel of a pixel format, identified by a variant of the format's [`Format::Channels`].
+///
+/// This allows code to be written over channel identities rather than accessor names, through the
+/// `get` and `set` methods of [`Pixel`]. It is implemented for the channel enums of each format
+/// family, such as [`Rgba`][] for every [`RgbaFormat`][].
+///
+/// [`Rgba`]: crate::formats::rgba::Rgba
+/// [`RgbaFormat`]: crate::formats::rgba::RgbaFormat
+pub trait ChannelOf<F: Format>: 'static + Copy {
+    /// Every channel of the format, in the order of the channel enum.
+    const ALL: &'static [Self];
+
+    /// Returns the offset of the channel in the format's raw pixel.
+    fn offset(self) -> usize;
 }
 
 /// A pixel value in a specific format.
@@ -42,6 +117,15 @@ pub trait Format: 'static + Copy + crate::internal::Sealed {
 /// assert_eq!(pixel.alpha(), 0xFF);
 /// ```
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
 #[repr(transparent)]
 pub struct Pixel<F: Format> {
     raw: F::RawPixel,
@@ -54,6 +138,16 @@ impl<F: Format> Default for Pixel<F> {
     }
 }
 
+impl<F> Hash for Pixel<F>
+where
+    F: Format,
+    F::RawPixel: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
 #[cfg(feature = "bytemuck")]
 unsafe impl<F> bytemuck::Zeroable for Pixel<F>
 where
@@ -70,6 +164,32 @@ where
 {
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, F> arbitrary::Arbitrary<'a> for Pixel<F>
+where
+    F: Format,
+    F::RawPixel: arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary().map(Self::from_raw)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        F::RawPixel::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<F> defmt::Format for Pixel<F>
+where
+    F: Format,
+    F::RawPixel: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "Pixel({})", self.raw);
+    }
+}
+
 impl<F: Format> Pixel<F> {
     /// Creates a new pixel by converting a value that can be converted into the raw pixel type.
     pub fn new(raw: impl Into<F::RawPixel>) -> Self {
@@ -105,6 +225,383 @@ impl<F: Format> Pixel<F> {
     pub fn into_raw(self) -> F::RawPixel {
         self.raw
     }
+
+    /// Returns the pixel's bytes in little-endian byte order, regardless of the host.
+    ///
+    /// See [`RawPixel::to_le_bytes`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::rgba::Rgba8888, pixel::Pixel};
+    ///
+    /// let pixel = Pixel::<Rgba8888>::new(0x4433_2211);
+    /// assert_eq!(pixel.to_le_bytes(), [0x11, 0x22, 0x33, 0x44]);
+    /// assert_eq!(pixel.to_be_bytes(), [0x44, 0x33, 0x22, 0x11]);
+    /// assert_eq!(Pixel::<Rgba8888>::from_le_bytes([0x11, 0x22, 0x33, 0x44]), pixel);
+    /// ```
+    pub fn to_le_bytes(self) -> <F::RawPixel as RawPixel>::Bytes {
+        self.raw.to_le_bytes()
+    }
+
+    /// Returns the pixel's bytes in big-endian byte order, regardless of the host.
+    ///
+    /// See [`RawPixel::to_be_bytes`].
+    pub fn to_be_bytes(self) -> <F::RawPixel as RawPixel>::Bytes {
+        self.raw.to_be_bytes()
+    }
+
+    /// Creates a new pixel from its bytes in little-endian byte order.
+    pub fn from_le_bytes(bytes: <F::RawPixel as RawPixel>::Bytes) -> Self {
+        Self::from_raw(F::RawPixel::from_le_bytes(bytes))
+    }
+
+    /// Creates a new pixel from its bytes in big-endian byte order.
+    pub fn from_be_bytes(bytes: <F::RawPixel as RawPixel>::Bytes) -> Self {
+        Self::from_raw(F::RawPixel::from_be_bytes(bytes))
+    }
+
+    /// Creates a new pixel with every channel set to the given value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::rgba::Rgba8888, pixel::Pixel};
+    ///
+    /// assert_eq!(Pixel::<Rgba8888>::splat(0x80), Pixel::with_rgba(0x80, 0x80, 0x80, 0x80));
+    /// ```
+    #[must_use]
+    pub fn splat(value: <F::RawPixel as RawPixel>::Channel) -> Self
+    where
+        <F::RawPixel as RawPixel>::Channel: Copy,
+    {
+        Self::from_raw(F::RawPixel::splat(value))
+    }
+
+    /// Returns a new pixel with `f` applied to every channel.
+    ///
+    /// Channels are visited in the raw pixel's order, which is not necessarily the format's
+    /// semantic (for example, RGBA) order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::rgba::Rgba8888, pixel::Pixel};
+    ///
+    /// let pixel = Pixel::<Rgba8888>::with_rgba(0x10, 0x20, 0x30, 0xFF);
+    /// let inverted = pixel.map_channels(|c| !c);
+    /// assert_eq!(inverted, Pixel::with_rgba(0xEF, 0xDF, 0xCF, 0x00));
+    /// ```
+    #[must_use]
+    pub fn map_channels(
+        mut self,
+        mut f: impl FnMut(<F::RawPixel as RawPixel>::Channel) -> <F::RawPixel as RawPixel>::Channel,
+    ) -> Self {
+        for i in 0..F::RawPixel::CHANNELS {
+            // SAFETY: `i` is less than the number of channels.
+            unsafe {
+                let value = f(self.raw.get_channel_unchecked(i));
+                self.raw.set_channel_unchecked(i, value);
+            }
+        }
+        self
+    }
+
+    /// Returns a new pixel with `f` applied to every pair of corresponding channels of the pixel
+    /// and `other`.
+    ///
+    /// Channels are visited in the raw pixel's order, which is not necessarily the format's
+    /// semantic (for example, RGBA) order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::rgba::Rgba8888, pixel::Pixel};
+    ///
+    /// let a = Pixel::<Rgba8888>::with_rgba(0x10, 0x80, 0x30, 0xFF);
+    /// let b = Pixel::<Rgba8888>::with_rgba(0x20, 0x40, 0x30, 0x00);
+    /// assert_eq!(a.zip_channels(&b, u8::max), Pixel::with_rgba(0x20, 0x80, 0x30, 0xFF));
+    /// ```
+    #[must_use]
+    pub fn zip_channels(
+        mut self,
+        other: &Self,
+        mut f: impl FnMut(
+            <F::RawPixel as RawPixel>::Channel,
+            <F::RawPixel as RawPixel>::Channel,
+        ) -> <F::RawPixel as RawPixel>::Channel,
+    ) -> Self {
+        for i in 0..F::RawPixel::CHANNELS {
+            // SAFETY: `i` is less than the number of channels.
+            unsafe {
+                let value = f(
+                    self.raw.get_channel_unchecked(i),
+                    other.raw.get_channel_unchecked(i),
+                );
+                self.raw.set_channel_unchecked(i, value);
+            }
+        }
+        self
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: Format,
+    F::Channels: ChannelOf<F>,
+{
+    /// Returns the value of the given channel.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::rgba::{Rgba, Rgba8888}, pixel::Pixel};
+    ///
+    /// let mut pixel = Pixel::<Rgba8888>::with_rgba(0x10, 0x20, 0x30, 0xFF);
+    /// assert_eq!(pixel.get(Rgba::Green), 0x20);
+    ///
+    /// pixel.set(Rgba::Blue, 0x80);
+    /// assert_eq!(pixel.blue(), 0x80);
+    /// ```
+    pub fn get(&self, channel: F::Channels) -> <F::RawPixel as RawPixel>::Channel {
+        self.raw.get_channel(channel.offset())
+    }
+
+    /// Returns an iterator over every channel of the pixel and its value.
+    ///
+    /// Channels are yielded in the order of [`ChannelOf::ALL`], regardless of the memory layout.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::rgba::{Abgr8888, Rgba}, pixel::Pixel};
+    ///
+    /// let pixel = Pixel::<Abgr8888>::with_rgba(0x10, 0x20, 0x30, 0xFF);
+    /// let mut channels = pixel.iter_channels();
+    /// assert_eq!(channels.next(), Some((Rgba::Red, 0x10)));
+    /// assert_eq!(channels.len(), 3);
+    /// assert_eq!(channels.last(), Some((Rgba::Alpha, 0xFF)));
+    /// ```
+    pub fn iter_channels(&self) -> ChannelIter<F> {
+        ChannelIter {
+            pixel: *self,
+            channels: F::Channels::ALL.iter(),
+        }
+    }
+
+    /// Sets the value of the given channel.
+    pub fn set(
+        &mut self,
+        channel: F::Channels,
+        value: <F::RawPixel as RawPixel>::Channel,
+    ) -> &mut Self {
+        self.raw.set_channel(channel.offset(), value);
+        self
+    }
+}
+
+/// Indexes the pixel by the channels of its format, for raw pixels that implement [`ChannelRef`].
+///
+/// ## Example
+///
+/// ```rust
+/// use pxlfmt::{formats::rgba::{FloatRgba, Rgba}, pixel::Pixel};
+///
+/// let mut pixel = Pixel::<FloatRgba>::with_rgba(0.25, 0.5, 0.75, 1.0);
+/// pixel[Rgba::Red] *= 2.0;
+/// assert_eq!(pixel[Rgba::Red], 0.5);
+/// ```
+impl<F> Index<F::Channels> for Pixel<F>
+where
+    F: Format,
+    F::Channels: ChannelOf<F>,
+    F::RawPixel: ChannelRef,
+{
+    type Output = <F::RawPixel as RawPixel>::Channel;
+
+    fn index(&self, channel: F::Channels) -> &Self::Output {
+        self.raw.channel_ref(channel.offset())
+    }
+}
+
+impl<F> IndexMut<F::Channels> for Pixel<F>
+where
+    F: Format,
+    F::Channels: ChannelOf<F>,
+    F::RawPixel: ChannelRef,
+{
+    fn index_mut(&mut self, channel: F::Channels) -> &mut Self::Output {
+        self.raw.channel_mut(channel.offset())
+    }
+}
+
+/// Byte views of pixels whose raw storage is [plain bytes](PlainBytes).
+///
+/// These are safe alternatives to `bytemuck` casts, available without any feature.
+///
+/// ## Example
+///
+/// ```rust
+/// use pxlfmt::{formats::rgba::Rgba8888Be, pixel::Pixel};
+///
+/// let bytes = [0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70, 0x80];
+/// let pixels = Pixel::<Rgba8888Be>::slice_from_bytes(&bytes).unwrap();
+/// assert_eq!(pixels.len(), 2);
+/// assert_eq!(pixels[0].as_bytes(), &bytes[..4]);
+/// assert_eq!(Pixel::slice_as_bytes(pixels), &bytes);
+/// ```
+impl<F> Pixel<F>
+where
+    F: Format,
+    F::RawPixel: PlainBytes,
+{
+    /// Returns the bytes of the pixel, in memory order.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        Self::slice_as_bytes(core::slice::from_ref(self))
+    }
+
+    /// Returns the mutable bytes of the pixel, in memory order.
+    #[must_use]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        Self::slice_as_bytes_mut(core::slice::from_mut(self))
+    }
+
+    /// Returns the pixel viewed from the given bytes, or `None` if the length does not match.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<&Self> {
+        match Self::slice_from_bytes(bytes)? {
+            [pixel] => Some(pixel),
+            _ => None,
+        }
+    }
+
+    /// Returns the mutable pixel viewed from the given bytes, or `None` if the length does not
+    /// match.
+    #[must_use]
+    pub fn from_bytes_mut(bytes: &mut [u8]) -> Option<&mut Self> {
+        match Self::slice_from_bytes_mut(bytes)? {
+            [pixel] => Some(pixel),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes of a slice of pixels, in memory order.
+    #[must_use]
+    pub fn slice_as_bytes(pixels: &[Self]) -> &[u8] {
+        // SAFETY: `PlainBytes` guarantees the raw pixel (and so the pixel) is only initialized
+        // bytes, with no padding.
+        unsafe { core::slice::from_raw_parts(pixels.as_ptr().cast(), size_of_val(pixels)) }
+    }
+
+    /// Returns the mutable bytes of a slice of pixels, in memory order.
+    #[must_use]
+    pub fn slice_as_bytes_mut(pixels: &mut [Self]) -> &mut [u8] {
+        let len = size_of_val(pixels);
+        // SAFETY: As for `slice_as_bytes`; additionally, every bit pattern is a valid pixel, so any
+        // bytes written are.
+        unsafe { core::slice::from_raw_parts_mut(pixels.as_mut_ptr().cast(), len) }
+    }
+
+    /// Returns the pixels viewed from the given bytes.
+    ///
+    /// Returns `None` if the length is not a multiple of the size of a pixel.
+    #[must_use]
+    pub fn slice_from_bytes(bytes: &[u8]) -> Option<&[Self]> {
+        if !bytes.len().is_multiple_of(size_of::<Self>()) {
+            return None;
+        }
+        // SAFETY: `PlainBytes` guarantees an alignment of `1`, and that every bit pattern is a
+        // valid pixel; the length is a whole number of pixels.
+        let pixels = unsafe {
+            core::slice::from_raw_parts(bytes.as_ptr().cast(), bytes.len() / size_of::<Self>())
+        };
+        Some(pixels)
+    }
+
+    /// Returns the mutable pixels viewed from the given bytes.
+    ///
+    /// Returns `None` if the length is not a multiple of the size of a pixel.
+    #[must_use]
+    pub fn slice_from_bytes_mut(bytes: &mut [u8]) -> Option<&mut [Self]> {
+        if !bytes.len().is_multiple_of(size_of::<Self>()) {
+            return None;
+        }
+        let len = bytes.len() / size_of::<Self>();
+        // SAFETY: As for `slice_from_bytes`.
+        let pixels = unsafe { core::slice::from_raw_parts_mut(bytes.as_mut_ptr().cast(), len) };
+        Some(pixels)
+    }
+}
+
+/// An iterator over the channels of a pixel and their values.
+///
+/// Created by [`Pixel::iter_channels`].
+pub struct ChannelIter<F: Format>
+where
+    F::Channels: 'static,
+{
+    pixel: Pixel<F>,
+    channels: core::slice::Iter<'static, F::Channels>,
+}
+
+impl<F> Clone for ChannelIter<F>
+where
+    F: Format,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pixel: self.pixel,
+            channels: self.channels.clone(),
+        }
+    }
+}
+
+impl<F> core::fmt::Debug for ChannelIter<F>
+where
+    F: Format,
+    F::Channels: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChannelIter")
+            .field("remaining", &self.channels.as_slice())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<F> Iterator for ChannelIter<F>
+where
+    F: Format,
+    F::Channels: ChannelOf<F>,
+{
+    type Item = (F::Channels, <F::RawPixel as RawPixel>::Channel);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let channel = *self.channels.next()?;
+        Some((channel, self.pixel.get(channel)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.channels.size_hint()
+    }
+}
+
+impl<F> DoubleEndedIterator for ChannelIter<F>
+where
+    F: Format,
+    F::Channels: ChannelOf<F>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let channel = *self.channels.next_back()?;
+        Some((channel, self.pixel.get(channel)))
+    }
+}
+
+impl<F> ExactSizeIterator for ChannelIter<F>
+where
+    F: Format,
+    F::Channels: ChannelOf<F>,
+{
 }
 
 impl<F> AsRef<F::RawPixel> for Pixel<F>
@@ -152,6 +649,28 @@ mod tests {
     use super::*;
     use alloc::format;
 
+    #[test]
+    fn format_metadata() {
+        use crate::formats::{gray::Gray4, rgba::Rgba16161616};
+
+        assert_eq!(Rgba16161616::NAME, "Rgba16161616");
+        assert_eq!(Rgba16161616::BITS_PER_PIXEL, 64);
+        assert_eq!(Rgba16161616::CHANNEL_COUNT, 4);
+        assert_eq!(
+            Rgba16161616::channel_descriptor(2),
+            ChannelDescriptor {
+                shift: 32,
+                bits: 16
+            }
+        );
+
+        assert_eq!((Gray4::BITS_PER_PIXEL, Gray4::CHANNEL_COUNT), (4, 1));
+        assert_eq!(
+            Gray4::channel_descriptor(0),
+            ChannelDescriptor { shift: 0, bits: 4 }
+        );
+    }
+
     #[test]
     fn upper_hex() {
         let pixel = Pixel::<crate::formats::rgba::Rgba8888>::new(0xFF00_00FF);
@@ -183,6 +702,68 @@ mod tests {
         assert_eq!(pixels_back[0].as_raw().into_inner(), 0xFF00_00FF);
     }
 
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut u = Unstructured::new(&[0x01, 0x02, 0x03, 0x04]);
+        let pixel = Pixel::<crate::formats::rgba::Rgba8888>::arbitrary(&mut u).unwrap();
+        assert_eq!(
+            pixel.as_raw().into_inner(),
+            u32::from_le_bytes([0x01, 0x02, 0x03, 0x04])
+        );
+        assert_eq!(
+            Pixel::<crate::formats::rgba::Rgba8888>::size_hint(0),
+            (4, Some(4))
+        );
+    }
+
+    #[test]
+    fn hash_matches_raw() {
+        use core::hash::BuildHasher;
+
+        // A fixed-seed hasher, so the test does not depend on `std`'s `RandomState`.
+        #[derive(Default)]
+        struct Fnv(u64);
+
+        impl Hasher for Fnv {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for byte in bytes {
+                    self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01B3);
+                }
+            }
+        }
+
+        let hasher = core::hash::BuildHasherDefault::<Fnv>::default();
+        let pixel = Pixel::<crate::formats::rgba::Rgba8888>::with_rgba(0xFF, 0x00, 0x00, 0xFF);
+        assert_eq!(hasher.hash_one(pixel), hasher.hash_one(pixel.into_raw()));
+        let mut other = pixel;
+        other.set_red(0x00);
+        assert_ne!(hasher.hash_one(pixel), hasher.hash_one(other));
+    }
+
+    #[test]
+    fn byte_views() {
+        use crate::formats::rgba::Rgba8888Le;
+
+        let mut bytes = [0x10, 0x20, 0x30, 0x40, 0x50, 0x60, 0x70, 0x80];
+        assert!(Pixel::<Rgba8888Le>::slice_from_bytes(&bytes[..7]).is_none());
+        assert!(Pixel::<Rgba8888Le>::from_bytes(&bytes).is_none());
+
+        let pixel = Pixel::<Rgba8888Le>::from_bytes(&bytes[4..]).unwrap();
+        assert_eq!(pixel.red(), 0x50);
+
+        let pixels = Pixel::<Rgba8888Le>::slice_from_bytes_mut(&mut bytes).unwrap();
+        pixels[1].set_red(0x00);
+        pixels[0].as_bytes_mut()[3] = 0x00;
+        assert_eq!(bytes, [0x10, 0x20, 0x30, 0x00, 0x00, 0x60, 0x70, 0x80]);
+    }
+
     #[test]
     fn into_raw() {
         let pixel = Pixel::<crate::formats::rgba::Rgba8888>::with_rgba(0xFF, 0x00, 0x00, 0xFF);
@@ -215,6 +796,131 @@ mod tests {
         assert_eq!(pixel.as_raw().into_inner(), 0xFF00_0001);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn map_and_zip_channels() {
+        let pixel = Pixel::<crate::formats::rgba::FloatRgba>::with_rgba(0.25, 0.5, 1.0, 0.0);
+        let doubled = pixel.map_channels(|c| c * 2.0);
+        assert_eq!(doubled.as_raw().into_inner(), [0.5, 1.0, 2.0, 0.0]);
+
+        let mut visited = 0;
+        let summed = pixel.zip_channels(&doubled, |a, b| {
+            visited += 1;
+            a + b
+        });
+        assert_eq!(summed.as_raw().into_inner(), [0.75, 1.5, 3.0, 0.0]);
+        assert_eq!(visited, 4);
+    }
+
+    #[test]
+    fn get_and_set_by_channel() {
+        use crate::formats::{
+            cmyk::{Cmyk, Cmyk8888},
+            rgba::{Abgr8888, Rgba},
+        };
+
+        let mut pixel = Pixel::<Abgr8888>::with_rgba(0x10, 0x20, 0x30, 0x40);
+        assert_eq!(pixel.get(Rgba::Red), 0x10);
+        assert_eq!(pixel.get(Rgba::Alpha), 0x40);
+        pixel.set(Rgba::Red, 0x50).set(Rgba::Alpha, 0x60);
+        assert_eq!(pixel.channels(), [0x50, 0x20, 0x30, 0x60]);
+
+        let mut cmyk = Pixel::<Cmyk8888>::zeroed();
+        cmyk.set(Cmyk::Black, 0xFF);
+        assert_eq!(cmyk.get(Cmyk::Black), 0xFF);
+        assert_eq!(cmyk.get(Cmyk::Cyan), 0x00);
+    }
+
+    #[test]
+    fn get_and_set_in_every_family() {
+        use core::fmt::Debug;
+
+        use crate::formats::{
+            bayer::BayerRggb8,
+            depth::{D16, D24S8, D32F},
+            hdr::{R11G11B10F, Rgb9e5},
+            rg::Rg8Snorm,
+            xyz::XyzaF32,
+            ycocg::YcocgR,
+            yuv::{Uyvy422, Yuyv422},
+        };
+
+        fn check<F: Format>(value: <F::RawPixel as RawPixel>::Channel)
+        where
+            F::Channels: ChannelOf<F> + Debug + PartialEq,
+            <F::RawPixel as RawPixel>::Channel: Copy + Debug + PartialEq,
+        {
+            for &channel in F::Channels::ALL {
+                let mut pixel = Pixel::<F>::zeroed();
+                pixel.set(channel, value);
+                assert_eq!(pixel.get(channel), value, "{} {channel:?}", F::NAME);
+                assert_eq!(
+                    F::describe(channel),
+                    F::channel_descriptor(channel.offset())
+                );
+                assert_eq!(
+                    pixel.iter_channels().find(|&(_, v)| v == value),
+                    Some((channel, value))
+                );
+            }
+        }
+
+        check::<Yuyv422>(3);
+        check::<Uyvy422>(3);
+        check::<Rg8Snorm>(-3);
+        check::<XyzaF32>(3.0);
+        check::<YcocgR>(-3);
+        check::<Rgb9e5>(3);
+        check::<R11G11B10F>(3);
+        check::<BayerRggb8>(3);
+        check::<D16>(3);
+        check::<D24S8>(3);
+        check::<D32F>(3.0);
+    }
+
+    #[test]
+    fn index_by_channel() {
+        use crate::formats::{
+            gray::{Gray, Gray16},
+            rgba::{Abgr8888Be, Rgba, Rgba16161616},
+        };
+
+        let mut wide = Pixel::<Rgba16161616>::with_rgba(1, 2, 3, 4);
+        assert_eq!(wide[Rgba::Blue], 3);
+        wide[Rgba::Alpha] = 0xFFFF;
+        assert_eq!(wide.alpha(), 0xFFFF);
+
+        let mut abgr = Pixel::<Abgr8888Be>::with_rgba(1, 2, 3, 4);
+        abgr[Rgba::Red] = 0x10;
+        assert_eq!(abgr.channels(), [0x10, 2, 3, 4]);
+        assert_eq!(abgr.as_bytes()[0], 0x10);
+
+        let mut gray = Pixel::<Gray16>::with_gray(7);
+        gray[Gray::Gray] += 1;
+        assert_eq!(gray.gray(), 8);
+    }
+
+    #[test]
+    fn iter_channels() {
+        use crate::formats::{
+            gray::{Gray, Gray8},
+            rgba::Rgba,
+        };
+
+        let pixel = Pixel::<Gray8>::with_gray(0x80);
+        let mut channels = pixel.iter_channels();
+        assert_eq!(channels.next(), Some((Gray::Gray, 0x80)));
+        assert_eq!(channels.next(), None);
+
+        let rgba = Pixel::<crate::formats::rgba::Rgba8888>::with_rgba(1, 2, 3, 4);
+        let sum: u32 = rgba
+            .iter_channels()
+            .map(|(_, value)| u32::from(value))
+            .sum();
+        assert_eq!(sum, 10);
+        assert_eq!(rgba.iter_channels().next_back(), Some((Rgba::Alpha, 4)));
+    }
+
     #[test]
     fn default() {
         let pixel = Pixel::<crate::formats::rgba::Rgba8888>::default();
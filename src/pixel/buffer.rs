@@ -0,0 +1,394 @@
+//! An owned, two-dimensional buffer of pixels.
+//!
+//! This module requires the `alloc` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pxlfmt::{formats::rgba::Rgba8888, pixel::{Pixel, buffer::PixelBuffer}};
+//!
+//! let mut buffer = PixelBuffer::<Rgba8888>::new(4, 2);
+//! buffer[(1, 0)] = Pixel::with_rgba(0xFF, 0x00, 0x00, 0xFF);
+//!
+//! assert_eq!(buffer.row(0)[1].red(), 0xFF);
+//! assert_eq!(buffer.get(4, 0), None);
+//! ```
+
+use alloc::{vec, vec::Vec};
+use core::{
+    error::Error,
+    fmt::{Debug, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    orient::Orientation,
+    pixel::{
+        Format, Pixel,
+        slice::PixelSlice,
+        view::{ImageView, ImageViewMut},
+    },
+};
+
+/// An error returned when a buffer is created from pixels of the wrong length.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BufferSizeError {
+    /// The number of pixels required by the dimensions of the buffer.
+    pub expected: usize,
+
+    /// The number of pixels provided.
+    pub actual: usize,
+}
+
+impl Display for BufferSizeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Buffer has {} pixels, expected {}",
+            self.actual, self.expected
+        )
+    }
+}
+
+impl Error for BufferSizeError {}
+
+/// An owned buffer of `width * height` pixels in the format `F`, stored in row-major order.
+#[derive(Clone)]
+pub struct PixelBuffer<F: Format> {
+    pixels: Vec<Pixel<F>>,
+    width: usize,
+    height: usize,
+}
+
+/// Returns the number of pixels in a buffer of the given dimensions.
+fn area(width: usize, height: usize) -> usize {
+    width
+        .checked_mul(height)
+        .expect("Buffer dimensions overflow usize")
+}
+
+impl<F: Format> PixelBuffer<F> {
+    /// Creates a buffer with every pixel [zeroed](Pixel::zeroed).
+    ///
+    /// ## Panics
+    ///
+    /// If `width * height` overflows `usize`.
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, Pixel::zeroed())
+    }
+
+    /// Creates a buffer with every pixel set to `pixel`.
+    ///
+    /// ## Panics
+    ///
+    /// If `width * height` overflows `usize`.
+    #[must_use]
+    pub fn filled(width: usize, height: usize, pixel: Pixel<F>) -> Self {
+        Self {
+            pixels: vec![pixel; area(width, height)],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a buffer from pixels in row-major order.
+    ///
+    /// ## Errors
+    ///
+    /// If `pixels` does not have exactly `width * height` pixels.
+    ///
+    /// ## Panics
+    ///
+    /// If `width * height` overflows `usize`.
+    pub fn from_vec(
+        width: usize,
+        height: usize,
+        pixels: Vec<Pixel<F>>,
+    ) -> Result<Self, BufferSizeError> {
+        let expected = area(width, height);
+        if pixels.len() != expected {
+            return Err(BufferSizeError {
+                expected,
+                actual: pixels.len(),
+            });
+        }
+        Ok(Self {
+            pixels,
+            width,
+            height,
+        })
+    }
+
+    /// Returns the width of the buffer, in pixels.
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the buffer, in pixels.
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the pixel at `(x, y)`, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<&Pixel<F>> {
+        self.index_of(x, y).map(|i| &self.pixels[i])
+    }
+
+    /// Returns the mutable pixel at `(x, y)`, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Pixel<F>> {
+        self.index_of(x, y).map(|i| &mut self.pixels[i])
+    }
+
+    /// Returns the pixels of row `y`.
+    ///
+    /// ## Panics
+    ///
+    /// If `y` is out of bounds.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[Pixel<F>] {
+        assert!(y < self.height, "Row out of bounds");
+        &self.pixels[y * self.width..][..self.width]
+    }
+
+    /// Returns the mutable pixels of row `y`.
+    ///
+    /// ## Panics
+    ///
+    /// If `y` is out of bounds.
+    #[must_use]
+    pub fn row_mut(&mut self, y: usize) -> &mut [Pixel<F>] {
+        assert!(y < self.height, "Row out of bounds");
+        &mut self.pixels[y * self.width..][..self.width]
+    }
+
+    /// Returns every pixel, in row-major order.
+    #[must_use]
+    pub fn as_slice(&self) -> &PixelSlice<F> {
+        PixelSlice::new(&self.pixels)
+    }
+
+    /// Returns every mutable pixel, in row-major order.
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut PixelSlice<F> {
+        PixelSlice::new_mut(&mut self.pixels)
+    }
+
+    /// Returns a view of the buffer.
+    #[must_use]
+    pub fn as_view(&self) -> ImageView<'_, F> {
+        ImageView::new_unchecked(&self.pixels, self.width, self.height, self.width)
+    }
+
+    /// Creates a buffer from the pixels of a view, tightly packed without padding.
+    ///
+    /// See [`ImageView::compact_into`].
+    #[must_use]
+    pub fn from_view(view: ImageView<'_, F>) -> Self {
+        let mut buffer = Self::new(view.width(), view.height());
+        view.compact_into(&mut buffer.pixels);
+        buffer
+    }
+
+    /// Returns a mutable view of the buffer.
+    #[must_use]
+    pub fn as_view_mut(&mut self) -> ImageViewMut<'_, F> {
+        ImageViewMut::new_unchecked(&mut self.pixels, self.width, self.height, self.width)
+    }
+
+    /// Mirrors the buffer horizontally, in place.
+    pub fn flip_horizontal(&mut self) {
+        self.as_view_mut().flip_horizontal();
+    }
+
+    /// Mirrors the buffer vertically, in place.
+    pub fn flip_vertical(&mut self) {
+        self.as_view_mut().flip_vertical();
+    }
+
+    /// Returns a copy of the buffer in the given orientation.
+    ///
+    /// See [`Orientation::apply`].
+    #[must_use]
+    pub fn oriented(&self, orientation: Orientation) -> Self {
+        let (width, height) = orientation.oriented_size(self.width, self.height);
+        let mut dst = Self::new(width, height);
+        self.as_view()
+            .orient_into(orientation, &mut dst.as_view_mut());
+        dst
+    }
+
+    /// Returns a copy of the buffer rotated 90 degrees clockwise.
+    #[must_use]
+    pub fn rotate90(&self) -> Self {
+        self.oriented(Orientation::Rotate90)
+    }
+
+    /// Returns a copy of the buffer rotated 180 degrees.
+    #[must_use]
+    pub fn rotate180(&self) -> Self {
+        self.oriented(Orientation::Rotate180)
+    }
+
+    /// Returns a copy of the buffer rotated 270 degrees clockwise (90 degrees counter-clockwise).
+    #[must_use]
+    pub fn rotate270(&self) -> Self {
+        self.oriented(Orientation::Rotate270)
+    }
+
+    /// Consumes the buffer and returns its pixels, in row-major order.
+    #[must_use]
+    pub fn into_vec(self) -> Vec<Pixel<F>> {
+        self.pixels
+    }
+
+    /// Returns the index of the pixel at `(x, y)`, or `None` if it is out of bounds.
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+}
+
+impl<F: Format> ImageView<'_, F> {
+    /// Copies the view into a new buffer, tightly packed without padding.
+    ///
+    /// See [`ImageView::compact_into`].
+    #[must_use]
+    pub fn to_buffer(&self) -> PixelBuffer<F> {
+        PixelBuffer::from_view(*self)
+    }
+}
+
+impl<F: Format> Debug for PixelBuffer<F>
+where
+    Pixel<F>: Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PixelBuffer")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("pixels", &self.pixels)
+            .finish()
+    }
+}
+
+impl<F: Format> PartialEq for PixelBuffer<F>
+where
+    Pixel<F>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.pixels == other.pixels
+    }
+}
+
+impl<F: Format> Eq for PixelBuffer<F> where Pixel<F>: Eq {}
+
+impl<F: Format> Index<(usize, usize)> for PixelBuffer<F> {
+    type Output = Pixel<F>;
+
+    /// Returns the pixel at `(x, y)`.
+    ///
+    /// ## Panics
+    ///
+    /// If `(x, y)` is out of bounds.
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y).expect("Pixel out of bounds")
+    }
+}
+
+impl<F: Format> IndexMut<(usize, usize)> for PixelBuffer<F> {
+    /// Returns the mutable pixel at `(x, y)`.
+    ///
+    /// ## Panics
+    ///
+    /// If `(x, y)` is out of bounds.
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y).expect("Pixel out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::formats::rgba::Rgba8888;
+
+    use super::*;
+
+    #[test]
+    fn from_view_removes_padding() {
+        let pixels = [0, 1, 99, 2, 3, 99].map(Pixel::<Rgba8888>::new);
+        let view = ImageView::with_stride(&pixels, 2, 2, 3).unwrap();
+        let buffer = view.to_buffer();
+        assert_eq!((buffer.width(), buffer.height()), (2, 2));
+        assert_eq!(buffer.into_vec(), [0, 1, 2, 3].map(Pixel::new));
+    }
+
+    #[test]
+    fn filled() {
+        let pixel = Pixel::<Rgba8888>::with_rgba(1, 2, 3, 4);
+        let buffer = PixelBuffer::filled(3, 2, pixel);
+        assert_eq!((buffer.width(), buffer.height()), (3, 2));
+        assert_eq!(buffer.into_vec(), vec![pixel; 6]);
+    }
+
+    #[test]
+    fn from_vec() {
+        let pixels = vec![Pixel::<Rgba8888>::zeroed(); 5];
+        assert_eq!(
+            PixelBuffer::from_vec(3, 2, pixels),
+            Err(BufferSizeError {
+                expected: 6,
+                actual: 5
+            })
+        );
+
+        let pixels = (0..6).map(Pixel::<Rgba8888>::new).collect();
+        let buffer = PixelBuffer::from_vec(3, 2, pixels).unwrap();
+        assert_eq!(buffer[(2, 1)], Pixel::new(5));
+        assert_eq!(
+            buffer.row(1),
+            &[Pixel::new(3), Pixel::new(4), Pixel::new(5)]
+        );
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut buffer = PixelBuffer::<Rgba8888>::new(2, 2);
+        buffer[(0, 1)] = Pixel::new(1);
+        buffer.row_mut(0)[1] = Pixel::new(2);
+        assert_eq!(buffer.as_slice().as_pixels(), &[0, 2, 1, 0].map(Pixel::new));
+        assert_eq!(buffer.as_view().row(1), &[Pixel::new(1), Pixel::new(0)]);
+        assert_eq!(buffer.get(2, 0), None);
+        assert_eq!(buffer.get_mut(0, 2), None);
+    }
+
+    #[test]
+    fn orientation() {
+        let pixels = (1..=6).map(Pixel::<Rgba8888>::new).collect();
+        let mut buffer = PixelBuffer::from_vec(3, 2, pixels).unwrap();
+
+        let rotated = buffer.rotate90();
+        assert_eq!((rotated.width(), rotated.height()), (2, 3));
+        assert_eq!(rotated.row(0), &[Pixel::new(4), Pixel::new(1)]);
+        assert_eq!(rotated.rotate270(), buffer);
+
+        buffer.flip_horizontal();
+        buffer.flip_vertical();
+        assert_eq!(buffer, buffer.rotate180().rotate180());
+        assert_eq!(
+            buffer.row(0),
+            &[Pixel::new(6), Pixel::new(5), Pixel::new(4)]
+        );
+    }
+
+    #[test]
+    #[should_panic = "Pixel out of bounds"]
+    fn index_out_of_bounds() {
+        let buffer = PixelBuffer::<Rgba8888>::new(2, 2);
+        let _ = buffer[(2, 0)];
+    }
+}
@@ -0,0 +1,278 @@
+//! Sub-byte pixel formats, packed several pixels to a byte.
+//!
+//! A [`Pixel`] is always at least one byte, as it is `#[repr(transparent)]` over its raw pixel.
+//! Formats with fewer bits per pixel implement [`PackedFormat`], and are stored in memory packed
+//! several pixels to a byte; [`PackedBitSlice`] and [`PackedBitSliceMut`] index individual pixels
+//! out of such a buffer.
+//!
+//! Pixels are packed most significant bits first, so the first pixel of a byte is stored in its
+//! highest bits (as in PBM files and most e-paper displays).
+//!
+//! # Example
+//!
+//! ```rust
+//! use pxlfmt::{formats::gray::Mono1, pixel::{Pixel, packed::PackedBitSliceMut}};
+//!
+//! let mut bytes = [0b1000_0001];
+//! let mut pixels = PackedBitSliceMut::<Mono1>::new(&mut bytes);
+//! assert_eq!(pixels.len(), 8);
+//! assert_eq!(pixels.get(0).map(|p| p.gray()), Some(1));
+//! assert_eq!(pixels.get(1).map(|p| p.gray()), Some(0));
+//!
+//! pixels.set(1, Pixel::with_gray(1));
+//! assert_eq!(bytes, [0b1100_0001]);
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::pixel::{Format, Pixel, raw::RawPixel, raw::U8x1};
+
+/// A pixel format with fewer than 8 bits per pixel, which can be packed several pixels to a byte.
+///
+/// The number of bits each pixel occupies when packed is [`Format::BITS_PER_PIXEL`], which is
+/// always a divisor of 8 (i.e. `1`, `2`, or `4`). When unpacked, the pixel's raw value is stored
+/// in the lowest `BITS_PER_PIXEL` bits of a byte.
+pub trait PackedFormat: Format<RawPixel = U8x1> {
+    /// The number of pixels that are packed into each byte.
+    const PIXELS_PER_BYTE: usize = 8 / Self::BITS_PER_PIXEL as usize;
+
+    /// The mask of the bits of a single, unpacked pixel.
+    const MASK: u8 = u8::MAX >> (8 - Self::BITS_PER_PIXEL);
+}
+
+/// Returns the byte index and the shift of the pixel at `index`.
+fn locate<F: PackedFormat>(index: usize) -> (usize, u32) {
+    let byte = index / F::PIXELS_PER_BYTE;
+    #[allow(clippy::cast_possible_truncation)]
+    let slot = (index % F::PIXELS_PER_BYTE) as u32;
+    (byte, 8 - F::BITS_PER_PIXEL * (slot + 1))
+}
+
+/// Reads the pixel at `index` out of a packed buffer.
+fn read<F: PackedFormat>(bytes: &[u8], index: usize) -> Option<Pixel<F>> {
+    let (byte, shift) = locate::<F>(index);
+    let value = (bytes.get(byte)? >> shift) & F::MASK;
+    Some(Pixel::from_raw(U8x1::from(value)))
+}
+
+/// A borrowed, read-only view of pixels packed several to a byte.
+///
+/// The view covers every pixel slot in the underlying bytes, so its length is always a multiple of
+/// [`PackedFormat::PIXELS_PER_BYTE`]; rows that do not end on a byte boundary include padding.
+#[derive(Copy, Clone, Debug)]
+pub struct PackedBitSlice<'a, F: PackedFormat> {
+    bytes: &'a [u8],
+    format: PhantomData<F>,
+}
+
+impl<'a, F: PackedFormat> PackedBitSlice<'a, F> {
+    /// Creates a new view over the given packed bytes.
+    #[must_use]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            format: PhantomData,
+        }
+    }
+
+    /// Returns the number of pixels in the view.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.bytes.len() * F::PIXELS_PER_BYTE
+    }
+
+    /// Returns `true` if the view contains no pixels.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the pixel at the given index, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<Pixel<F>> {
+        read(self.bytes, index)
+    }
+
+    /// Returns an iterator over the pixels in the view.
+    pub fn iter(&self) -> impl Iterator<Item = Pixel<F>> + 'a {
+        let bytes = self.bytes;
+        (0..self.len()).filter_map(move |index| read(bytes, index))
+    }
+
+    /// Returns the underlying packed bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+/// A borrowed, mutable view of pixels packed several to a byte.
+///
+/// See [`PackedBitSlice`] for the read-only counterpart.
+#[derive(Debug)]
+pub struct PackedBitSliceMut<'a, F: PackedFormat> {
+    bytes: &'a mut [u8],
+    format: PhantomData<F>,
+}
+
+impl<'a, F: PackedFormat> PackedBitSliceMut<'a, F> {
+    /// Creates a new view over the given packed bytes.
+    #[must_use]
+    pub const fn new(bytes: &'a mut [u8]) -> Self {
+        Self {
+            bytes,
+            format: PhantomData,
+        }
+    }
+
+    /// Returns the number of pixels in the view.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.bytes.len() * F::PIXELS_PER_BYTE
+    }
+
+    /// Returns `true` if the view contains no pixels.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the pixel at the given index, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<Pixel<F>> {
+        read(self.bytes, index)
+    }
+
+    /// Sets the pixel at the given index.
+    ///
+    /// Bits of the pixel's raw value beyond [`Format::BITS_PER_PIXEL`] are ignored.
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn set(&mut self, index: usize, pixel: Pixel<F>) {
+        assert!(index < self.len(), "Index out of bounds");
+        let (byte, shift) = locate::<F>(index);
+        let value = pixel.into_raw().into_inner() & F::MASK;
+        let byte = &mut self.bytes[byte];
+        *byte = (*byte & !(F::MASK << shift)) | (value << shift);
+    }
+
+    /// Sets every pixel in the view.
+    pub fn fill(&mut self, pixel: Pixel<F>) {
+        let value = pixel.into_raw().into_inner() & F::MASK;
+        let mut byte = 0;
+        for _ in 0..F::PIXELS_PER_BYTE {
+            byte = (byte << F::BITS_PER_PIXEL) | value;
+        }
+        self.bytes.fill(byte);
+    }
+
+    /// Returns a read-only view of the same pixels.
+    #[must_use]
+    pub fn as_packed(&self) -> PackedBitSlice<'_, F> {
+        PackedBitSlice::new(self.bytes)
+    }
+
+    /// Returns the underlying packed bytes.
+    #[must_use]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use crate::formats::gray::{Gray2, Gray4, Mono1};
+
+    use super::*;
+
+    #[test]
+    fn mono1_get() {
+        let bytes = [0b1010_0000, 0b0000_0001];
+        let pixels = PackedBitSlice::<Mono1>::new(&bytes);
+        assert_eq!(pixels.len(), 16);
+        assert_eq!(pixels.get(0).map(|p| p.gray()), Some(1));
+        assert_eq!(pixels.get(1).map(|p| p.gray()), Some(0));
+        assert_eq!(pixels.get(2).map(|p| p.gray()), Some(1));
+        assert_eq!(pixels.get(15).map(|p| p.gray()), Some(1));
+        assert_eq!(pixels.get(16), None);
+    }
+
+    #[test]
+    fn mono1_iter() {
+        let bytes = [0b1100_0011];
+        let pixels = PackedBitSlice::<Mono1>::new(&bytes);
+        let grays: Vec<u8> = pixels.iter().map(|p| p.gray()).collect();
+        assert_eq!(grays, [1, 1, 0, 0, 0, 0, 1, 1]);
+    }
+
+    #[test]
+    fn mono1_set() {
+        let mut bytes = [0x00, 0xFF];
+        let mut pixels = PackedBitSliceMut::<Mono1>::new(&mut bytes);
+        pixels.set(7, Pixel::with_gray(1));
+        pixels.set(8, Pixel::with_gray(0));
+        assert_eq!(pixels.as_packed().get(7).map(|p| p.gray()), Some(1));
+        assert_eq!(bytes, [0b0000_0001, 0b0111_1111]);
+    }
+
+    #[test]
+    fn mono1_set_ignores_extra_bits() {
+        let mut bytes = [0x00];
+        let mut pixels = PackedBitSliceMut::<Mono1>::new(&mut bytes);
+        pixels.set(0, Pixel::with_gray(0b10));
+        assert_eq!(bytes, [0x00]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn mono1_set_out_of_bounds() {
+        let mut bytes = [0x00];
+        PackedBitSliceMut::<Mono1>::new(&mut bytes).set(8, Pixel::with_gray(1));
+    }
+
+    #[test]
+    fn mono1_fill() {
+        let mut bytes = [0x00; 2];
+        PackedBitSliceMut::<Mono1>::new(&mut bytes).fill(Pixel::with_gray(1));
+        assert_eq!(bytes, [0xFF; 2]);
+    }
+
+    #[test]
+    fn gray2_get_and_set() {
+        let mut bytes = [0b11_10_01_00];
+        let mut pixels = PackedBitSliceMut::<Gray2>::new(&mut bytes);
+        assert_eq!(pixels.len(), 4);
+        let grays: Vec<u8> = pixels.as_packed().iter().map(|p| p.gray()).collect();
+        assert_eq!(grays, [3, 2, 1, 0]);
+
+        pixels.set(3, Pixel::with_gray(2));
+        pixels.set(0, Pixel::with_gray(0));
+        assert_eq!(bytes, [0b00_10_01_10]);
+    }
+
+    #[test]
+    fn gray4_get_and_set() {
+        let mut bytes = [0xA5, 0x0F];
+        let mut pixels = PackedBitSliceMut::<Gray4>::new(&mut bytes);
+        assert_eq!(pixels.len(), 4);
+        assert_eq!(pixels.get(0).map(|p| p.gray()), Some(0xA));
+        assert_eq!(pixels.get(1).map(|p| p.gray()), Some(0x5));
+        assert_eq!(pixels.get(3).map(|p| p.gray()), Some(0xF));
+
+        pixels.set(2, Pixel::with_gray(0x7));
+        assert_eq!(bytes, [0xA5, 0x7F]);
+    }
+
+    #[test]
+    fn gray4_fill() {
+        let mut bytes = [0x00; 2];
+        PackedBitSliceMut::<Gray4>::new(&mut bytes).fill(Pixel::with_gray(0x3));
+        assert_eq!(bytes, [0x33; 2]);
+    }
+}
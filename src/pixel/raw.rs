@@ -25,12 +25,174 @@
 
 use core::mem;
 
+use crate::internal::{SealedBytes, SealedConstChannels};
+
+/// Implements the byte order methods of [`RawPixel`] for a raw pixel wrapping a single integer or
+/// floating point value.
+macro_rules! scalar_bytes {
+    ($t:ty) => {
+        fn to_le_bytes(self) -> Self::Bytes {
+            self.0.to_le_bytes()
+        }
+
+        fn to_be_bytes(self) -> Self::Bytes {
+            self.0.to_be_bytes()
+        }
+
+        fn from_le_bytes(bytes: Self::Bytes) -> Self {
+            Self(<$t>::from_le_bytes(bytes))
+        }
+
+        fn from_be_bytes(bytes: Self::Bytes) -> Self {
+            Self(<$t>::from_be_bytes(bytes))
+        }
+    };
+}
+
+/// Implements the byte order methods of [`RawPixel`] for a raw pixel wrapping an array, encoding
+/// each element in turn.
+macro_rules! array_bytes {
+  ($t:ty) => {
+    fn to_le_bytes(self) -> Self::Bytes {
+      array_bytes!(@to self, to_le_bytes)
+    }
+
+    fn to_be_bytes(self) -> Self::Bytes {
+      array_bytes!(@to self, to_be_bytes)
+    }
+
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+      array_bytes!(@from bytes, $t, from_le_bytes)
+    }
+
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+      array_bytes!(@from bytes, $t, from_be_bytes)
+    }
+  };
+  (@to $self:ident, $method:ident) => {{
+    let mut bytes = [0; size_of::<Self>()];
+    for (chunk, element) in bytes.chunks_exact_mut(size_of_val(&$self.0[0])).zip($self.0) {
+      chunk.copy_from_slice(&element.$method());
+    }
+    bytes
+  }};
+  (@from $bytes:ident, $t:ty, $method:ident) => {{
+    let mut storage = <Self as RawPixel>::DEFAULT.0;
+    for (element, chunk) in storage.iter_mut().zip($bytes.chunks_exact(size_of::<$t>())) {
+      // The chunk is exactly `size_of::<$t>()` bytes long, so the conversion cannot fail.
+      *element = <$t>::$method(chunk.try_into().unwrap());
+    }
+    Self(storage)
+  }};
+}
+
+mod f32x1;
+pub use f32x1::F32x1;
+
 mod f32x4;
 pub use f32x4::F32x4;
 
+mod f64x4;
+pub use f64x4::F64x4;
+
+mod i16x4;
+pub use i16x4::I16x4;
+
+mod i8x2;
+pub use i8x2::I8x2;
+
+mod i8x4;
+pub use i8x4::I8x4;
+
+mod u16x1;
+pub use u16x1::U16x1;
+
+mod u16x3;
+pub use u16x3::U16x3;
+
+mod u16x3_msb10;
+pub use u16x3_msb10::U16x3Msb10;
+
+mod u16x4;
+pub use u16x4::U16x4;
+
+mod u16x565;
+pub use u16x565::U16x565;
+
+mod u32x111110;
+pub use u32x111110::U32x111110;
+
+mod u32x248;
+pub use u32x248::U32x248;
+
 mod u32x8888;
 pub use u32x8888::U32x8888;
 
+mod u32x8888_be;
+pub use u32x8888_be::U32x8888Be;
+
+mod u32x8888_le;
+pub use u32x8888_le::U32x8888Le;
+
+mod u32x9995;
+pub use u32x9995::U32x9995;
+
+mod u8x1;
+pub use u8x1::U8x1;
+
+mod u8x3;
+pub use u8x3::U8x3;
+
+mod u8x4;
+pub use u8x4::U8x4;
+
+/// Where a channel is stored within a raw pixel, as a bit shift and width.
+///
+/// For integer storage, positions are counted within the integer; for array storage, positions are
+/// counted in memory order, with each element following the previous one, so that byte `i` of a
+/// byte array starts at bit `8 * i`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ChannelDescriptor {
+    /// The number of bits below the channel.
+    pub shift: u32,
+
+    /// The number of bits the channel occupies.
+    pub bits: u32,
+}
+
+impl ChannelDescriptor {
+    /// Returns the mask of the channel's bits, after shifting.
+    ///
+    /// Returns `None` if the channel extends beyond the first 64 bits of the pixel (e.g. the last
+    /// channels of [`F64x4`]); use [`unshifted_mask`](ChannelDescriptor::unshifted_mask) instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::pixel::raw::ChannelDescriptor;
+    ///
+    /// let green = ChannelDescriptor { shift: 5, bits: 6 };
+    /// assert_eq!(green.mask(), Some(0x07E0));
+    /// ```
+    #[must_use]
+    pub const fn mask(self) -> Option<u64> {
+        if self.shift + self.bits > u64::BITS {
+            return None;
+        }
+        Some(self.unshifted_mask() << self.shift)
+    }
+
+    /// Returns the mask of the channel's bits, before shifting.
+    #[must_use]
+    pub const fn unshifted_mask(self) -> u64 {
+        if self.bits == 0 {
+            0
+        } else {
+            u64::MAX >> (u64::BITS - self.bits)
+        }
+    }
+}
+
 /// A trait for types that can represent a raw pixel value.
 ///
 /// This trait provides methods to get and set the individual channels of a pixel.
@@ -49,6 +211,7 @@ pub use u32x8888::U32x8888;
 ///   const DEFAULT: Self = Self(0);
 ///   type Channel = u8;
 ///   type Storage = u32;
+///   type Bytes = [u8; 4];
 ///
 ///   unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
 ///     (self.0 >> (offset * 8) & 0xFF) as u8
@@ -67,6 +230,22 @@ pub use u32x8888::U32x8888;
 ///   fn into_inner(self) -> Self::Storage {
 ///     self.0
 ///   }
+///
+///   fn to_le_bytes(self) -> Self::Bytes {
+///     self.0.to_le_bytes()
+///   }
+///
+///   fn to_be_bytes(self) -> Self::Bytes {
+///     self.0.to_be_bytes()
+///   }
+///
+///   fn from_le_bytes(bytes: Self::Bytes) -> Self {
+///     Self(u32::from_le_bytes(bytes))
+///   }
+///
+///   fn from_be_bytes(bytes: Self::Bytes) -> Self {
+///     Self(u32::from_be_bytes(bytes))
+///   }
 /// }
 ///
 /// impl From<u32> for U32x8888 {
@@ -84,6 +263,11 @@ pub trait RawPixel: Copy + From<Self::Storage> {
     /// For example, for `U32x8888`, this would be `u32`.
     type Storage;
 
+    /// The byte array used to encode the pixel with an explicit byte order.
+    ///
+    /// For example, for `U32x8888`, this would be `[u8; 4]`.
+    type Bytes: Copy;
+
     /// The underlying type used to represent each of the pixel's channels.
     ///
     /// For example, for `U32x8888`, this would be `u8`.
@@ -201,6 +385,25 @@ pub trait RawPixel: Copy + From<Self::Storage> {
         self
     }
 
+    /// Describes where the channel at the provided offset is stored.
+    ///
+    /// Defaults to channels of the full width of [`Channel`](RawPixel::Channel), one after the other
+    /// in offset order.
+    ///
+    /// ## Panics
+    ///
+    /// If `offset` is out of bounds for the pixel's channel count, this method will panic.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    fn channel_descriptor(offset: usize) -> ChannelDescriptor {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        let bits = (mem::size_of::<Self::Channel>() * 8) as u32;
+        ChannelDescriptor {
+            shift: offset as u32 * bits,
+            bits,
+        }
+    }
+
     /// Returns the underlying raw value.
     #[must_use]
     fn as_inner(&self) -> &Self::Storage;
@@ -208,8 +411,141 @@ pub trait RawPixel: Copy + From<Self::Storage> {
     /// Consumes the pixel and returns the underlying raw value.
     #[must_use]
     fn into_inner(self) -> Self::Storage;
+
+    /// Returns the pixel's bytes in little-endian byte order, regardless of the host.
+    ///
+    /// Each integer or floating point element of the storage is encoded in turn, in memory order.
+    #[must_use]
+    fn to_le_bytes(self) -> Self::Bytes;
+
+    /// Returns the pixel's bytes in big-endian byte order, regardless of the host.
+    ///
+    /// Each integer or floating point element of the storage is encoded in turn, in memory order.
+    #[must_use]
+    fn to_be_bytes(self) -> Self::Bytes;
+
+    /// Creates a pixel from its bytes in little-endian byte order.
+    ///
+    /// This is the inverse of [`to_le_bytes`](RawPixel::to_le_bytes).
+    #[must_use]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Creates a pixel from its bytes in big-endian byte order.
+    ///
+    /// This is the inverse of [`to_be_bytes`](RawPixel::to_be_bytes).
+    #[must_use]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+}
+
+/// A raw pixel type with four channels, whose extreme values are available as constants.
+///
+/// Channel setters are trait methods, which cannot be called in constants; this trait instead
+/// provides the values needed for constant pixels such as
+/// [`Pixel::BLACK`](crate::pixel::Pixel::BLACK).
+///
+/// This trait is _sealed_, and is implemented for the built-in raw pixel types with four
+/// normalized channels.
+#[allow(private_bounds)]
+pub trait ConstChannels: RawPixel + SealedConstChannels {
+    /// The raw pixel with every channel set to its maximum.
+    const MAX: Self;
+
+    /// For each offset, the raw pixel with the channel at that offset set to its maximum, and every
+    /// other channel set to zero.
+    const MAX_AT: [Self; 4];
+}
+
+macro_rules! impl_const_channels {
+  ($($raw:ty => $zero:expr, $max:expr;)*) => {
+    $(
+      impl SealedConstChannels for $raw {}
+
+      impl ConstChannels for $raw {
+        const MAX: Self = Self::from_channels($max, $max, $max, $max);
+        const MAX_AT: [Self; 4] = [
+          Self::from_channels($max, $zero, $zero, $zero),
+          Self::from_channels($zero, $max, $zero, $zero),
+          Self::from_channels($zero, $zero, $max, $zero),
+          Self::from_channels($zero, $zero, $zero, $max),
+        ];
+      }
+    )*
+  };
+}
+
+impl_const_channels! {
+  F32x4 => 0.0, 1.0;
+  F64x4 => 0.0, 1.0;
+  I8x4 => 0, i8::MAX;
+  U16x4 => 0, u16::MAX;
+  U32x8888 => 0, u8::MAX;
+  U32x8888Be => 0, u8::MAX;
+  U32x8888Le => 0, u8::MAX;
+}
+
+/// A raw pixel type whose channels are each stored as a whole value, which can be borrowed.
+///
+/// This is implemented for the built-in raw pixel types backed by an array of channels (or a
+/// single channel), and allows indexing a [`Pixel`](crate::pixel::Pixel) by the channels of its
+/// format (e.g. `pixel[Rgba::Red]`). Packed types, where channels share an integer, cannot
+/// implement it.
+pub trait ChannelRef: RawPixel {
+    /// Returns a reference to the channel at the provided offset.
+    ///
+    /// ## Panics
+    ///
+    /// If `offset` is out of bounds for the pixel's channel count, this method will panic.
+    fn channel_ref(&self, offset: usize) -> &Self::Channel;
+
+    /// Returns a mutable reference to the channel at the provided offset.
+    ///
+    /// ## Panics
+    ///
+    /// If `offset` is out of bounds for the pixel's channel count, this method will panic.
+    fn channel_mut(&mut self, offset: usize) -> &mut Self::Channel;
 }
 
+/// A raw pixel type made only of bytes, which can be safely viewed as (and from) a byte slice.
+///
+/// Such a type has an alignment of `1`, no padding, and every bit pattern is a valid value, so the
+/// byte views on [`Pixel`](crate::pixel::Pixel) need neither the `bytemuck` feature nor `unsafe`
+/// code.
+///
+/// This trait is _sealed_, and is implemented for the built-in raw pixel types whose storage is
+/// `u8` or an array of `u8` or `i8`.
+#[allow(private_bounds)]
+pub trait PlainBytes: RawPixel + SealedBytes {}
+
+impl SealedBytes for I8x2 {}
+impl SealedBytes for I8x4 {}
+impl SealedBytes for U8x1 {}
+impl SealedBytes for U8x3 {}
+impl SealedBytes for U8x4 {}
+impl SealedBytes for U32x8888Be {}
+impl SealedBytes for U32x8888Le {}
+
+impl PlainBytes for I8x2 {}
+impl PlainBytes for I8x4 {}
+impl PlainBytes for U8x1 {}
+impl PlainBytes for U8x3 {}
+impl PlainBytes for U8x4 {}
+impl PlainBytes for U32x8888Be {}
+impl PlainBytes for U32x8888Le {}
+
+// Checks the requirements of `PlainBytes` for each implementation.
+const _: () = {
+    const fn assert_plain_bytes<R: PlainBytes, S>() {
+        assert!(align_of::<R>() == 1 && size_of::<R>() == size_of::<S>());
+    }
+    assert_plain_bytes::<I8x2, [i8; 2]>();
+    assert_plain_bytes::<I8x4, [i8; 4]>();
+    assert_plain_bytes::<U8x1, u8>();
+    assert_plain_bytes::<U8x3, [u8; 3]>();
+    assert_plain_bytes::<U8x4, [u8; 4]>();
+    assert_plain_bytes::<U32x8888Be, [u8; 4]>();
+    assert_plain_bytes::<U32x8888Le, [u8; 4]>();
+};
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,6 +557,23 @@ mod tests {
         assert_eq!(pixel.get_channel(1), 0xFF); // Green channel
     }
 
+    #[test]
+    fn channel_descriptor_masks() {
+        let descriptor = F64x4::channel_descriptor(1);
+        assert_eq!(
+            descriptor,
+            ChannelDescriptor {
+                shift: 64,
+                bits: 64
+            }
+        );
+        assert_eq!(descriptor.mask(), None);
+        assert_eq!(descriptor.unshifted_mask(), u64::MAX);
+
+        let descriptor = U32x248::channel_descriptor(0);
+        assert_eq!(descriptor.mask(), Some(0x00FF_FFFF));
+    }
+
     #[test]
     fn raw_pixel_is_copy() {
         let pixel = U32x8888::from(0xFF00_00FF);
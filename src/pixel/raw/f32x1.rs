@@ -0,0 +1,118 @@
+use crate::pixel::raw::{ChannelRef, RawPixel};
+
+/// A raw pixel value represented as a single 32-bit floating point number.
+///
+/// The pixel has exactly one channel, which is the entire value.
+///
+/// ## Layout
+///
+/// This struct is identical to a `f32` in memory (`#[repr(transparent)]`).
+#[derive(Copy, Clone, Default, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct F32x1(f32);
+
+impl F32x1 {
+    /// Creates a new raw pixel value set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self(0.0)
+    }
+
+    /// Creates a new raw pixel value from the given 32-bit floating point number.
+    #[must_use]
+    pub const fn from_f32(value: f32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<f32> for F32x1 {
+    fn from(value: f32) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for F32x1 {
+    const DEFAULT: Self = Self(0.0);
+    type Storage = f32;
+    type Bytes = [u8; 4];
+    type Channel = f32;
+
+    unsafe fn get_channel_unchecked(&self, _offset: usize) -> Self::Channel {
+        self.0
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, _offset: usize, value: Self::Channel) -> &mut Self {
+        self.0 = value;
+        self
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    scalar_bytes!(f32);
+}
+
+impl ChannelRef for F32x1 {
+    fn channel_ref(&self, offset: usize) -> &Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &self.0
+    }
+
+    fn channel_mut(&mut self, offset: usize) -> &mut Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for F32x1 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for F32x1 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_cast() {
+        let pixels = [F32x1::from(1.0)];
+        let bytes: &[u8] = bytemuck::cast_slice(&pixels);
+        assert_eq!(bytes, &1.0_f32.to_ne_bytes());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn single_channel() {
+        assert_eq!(F32x1::CHANNELS, 1);
+
+        let mut pixel = F32x1::from_f32(0.5);
+        assert_eq!(pixel.get_channel(0), 0.5);
+
+        pixel.set_channel(0, 0.25);
+        assert_eq!(pixel.into_inner(), 0.25);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn new_zero() {
+        assert_eq!(F32x1::new_zeroed().into_inner(), 0.0);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::pixel::raw::RawPixel;
+use crate::pixel::raw::{ChannelRef, RawPixel};
 
 /// A raw pixel value represented as 4 32-bit floating point numbers.
 ///
@@ -8,6 +8,17 @@ use crate::pixel::raw::RawPixel;
 ///
 /// This struct is identical to a `[f32; 4]` in memory (`#[repr(transparent)]`).
 #[derive(Copy, Clone, Default, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
 #[repr(transparent)]
 pub struct F32x4([f32; 4]);
 
@@ -29,6 +40,88 @@ impl F32x4 {
     pub const fn from_channels(a: f32, b: f32, c: f32, d: f32) -> Self {
         Self([a, b, c, d])
     }
+
+    /// Returns the 4 channels of the raw pixel value, in offset order.
+    #[must_use]
+    pub const fn to_channels(self) -> [f32; 4] {
+        self.0
+    }
+
+    /// Returns a new raw pixel with `f` applied to each pair of corresponding channels.
+    #[must_use]
+    fn zip_with(self, other: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        Self(core::array::from_fn(|i| f(self.0[i], other.0[i])))
+    }
+
+    /// Returns the component-wise minimum of `self` and `other`.
+    ///
+    /// If one of the channels is `NaN`, the other channel is returned, as in [`f32::min`].
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        self.zip_with(other, f32::min)
+    }
+
+    /// Returns the component-wise maximum of `self` and `other`.
+    ///
+    /// If one of the channels is `NaN`, the other channel is returned, as in [`f32::max`].
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        self.zip_with(other, f32::max)
+    }
+
+    /// Restricts each channel to the range of the corresponding channels in `min` and `max`.
+    ///
+    /// ## Panics
+    ///
+    /// If any channel of `min` is greater than the corresponding channel of `max`, or either is
+    /// `NaN`, as in [`f32::clamp`].
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self(core::array::from_fn(|i| {
+            self.0[i].clamp(min.0[i], max.0[i])
+        }))
+    }
+
+    /// Computes `self * a + b` component-wise.
+    ///
+    /// Unlike `f32::mul_add`, this is not guaranteed to be a fused operation (which is unavailable
+    /// in `core`), and may round twice.
+    #[must_use]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        let product = self.zip_with(a, |x, y| x * y);
+        product.zip_with(b, |x, y| x + y)
+    }
+
+    /// Returns the dot product of `self` and `other`.
+    #[must_use]
+    pub fn dot(self, other: Self) -> f32 {
+        self.zip_with(other, |x, y| x * y).element_sum()
+    }
+
+    /// Returns the sum of all channels.
+    #[must_use]
+    pub fn element_sum(self) -> f32 {
+        let [a, b, c, d] = self.0;
+        (a + b) + (c + d)
+    }
+
+    /// Returns the smallest channel.
+    ///
+    /// `NaN` channels are ignored unless all channels are `NaN`.
+    #[must_use]
+    pub fn min_element(self) -> f32 {
+        let [a, b, c, d] = self.0;
+        a.min(b).min(c.min(d))
+    }
+
+    /// Returns the largest channel.
+    ///
+    /// `NaN` channels are ignored unless all channels are `NaN`.
+    #[must_use]
+    pub fn max_element(self) -> f32 {
+        let [a, b, c, d] = self.0;
+        a.max(b).max(c.max(d))
+    }
 }
 
 impl From<[f32; 4]> for F32x4 {
@@ -40,6 +133,7 @@ impl From<[f32; 4]> for F32x4 {
 impl RawPixel for F32x4 {
     const DEFAULT: Self = Self([0.0; 4]);
     type Storage = [f32; 4];
+    type Bytes = [u8; 16];
     type Channel = f32;
 
     unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
@@ -58,6 +152,20 @@ impl RawPixel for F32x4 {
     fn into_inner(self) -> Self::Storage {
         self.0
     }
+
+    array_bytes!(f32);
+}
+
+impl ChannelRef for F32x4 {
+    fn channel_ref(&self, offset: usize) -> &Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &self.0[offset]
+    }
+
+    fn channel_mut(&mut self, offset: usize) -> &mut Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &mut self.0[offset]
+    }
 }
 
 #[cfg(feature = "bytemuck")]
@@ -124,6 +232,47 @@ mod tests {
         assert_eq!(pixel.as_inner(), &[0.0, 1.0, 2.0, 3.0]);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn min_max() {
+        let a = F32x4::from([0.0, 5.0, -1.0, f32::NAN]);
+        let b = F32x4::from([1.0, 2.0, -2.0, 3.0]);
+        assert_eq!(a.min(b).into_inner(), [0.0, 2.0, -2.0, 3.0]);
+        assert_eq!(a.max(b).into_inner(), [1.0, 5.0, -1.0, 3.0]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn clamp() {
+        let pixel = F32x4::from([-0.5, 0.5, 1.5, 2.0]);
+        let clamped = pixel.clamp(F32x4::from([0.0; 4]), F32x4::from([1.0, 1.0, 1.0, 3.0]));
+        assert_eq!(clamped.into_inner(), [0.0, 0.5, 1.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "min > max")]
+    fn clamp_panics_on_invalid_range() {
+        let _ = F32x4::new_zeroed().clamp(F32x4::from([1.0; 4]), F32x4::from([0.0; 4]));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn mul_add() {
+        let pixel = F32x4::from([1.0, 2.0, 3.0, 4.0]);
+        let result = pixel.mul_add(F32x4::from([2.0; 4]), F32x4::from([0.5; 4]));
+        assert_eq!(result.into_inner(), [2.5, 4.5, 6.5, 8.5]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn horizontal() {
+        let pixel = F32x4::from([1.0, -2.0, 3.0, 4.0]);
+        assert_eq!(pixel.dot(F32x4::from([1.0, 1.0, 0.0, 0.5])), 1.0);
+        assert_eq!(pixel.element_sum(), 6.0);
+        assert_eq!(pixel.min_element(), -2.0);
+        assert_eq!(pixel.max_element(), 4.0);
+    }
+
     #[test]
     #[allow(clippy::float_cmp)]
     fn get_channel() {
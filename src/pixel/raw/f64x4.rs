@@ -0,0 +1,139 @@
+use crate::pixel::raw::{ChannelRef, RawPixel};
+
+/// A raw pixel value represented as 4 64-bit floating point numbers.
+///
+/// Each channel is stored as one of the four 64-bit components.
+///
+/// ## Layout
+///
+/// This struct is identical to a `[f64; 4]` in memory (`#[repr(transparent)]`).
+#[derive(Copy, Clone, Default, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct F64x4([f64; 4]);
+
+impl F64x4 {
+    /// Creates a new raw pixel value with all channels set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self([0.0; 4])
+    }
+
+    /// Creates a new raw pixel value from the given array of 4 64-bit floating point numbers.
+    #[must_use]
+    pub const fn from_f64x4(value: [f64; 4]) -> Self {
+        Self(value)
+    }
+
+    /// Creates a new raw pixel value from the given 4 64-bit floating point numbers.
+    #[must_use]
+    pub const fn from_channels(a: f64, b: f64, c: f64, d: f64) -> Self {
+        Self([a, b, c, d])
+    }
+
+    /// Returns the 4 channels of the raw pixel value, in offset order.
+    #[must_use]
+    pub const fn to_channels(self) -> [f64; 4] {
+        self.0
+    }
+}
+
+impl From<[f64; 4]> for F64x4 {
+    fn from(value: [f64; 4]) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for F64x4 {
+    const DEFAULT: Self = Self([0.0; 4]);
+    type Storage = [f64; 4];
+    type Bytes = [u8; 32];
+    type Channel = f64;
+
+    unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
+        unsafe { *self.0.get_unchecked(offset) }
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, offset: usize, value: Self::Channel) -> &mut Self {
+        *unsafe { self.0.get_unchecked_mut(offset) } = value;
+        self
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    array_bytes!(f64);
+}
+
+impl ChannelRef for F64x4 {
+    fn channel_ref(&self, offset: usize) -> &Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &self.0[offset]
+    }
+
+    fn channel_mut(&mut self, offset: usize) -> &mut Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &mut self.0[offset]
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for F64x4 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for F64x4 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_cast() {
+        let pixel = F64x4::from([0.0, 1.0, 2.0, 3.0]);
+        let binding = [pixel];
+        let bytes: &[u8] = bytemuck::cast_slice(&binding);
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(&bytes[8..16], &1.0_f64.to_ne_bytes());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn new_zero() {
+        let pixel = F64x4::new_zeroed();
+        assert_eq!(pixel.as_inner(), &[0.0; 4]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn from_channels() {
+        let pixel = F64x4::from_channels(0.0, 1.0, 2.0, 3.0);
+        assert_eq!(pixel, F64x4::from_f64x4([0.0, 1.0, 2.0, 3.0]));
+        assert_eq!(pixel.into_inner(), [0.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn get_and_set_channel() {
+        let mut pixel = F64x4::from([0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(pixel.get_channel(1), 1.0);
+
+        pixel.set_channel(3, 0.1);
+        assert_eq!(pixel.into_inner(), [0.0, 1.0, 2.0, 0.1]);
+    }
+}
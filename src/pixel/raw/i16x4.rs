@@ -0,0 +1,155 @@
+use core::fmt::{LowerHex, UpperHex};
+
+use crate::pixel::raw::{ChannelRef, RawPixel};
+
+/// A raw pixel value represented as 4 16-bit signed integers.
+///
+/// Each channel is stored as one of the four 16-bit integers, in memory order, in the host's byte
+/// order.
+///
+/// ## Layout
+///
+/// This struct is identical to a `[i16; 4]` in memory (`#[repr(transparent)]`).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct I16x4([i16; 4]);
+
+impl I16x4 {
+    /// Creates a new raw pixel value with all channels set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self([0; 4])
+    }
+
+    /// Creates a new raw pixel value from the given array of 4 16-bit signed integers.
+    #[must_use]
+    pub const fn from_i16x4(value: [i16; 4]) -> Self {
+        Self(value)
+    }
+
+    /// Creates a new raw pixel value from the given 4 16-bit channels.
+    #[must_use]
+    pub const fn from_channels(a: i16, b: i16, c: i16, d: i16) -> Self {
+        Self([a, b, c, d])
+    }
+}
+
+impl From<[i16; 4]> for I16x4 {
+    fn from(value: [i16; 4]) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for I16x4 {
+    const DEFAULT: Self = Self([0; 4]);
+    type Storage = [i16; 4];
+    type Bytes = [u8; 8];
+    type Channel = i16;
+
+    unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
+        unsafe { *self.0.get_unchecked(offset) }
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, offset: usize, value: Self::Channel) -> &mut Self {
+        *unsafe { self.0.get_unchecked_mut(offset) } = value;
+        self
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    array_bytes!(i16);
+}
+
+impl ChannelRef for I16x4 {
+    fn channel_ref(&self, offset: usize) -> &Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &self.0[offset]
+    }
+
+    fn channel_mut(&mut self, offset: usize) -> &mut Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &mut self.0[offset]
+    }
+}
+
+impl UpperHex for I16x4 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for channel in self.0 {
+            write!(f, "{:04X}", channel.cast_unsigned())?;
+        }
+        Ok(())
+    }
+}
+
+impl LowerHex for I16x4 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for channel in self.0 {
+            write!(f, "{:04x}", channel.cast_unsigned())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for I16x4 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for I16x4 {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn new_zero() {
+        let pixel = I16x4::new_zeroed();
+        assert_eq!(pixel.into_inner(), [0; 4]);
+    }
+
+    #[test]
+    fn get_and_set_channel() {
+        let mut pixel = I16x4::from_channels(1, -2, 3, -4);
+        assert_eq!(pixel.get_channel(0), 1);
+        assert_eq!(pixel.get_channel(3), -4);
+
+        pixel.set_channel(2, i16::MIN);
+        assert_eq!(pixel.into_inner(), [1, -2, i16::MIN, -4]);
+    }
+
+    #[test]
+    fn byte_order() {
+        let pixel = I16x4::from_channels(0x0102, -2, 0, 0x7F00);
+        let le = [0x02, 0x01, 0xFE, 0xFF, 0x00, 0x00, 0x00, 0x7F];
+        let be = [0x01, 0x02, 0xFF, 0xFE, 0x00, 0x00, 0x7F, 0x00];
+        assert_eq!(pixel.to_le_bytes(), le);
+        assert_eq!(pixel.to_be_bytes(), be);
+        assert_eq!(I16x4::from_le_bytes(le), pixel);
+        assert_eq!(I16x4::from_be_bytes(be), pixel);
+    }
+
+    #[test]
+    fn hex_is_channel_order() {
+        let pixel = I16x4::from_channels(-1, 0, 0x12, 0x7FFF);
+        assert_eq!(format!("{pixel:X}"), "FFFF000000127FFF");
+    }
+}
@@ -0,0 +1,144 @@
+use core::fmt::{LowerHex, UpperHex};
+
+use crate::pixel::raw::{ChannelRef, RawPixel};
+
+/// A raw pixel value represented as 2 8-bit signed integers.
+///
+/// Each channel is stored as one of the two bytes, in memory order, regardless of the host's
+/// endianness.
+///
+/// ## Layout
+///
+/// This struct is identical to a `[i8; 2]` in memory (`#[repr(transparent)]`).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct I8x2([i8; 2]);
+
+impl I8x2 {
+    /// Creates a new raw pixel value with all channels set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self([0; 2])
+    }
+
+    /// Creates a new raw pixel value from the given array of 2 8-bit signed integers.
+    #[must_use]
+    pub const fn from_i8x2(value: [i8; 2]) -> Self {
+        Self(value)
+    }
+
+    /// Creates a new raw pixel value from the given 2 8-bit channels.
+    #[must_use]
+    pub const fn from_channels(a: i8, b: i8) -> Self {
+        Self([a, b])
+    }
+}
+
+impl From<[i8; 2]> for I8x2 {
+    fn from(value: [i8; 2]) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for I8x2 {
+    const DEFAULT: Self = Self([0; 2]);
+    type Storage = [i8; 2];
+    type Bytes = [u8; 2];
+    type Channel = i8;
+
+    unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
+        unsafe { *self.0.get_unchecked(offset) }
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, offset: usize, value: Self::Channel) -> &mut Self {
+        *unsafe { self.0.get_unchecked_mut(offset) } = value;
+        self
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    array_bytes!(i8);
+}
+
+impl ChannelRef for I8x2 {
+    fn channel_ref(&self, offset: usize) -> &Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &self.0[offset]
+    }
+
+    fn channel_mut(&mut self, offset: usize) -> &mut Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &mut self.0[offset]
+    }
+}
+
+impl UpperHex for I8x2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02X}", byte.cast_unsigned())?;
+        }
+        Ok(())
+    }
+}
+
+impl LowerHex for I8x2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte.cast_unsigned())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for I8x2 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for I8x2 {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn new_zero() {
+        let pixel = I8x2::new_zeroed();
+        assert_eq!(pixel.into_inner(), [0; 2]);
+    }
+
+    #[test]
+    fn get_and_set_channel() {
+        let mut pixel = I8x2::from_channels(1, -2);
+        assert_eq!(I8x2::CHANNELS, 2);
+        assert_eq!(pixel.get_channel(1), -2);
+
+        pixel.set_channel(0, -128);
+        assert_eq!(pixel.into_inner(), [-128, -2]);
+    }
+
+    #[test]
+    fn hex_is_memory_order() {
+        let pixel = I8x2::from_channels(-85, 18);
+        assert_eq!(format!("{pixel:X}"), "AB12");
+    }
+}
@@ -0,0 +1,160 @@
+use core::fmt::{LowerHex, UpperHex};
+
+use crate::pixel::raw::{ChannelRef, RawPixel};
+
+/// A raw pixel value represented as 4 8-bit signed integers.
+///
+/// Each channel is stored as one of the four bytes, in memory order, regardless of the host's
+/// endianness.
+///
+/// ## Layout
+///
+/// This struct is identical to a `[i8; 4]` in memory (`#[repr(transparent)]`).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct I8x4([i8; 4]);
+
+impl I8x4 {
+    /// Creates a new raw pixel value with all channels set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self([0; 4])
+    }
+
+    /// Creates a new raw pixel value from the given array of 4 8-bit signed integers.
+    #[must_use]
+    pub const fn from_i8x4(value: [i8; 4]) -> Self {
+        Self(value)
+    }
+
+    /// Creates a new raw pixel value from the given 4 8-bit channels.
+    #[must_use]
+    pub const fn from_channels(a: i8, b: i8, c: i8, d: i8) -> Self {
+        Self([a, b, c, d])
+    }
+
+    /// Returns the 4 channels of the raw pixel value, in offset order.
+    #[must_use]
+    pub const fn to_channels(self) -> [i8; 4] {
+        self.0
+    }
+}
+
+impl From<[i8; 4]> for I8x4 {
+    fn from(value: [i8; 4]) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for I8x4 {
+    const DEFAULT: Self = Self([0; 4]);
+    type Storage = [i8; 4];
+    type Bytes = [u8; 4];
+    type Channel = i8;
+
+    unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
+        unsafe { *self.0.get_unchecked(offset) }
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, offset: usize, value: Self::Channel) -> &mut Self {
+        *unsafe { self.0.get_unchecked_mut(offset) } = value;
+        self
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    array_bytes!(i8);
+}
+
+impl ChannelRef for I8x4 {
+    fn channel_ref(&self, offset: usize) -> &Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &self.0[offset]
+    }
+
+    fn channel_mut(&mut self, offset: usize) -> &mut Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &mut self.0[offset]
+    }
+}
+
+impl UpperHex for I8x4 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02X}", byte.cast_unsigned())?;
+        }
+        Ok(())
+    }
+}
+
+impl LowerHex for I8x4 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte.cast_unsigned())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for I8x4 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for I8x4 {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::format;
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_cast() {
+        let pixel = I8x4::from([1, -1, 127, -128]);
+        let binding = [pixel];
+        let bytes: &[u8] = bytemuck::cast_slice(&binding);
+        assert_eq!(bytes, &[0x01, 0xFF, 0x7F, 0x80]);
+    }
+
+    #[test]
+    fn new_zero() {
+        let pixel = I8x4::new_zeroed();
+        assert_eq!(pixel.into_inner(), [0; 4]);
+    }
+
+    #[test]
+    fn get_and_set_channel() {
+        let mut pixel = I8x4::from_channels(1, -2, 3, -4);
+        assert_eq!(pixel.get_channel(0), 1);
+        assert_eq!(pixel.get_channel(3), -4);
+
+        pixel.set_channel(2, -128);
+        assert_eq!(pixel.into_inner(), [1, -2, -128, -4]);
+    }
+
+    #[test]
+    fn hex_is_memory_order() {
+        let pixel = I8x4::from_channels(-85, 0, 18, -1);
+        assert_eq!(format!("{pixel:X}"), "AB0012FF");
+        assert_eq!(format!("{pixel:x}"), "ab0012ff");
+    }
+}
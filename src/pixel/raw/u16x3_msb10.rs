@@ -0,0 +1,148 @@
+use core::fmt::{LowerHex, UpperHex};
+
+use crate::pixel::raw::{ChannelDescriptor, RawPixel};
+
+/// A raw pixel value represented as 3 16-bit unsigned integers, each holding a 10-bit channel in
+/// its most significant bits.
+///
+/// - Channel `n` is bits `6..16` of the `n`th integer.
+/// - Bits `0..6` of each integer are padding, and are always written as zero.
+///
+/// All channels are accessed as `u16` values in the range `0..=1023`, already shifted down; values
+/// written to a channel are truncated to 10 bits. Use [`RawPixel::as_inner`] to access the stored
+/// (shifted) integers.
+///
+/// ## Layout
+///
+/// This struct is identical to a `[u16; 3]` in memory (`#[repr(transparent)]`).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct U16x3Msb10([u16; 3]);
+
+impl U16x3Msb10 {
+    /// The number of padding bits below each channel.
+    const SHIFT: u32 = 6;
+
+    /// The mask of each channel, before shifting.
+    const MASK: u16 = 0x3FF;
+
+    /// Creates a new raw pixel value with all channels set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self([0; 3])
+    }
+
+    /// Creates a new raw pixel value from the given array of 3 stored (shifted) 16-bit integers.
+    ///
+    /// The padding bits are kept as-is.
+    #[must_use]
+    pub const fn from_u16x3(value: [u16; 3]) -> Self {
+        Self(value)
+    }
+}
+
+impl From<[u16; 3]> for U16x3Msb10 {
+    fn from(value: [u16; 3]) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for U16x3Msb10 {
+    const DEFAULT: Self = Self([0; 3]);
+    type Storage = [u16; 3];
+    type Bytes = [u8; 6];
+    type Channel = u16;
+
+    unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
+        unsafe { *self.0.get_unchecked(offset) >> Self::SHIFT }
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, offset: usize, value: Self::Channel) -> &mut Self {
+        *unsafe { self.0.get_unchecked_mut(offset) } = (value & Self::MASK) << Self::SHIFT;
+        self
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn channel_descriptor(offset: usize) -> ChannelDescriptor {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        ChannelDescriptor {
+            shift: offset as u32 * 16 + Self::SHIFT,
+            bits: Self::MASK.count_ones(),
+        }
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    array_bytes!(u16);
+}
+
+impl UpperHex for U16x3Msb10 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for stored in self.0 {
+            write!(f, "{stored:04X}")?;
+        }
+        Ok(())
+    }
+}
+
+impl LowerHex for U16x3Msb10 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for stored in self.0 {
+            write!(f, "{stored:04x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U16x3Msb10 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U16x3Msb10 {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn get_channel_shifts_down() {
+        let pixel = U16x3Msb10::from_u16x3([0xFFC0, 0x8000, 0x003F]);
+        assert_eq!(pixel.get_channel(0), 0x3FF);
+        assert_eq!(pixel.get_channel(1), 0x200);
+        assert_eq!(pixel.get_channel(2), 0);
+    }
+
+    #[test]
+    fn set_channel_shifts_up_and_truncates() {
+        let mut pixel = U16x3Msb10::from_u16x3([0x003F; 3]);
+        pixel.set_channel(0, 0x3FF).set_channel(1, 0x401);
+        assert_eq!(pixel.into_inner(), [0xFFC0, 0x0040, 0x003F]);
+    }
+
+    #[test]
+    fn hex_is_stored_value() {
+        let mut pixel = U16x3Msb10::new_zeroed();
+        pixel.set_channel(2, 1);
+        assert_eq!(format!("{pixel:X}"), "000000000040");
+    }
+}
@@ -0,0 +1,150 @@
+use core::fmt::{LowerHex, UpperHex};
+
+use crate::pixel::raw::{ChannelRef, RawPixel};
+
+/// A raw pixel value represented as 4 16-bit unsigned integers.
+///
+/// Each channel is stored as one of the four 16-bit integers, in memory order, in the host's byte
+/// order.
+///
+/// ## Layout
+///
+/// This struct is identical to a `[u16; 4]` in memory (`#[repr(transparent)]`).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct U16x4([u16; 4]);
+
+impl U16x4 {
+    /// Creates a new raw pixel value with all channels set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self([0; 4])
+    }
+
+    /// Creates a new raw pixel value from the given array of 4 16-bit unsigned integers.
+    #[must_use]
+    pub const fn from_u16x4(value: [u16; 4]) -> Self {
+        Self(value)
+    }
+
+    /// Creates a new raw pixel value from the given 4 16-bit channels.
+    #[must_use]
+    pub const fn from_channels(a: u16, b: u16, c: u16, d: u16) -> Self {
+        Self([a, b, c, d])
+    }
+
+    /// Returns the 4 channels of the raw pixel value, in offset order.
+    #[must_use]
+    pub const fn to_channels(self) -> [u16; 4] {
+        self.0
+    }
+}
+
+impl From<[u16; 4]> for U16x4 {
+    fn from(value: [u16; 4]) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for U16x4 {
+    const DEFAULT: Self = Self([0; 4]);
+    type Storage = [u16; 4];
+    type Bytes = [u8; 8];
+    type Channel = u16;
+
+    unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
+        unsafe { *self.0.get_unchecked(offset) }
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, offset: usize, value: Self::Channel) -> &mut Self {
+        *unsafe { self.0.get_unchecked_mut(offset) } = value;
+        self
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    array_bytes!(u16);
+}
+
+impl ChannelRef for U16x4 {
+    fn channel_ref(&self, offset: usize) -> &Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &self.0[offset]
+    }
+
+    fn channel_mut(&mut self, offset: usize) -> &mut Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &mut self.0[offset]
+    }
+}
+
+impl UpperHex for U16x4 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for channel in self.0 {
+            write!(f, "{channel:04X}")?;
+        }
+        Ok(())
+    }
+}
+
+impl LowerHex for U16x4 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for channel in self.0 {
+            write!(f, "{channel:04x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U16x4 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U16x4 {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn new_zero() {
+        let pixel = U16x4::new_zeroed();
+        assert_eq!(pixel.into_inner(), [0; 4]);
+    }
+
+    #[test]
+    fn get_and_set_channel() {
+        let mut pixel = U16x4::from_channels(1, 2, 0xFFFF, 4);
+        assert_eq!(pixel.get_channel(0), 1);
+        assert_eq!(pixel.get_channel(2), 0xFFFF);
+
+        pixel.set_channel(1, 0x1234);
+        assert_eq!(pixel.into_inner(), [1, 0x1234, 0xFFFF, 4]);
+    }
+
+    #[test]
+    fn hex_is_channel_order() {
+        let pixel = U16x4::from_u16x4([0xAB, 0, 0xFFFF, 1]);
+        assert_eq!(format!("{pixel:X}"), "00AB0000FFFF0001");
+    }
+}
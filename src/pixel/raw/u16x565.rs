@@ -0,0 +1,151 @@
+use core::fmt::{LowerHex, UpperHex};
+
+use crate::pixel::raw::{ChannelDescriptor, RawPixel};
+
+/// A raw pixel value represented as a 16-bit unsigned integer, split into a 5-bit, a 6-bit, and a
+/// 5-bit channel.
+///
+/// - Channel `0` is bits `11..16`.
+/// - Channel `1` is bits `5..11`.
+/// - Channel `2` is bits `0..5`.
+///
+/// All channels are accessed as `u16`; values written to a channel are truncated to its width.
+///
+/// ## Layout
+///
+/// This struct is identical to a `u16` in memory (`#[repr(transparent)]`).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct U16x565(u16);
+
+impl U16x565 {
+    /// The bit offset of each channel.
+    const SHIFTS: [u32; 3] = [11, 5, 0];
+
+    /// The mask of each channel, before shifting.
+    const MASKS: [u16; 3] = [0x1F, 0x3F, 0x1F];
+
+    /// Creates a new raw pixel value with all channels set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self(0)
+    }
+
+    /// Creates a new raw pixel value from the given 16-bit unsigned integer.
+    #[must_use]
+    pub const fn from_u16(value: u16) -> Self {
+        Self(value)
+    }
+}
+
+impl From<u16> for U16x565 {
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for U16x565 {
+    const DEFAULT: Self = Self(0);
+    const CHANNELS: usize = 3;
+    type Storage = u16;
+    type Bytes = [u8; 2];
+    type Channel = u16;
+
+    unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
+        (self.0 >> Self::SHIFTS[offset]) & Self::MASKS[offset]
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, offset: usize, value: Self::Channel) -> &mut Self {
+        let shift = Self::SHIFTS[offset];
+        let mask = Self::MASKS[offset];
+        self.0 = (self.0 & !(mask << shift)) | ((value & mask) << shift);
+        self
+    }
+
+    fn channel_descriptor(offset: usize) -> ChannelDescriptor {
+        ChannelDescriptor {
+            shift: Self::SHIFTS[offset],
+            bits: Self::MASKS[offset].count_ones(),
+        }
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    scalar_bytes!(u16);
+}
+
+impl UpperHex for U16x565 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:X}", self.into_inner())
+    }
+}
+
+impl LowerHex for U16x565 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:x}", self.into_inner())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U16x565 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U16x565 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_channel() {
+        let pixel = U16x565::from_u16(0x1F << 11 | 0x2A << 5 | 0x03);
+        assert_eq!(U16x565::CHANNELS, 3);
+        assert_eq!(pixel.get_channel(0), 0x1F);
+        assert_eq!(pixel.get_channel(1), 0x2A);
+        assert_eq!(pixel.get_channel(2), 0x03);
+    }
+
+    #[test]
+    fn channel_descriptor() {
+        assert_eq!(
+            U16x565::channel_descriptor(1),
+            ChannelDescriptor { shift: 5, bits: 6 }
+        );
+    }
+
+    #[test]
+    fn set_channel() {
+        let mut pixel = U16x565::new_zeroed();
+        pixel.set_channel(1, 0xFFFF);
+        assert_eq!(pixel.into_inner(), 0x3F << 5);
+
+        pixel.set_channel(0, 0x1F);
+        assert_eq!(pixel.into_inner(), 0xFFE0);
+
+        pixel.set_channel(1, 0);
+        assert_eq!(pixel.into_inner(), 0xF800);
+    }
+
+    #[test]
+    #[should_panic(expected = "Offset out of bounds")]
+    fn get_channel_out_of_bounds() {
+        let _ = U16x565::new_zeroed().get_channel(3);
+    }
+}
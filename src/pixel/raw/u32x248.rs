@@ -0,0 +1,164 @@
+use core::fmt::{LowerHex, UpperHex};
+
+use crate::pixel::raw::{ChannelDescriptor, RawPixel};
+
+/// A raw pixel value represented as a 32-bit unsigned integer, split into a 24-bit and 8-bit channel.
+///
+/// - Channel `0` is the low 24 bits.
+/// - Channel `1` is the high 8 bits.
+///
+/// Both channels are accessed as `u32`; values written to a channel are truncated to its width.
+///
+/// ## Layout
+///
+/// This struct is identical to a `u32` in memory (`#[repr(transparent)]`).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct U32x248(u32);
+
+impl U32x248 {
+    /// The mask of the low 24-bit channel.
+    const LOW_MASK: u32 = 0x00FF_FFFF;
+
+    /// Creates a new raw pixel value with all channels set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self(0)
+    }
+
+    /// Creates a new raw pixel value from the given 32-bit unsigned integer.
+    #[must_use]
+    pub const fn from_u32(value: u32) -> Self {
+        Self(value)
+    }
+
+    /// Creates a new raw pixel value from the given 24-bit and 8-bit channels.
+    ///
+    /// Bits of `low` beyond the first 24 are ignored.
+    #[must_use]
+    pub const fn from_channels(low: u32, high: u8) -> Self {
+        Self((low & Self::LOW_MASK) | (high as u32) << 24)
+    }
+}
+
+impl From<u32> for U32x248 {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for U32x248 {
+    const DEFAULT: Self = Self(0);
+    const CHANNELS: usize = 2;
+    type Storage = u32;
+    type Bytes = [u8; 4];
+    type Channel = u32;
+
+    unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
+        if offset == 0 {
+            self.0 & Self::LOW_MASK
+        } else {
+            self.0 >> 24
+        }
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, offset: usize, value: Self::Channel) -> &mut Self {
+        if offset == 0 {
+            self.0 = (self.0 & !Self::LOW_MASK) | (value & Self::LOW_MASK);
+        } else {
+            self.0 = (self.0 & Self::LOW_MASK) | (value << 24);
+        }
+        self
+    }
+
+    fn channel_descriptor(offset: usize) -> ChannelDescriptor {
+        match offset {
+            0 => ChannelDescriptor { shift: 0, bits: 24 },
+            1 => ChannelDescriptor { shift: 24, bits: 8 },
+            _ => panic!("Offset out of bounds"),
+        }
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    scalar_bytes!(u32);
+}
+
+impl UpperHex for U32x248 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:X}", self.into_inner())
+    }
+}
+
+impl LowerHex for U32x248 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:x}", self.into_inner())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U32x248 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U32x248 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_channels() {
+        let pixel = U32x248::from_channels(0x12_3456, 0x78);
+        assert_eq!(pixel.into_inner(), 0x7812_3456);
+    }
+
+    #[test]
+    fn from_channels_truncates_low() {
+        let pixel = U32x248::from_channels(0xFF12_3456, 0x78);
+        assert_eq!(pixel.into_inner(), 0x7812_3456);
+    }
+
+    #[test]
+    fn get_channel() {
+        let pixel = U32x248::from_u32(0xAB12_3456);
+        assert_eq!(U32x248::CHANNELS, 2);
+        assert_eq!(pixel.get_channel(0), 0x12_3456);
+        assert_eq!(pixel.get_channel(1), 0xAB);
+    }
+
+    #[test]
+    fn set_channel() {
+        let mut pixel = U32x248::new_zeroed();
+        pixel.set_channel(0, 0xFFFF_FFFF);
+        assert_eq!(pixel.into_inner(), 0x00FF_FFFF);
+
+        pixel.set_channel(1, 0x1FF);
+        assert_eq!(pixel.into_inner(), 0xFFFF_FFFF);
+
+        pixel.set_channel(1, 0x01);
+        assert_eq!(pixel.into_inner(), 0x01FF_FFFF);
+    }
+
+    #[test]
+    #[should_panic(expected = "Offset out of bounds")]
+    fn get_channel_out_of_bounds() {
+        let _ = U32x248::new_zeroed().get_channel(2);
+    }
+}
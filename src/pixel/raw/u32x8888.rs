@@ -9,7 +9,18 @@ use crate::pixel::raw::RawPixel;
 /// ## Layout
 ///
 /// This struct is identical to a `u32` in memory (`#[repr(transparent)]`).
-#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
 #[repr(transparent)]
 pub struct U32x8888(u32);
 
@@ -31,6 +42,12 @@ impl U32x8888 {
     pub const fn from_channels(a: u8, b: u8, c: u8, d: u8) -> Self {
         Self(a as u32 | (b as u32) << 8 | (c as u32) << 16 | (d as u32) << 24)
     }
+
+    /// Returns the 4 channels of the raw pixel value, in offset order.
+    #[must_use]
+    pub const fn to_channels(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
 }
 
 impl From<u32> for U32x8888 {
@@ -42,6 +59,7 @@ impl From<u32> for U32x8888 {
 impl RawPixel for U32x8888 {
     const DEFAULT: Self = Self(0);
     type Storage = u32;
+    type Bytes = [u8; 4];
     type Channel = u8;
 
     unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
@@ -61,6 +79,8 @@ impl RawPixel for U32x8888 {
     fn into_inner(self) -> Self::Storage {
         self.0
     }
+
+    scalar_bytes!(u32);
 }
 
 impl UpperHex for U32x8888 {
@@ -0,0 +1,200 @@
+use core::fmt::{LowerHex, UpperHex};
+
+use crate::pixel::raw::{ChannelDescriptor, ChannelRef, RawPixel};
+
+/// A raw pixel value represented as a 32-bit unsigned integer, stored in little-endian byte order.
+///
+/// Each channel is stored as one of the four 8-bit components, where channel `0` is the least
+/// significant byte of the integer. Unlike [`U32x8888`], which uses the host's byte order, the
+/// integer is always stored little-endian, so channel `0` is the first byte in memory on any
+/// host.
+///
+/// ## Layout
+///
+/// This struct is identical to a `[u8; 4]` in memory (`#[repr(transparent)]`).
+///
+/// [`U32x8888`]: crate::pixel::raw::U32x8888
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct U32x8888Le([u8; 4]);
+
+impl U32x8888Le {
+    /// Creates a new raw pixel value with all channels set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self([0; 4])
+    }
+
+    /// Creates a new raw pixel value from the given 32-bit unsigned integer.
+    #[must_use]
+    pub const fn from_u32(value: u32) -> Self {
+        Self(value.to_le_bytes())
+    }
+
+    /// Returns the value as a 32-bit unsigned integer.
+    #[must_use]
+    pub const fn to_u32(self) -> u32 {
+        u32::from_le_bytes(self.0)
+    }
+
+    /// Creates a new raw pixel value from the given 4 8-bit channels.
+    #[must_use]
+    pub const fn from_channels(a: u8, b: u8, c: u8, d: u8) -> Self {
+        Self::from_u32(a as u32 | (b as u32) << 8 | (c as u32) << 16 | (d as u32) << 24)
+    }
+
+    /// Returns the 4 channels of the raw pixel value, in offset order.
+    #[must_use]
+    pub const fn to_channels(self) -> [u8; 4] {
+        self.to_u32().to_le_bytes()
+    }
+
+    /// Returns the index in memory of the byte of the channel at `offset`.
+    const fn byte_index(offset: usize) -> usize {
+        offset
+    }
+}
+
+impl From<[u8; 4]> for U32x8888Le {
+    /// Creates a new raw pixel value from the given bytes, in memory order.
+    fn from(value: [u8; 4]) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for U32x8888Le {
+    const DEFAULT: Self = Self([0; 4]);
+    type Storage = [u8; 4];
+    type Bytes = [u8; 4];
+    type Channel = u8;
+
+    unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
+        unsafe { *self.0.get_unchecked(Self::byte_index(offset)) }
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, offset: usize, value: Self::Channel) -> &mut Self {
+        *unsafe { self.0.get_unchecked_mut(Self::byte_index(offset)) } = value;
+        self
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn channel_descriptor(offset: usize) -> ChannelDescriptor {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        ChannelDescriptor {
+            shift: Self::byte_index(offset) as u32 * 8,
+            bits: 8,
+        }
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.to_u32().to_le_bytes()
+    }
+
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.to_u32().to_be_bytes()
+    }
+
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        Self::from_u32(u32::from_le_bytes(bytes))
+    }
+
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Self::from_u32(u32::from_be_bytes(bytes))
+    }
+}
+
+impl ChannelRef for U32x8888Le {
+    fn channel_ref(&self, offset: usize) -> &Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &self.0[Self::byte_index(offset)]
+    }
+
+    fn channel_mut(&mut self, offset: usize) -> &mut Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &mut self.0[Self::byte_index(offset)]
+    }
+}
+
+impl UpperHex for U32x8888Le {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:X}", self.to_u32())
+    }
+}
+
+impl LowerHex for U32x8888Le {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:x}", self.to_u32())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U32x8888Le {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U32x8888Le {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::format;
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_cast() {
+        let pixel = U32x8888Le::from_u32(0x0403_0201);
+        let binding = [pixel];
+        let bytes: &[u8] = bytemuck::cast_slice(&binding);
+        assert_eq!(bytes, &[0x01, 0x02, 0x03, 0x04]);
+    }
+
+    #[test]
+    fn from_u32() {
+        let pixel = U32x8888Le::from_u32(0x0403_0201);
+        assert_eq!(pixel.into_inner(), [0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(pixel.to_u32(), 0x0403_0201);
+    }
+
+    #[test]
+    fn from_channels() {
+        let pixel = U32x8888Le::from_channels(0x01, 0x02, 0x03, 0x04);
+        assert_eq!(pixel.to_u32(), 0x0403_0201);
+    }
+
+    #[test]
+    fn get_and_set_channel() {
+        let mut pixel = U32x8888Le::from([0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(pixel.get_channel(0), 0x01);
+        assert_eq!(pixel.get_channel(3), 0x04);
+
+        pixel.set_channel(0, 0xFF);
+        assert_eq!(pixel.to_u32(), 0x0403_02FF);
+    }
+
+    #[test]
+    fn hex_is_integer_value() {
+        let pixel = U32x8888Le::from_u32(0xAB00_12FF);
+        assert_eq!(format!("{pixel:X}"), "AB0012FF");
+        assert_eq!(format!("{pixel:x}"), "ab0012ff");
+    }
+}
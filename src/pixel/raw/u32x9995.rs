@@ -0,0 +1,145 @@
+use core::fmt::{LowerHex, UpperHex};
+
+use crate::pixel::raw::{ChannelDescriptor, RawPixel};
+
+/// A raw pixel value represented as a 32-bit unsigned integer, split into three 9-bit channels and
+/// a 5-bit channel.
+///
+/// - Channel `0` is bits `0..9`.
+/// - Channel `1` is bits `9..18`.
+/// - Channel `2` is bits `18..27`.
+/// - Channel `3` is bits `27..32`.
+///
+/// All channels are accessed as `u32`; values written to a channel are truncated to its width.
+///
+/// ## Layout
+///
+/// This struct is identical to a `u32` in memory (`#[repr(transparent)]`).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct U32x9995(u32);
+
+impl U32x9995 {
+    /// The bit offset of each channel.
+    const SHIFTS: [u32; 4] = [0, 9, 18, 27];
+
+    /// The mask of each channel, before shifting.
+    const MASKS: [u32; 4] = [0x1FF, 0x1FF, 0x1FF, 0x1F];
+
+    /// Creates a new raw pixel value with all channels set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self(0)
+    }
+
+    /// Creates a new raw pixel value from the given 32-bit unsigned integer.
+    #[must_use]
+    pub const fn from_u32(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<u32> for U32x9995 {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for U32x9995 {
+    const DEFAULT: Self = Self(0);
+    const CHANNELS: usize = 4;
+    type Storage = u32;
+    type Bytes = [u8; 4];
+    type Channel = u32;
+
+    unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
+        (self.0 >> Self::SHIFTS[offset]) & Self::MASKS[offset]
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, offset: usize, value: Self::Channel) -> &mut Self {
+        let shift = Self::SHIFTS[offset];
+        let mask = Self::MASKS[offset];
+        self.0 = (self.0 & !(mask << shift)) | ((value & mask) << shift);
+        self
+    }
+
+    fn channel_descriptor(offset: usize) -> ChannelDescriptor {
+        ChannelDescriptor {
+            shift: Self::SHIFTS[offset],
+            bits: Self::MASKS[offset].count_ones(),
+        }
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    scalar_bytes!(u32);
+}
+
+impl UpperHex for U32x9995 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:X}", self.into_inner())
+    }
+}
+
+impl LowerHex for U32x9995 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:x}", self.into_inner())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U32x9995 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U32x9995 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_channel() {
+        let pixel = U32x9995::from_u32(0b10101_000000011_000000010_000000001);
+        assert_eq!(U32x9995::CHANNELS, 4);
+        assert_eq!(pixel.get_channel(0), 1);
+        assert_eq!(pixel.get_channel(1), 2);
+        assert_eq!(pixel.get_channel(2), 3);
+        assert_eq!(pixel.get_channel(3), 0b10101);
+    }
+
+    #[test]
+    fn set_channel() {
+        let mut pixel = U32x9995::new_zeroed();
+        pixel.set_channel(1, 0xFFFF_FFFF);
+        assert_eq!(pixel.into_inner(), 0x1FF << 9);
+
+        pixel.set_channel(3, 0x3F);
+        assert_eq!(pixel.into_inner(), (0x1F << 27) | (0x1FF << 9));
+
+        pixel.set_channel(1, 0);
+        assert_eq!(pixel.into_inner(), 0x1F << 27);
+    }
+
+    #[test]
+    #[should_panic(expected = "Offset out of bounds")]
+    fn get_channel_out_of_bounds() {
+        let _ = U32x9995::new_zeroed().get_channel(4);
+    }
+}
@@ -0,0 +1,140 @@
+use core::fmt::{LowerHex, UpperHex};
+
+use crate::pixel::raw::{ChannelRef, RawPixel};
+
+/// A raw pixel value represented as a single 8-bit unsigned integer.
+///
+/// The pixel has exactly one channel, which is the entire byte.
+///
+/// ## Layout
+///
+/// This struct is identical to a `u8` in memory (`#[repr(transparent)]`).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct U8x1(u8);
+
+impl U8x1 {
+    /// Creates a new raw pixel value set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self(0)
+    }
+
+    /// Creates a new raw pixel value from the given 8-bit unsigned integer.
+    #[must_use]
+    pub const fn from_u8(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl From<u8> for U8x1 {
+    fn from(value: u8) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for U8x1 {
+    const DEFAULT: Self = Self(0);
+    type Storage = u8;
+    type Bytes = [u8; 1];
+    type Channel = u8;
+
+    unsafe fn get_channel_unchecked(&self, _offset: usize) -> Self::Channel {
+        self.0
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, _offset: usize, value: Self::Channel) -> &mut Self {
+        self.0 = value;
+        self
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    scalar_bytes!(u8);
+}
+
+impl ChannelRef for U8x1 {
+    fn channel_ref(&self, offset: usize) -> &Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &self.0
+    }
+
+    fn channel_mut(&mut self, offset: usize) -> &mut Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &mut self.0
+    }
+}
+
+impl UpperHex for U8x1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:02X}", self.0)
+    }
+}
+
+impl LowerHex for U8x1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:02x}", self.0)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U8x1 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U8x1 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_cast() {
+        let pixels = [U8x1::from(0x01), U8x1::from(0x02)];
+        let bytes: &[u8] = bytemuck::cast_slice(&pixels);
+        assert_eq!(bytes, &[0x01, 0x02]);
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn zerocopy_as_bytes() {
+        use zerocopy::IntoBytes;
+
+        let pixels = [U8x1::from(0x01), U8x1::from(0x02)];
+        assert_eq!(pixels.as_bytes(), &[0x01, 0x02]);
+    }
+
+    #[test]
+    fn single_channel() {
+        assert_eq!(U8x1::CHANNELS, 1);
+
+        let mut pixel = U8x1::from_u8(0x7F);
+        assert_eq!(pixel.get_channel(0), 0x7F);
+
+        pixel.set_channel(0, 0x01);
+        assert_eq!(pixel.into_inner(), 0x01);
+    }
+
+    #[test]
+    #[should_panic(expected = "Offset out of bounds")]
+    fn get_channel_out_of_bounds() {
+        let _ = U8x1::new_zeroed().get_channel(1);
+    }
+}
@@ -0,0 +1,160 @@
+use core::fmt::{LowerHex, UpperHex};
+
+use crate::pixel::raw::{ChannelRef, RawPixel};
+
+/// A raw pixel value represented as 3 8-bit unsigned integers.
+///
+/// Each channel is stored as one of the three bytes, in memory order, regardless of the host's
+/// endianness.
+///
+/// ## Layout
+///
+/// This struct is identical to a `[u8; 3]` in memory (`#[repr(transparent)]`).
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct U8x3([u8; 3]);
+
+impl U8x3 {
+    /// Creates a new raw pixel value with all channels set to zero.
+    #[must_use]
+    pub const fn new_zeroed() -> Self {
+        Self([0; 3])
+    }
+
+    /// Creates a new raw pixel value from the given array of 3 8-bit unsigned integers.
+    #[must_use]
+    pub const fn from_u8x3(value: [u8; 3]) -> Self {
+        Self(value)
+    }
+
+    /// Creates a new raw pixel value from the given 3 8-bit channels.
+    #[must_use]
+    pub const fn from_channels(a: u8, b: u8, c: u8) -> Self {
+        Self([a, b, c])
+    }
+}
+
+impl From<[u8; 3]> for U8x3 {
+    fn from(value: [u8; 3]) -> Self {
+        Self(value)
+    }
+}
+
+impl RawPixel for U8x3 {
+    const DEFAULT: Self = Self([0; 3]);
+    type Storage = [u8; 3];
+    type Bytes = [u8; 3];
+    type Channel = u8;
+
+    unsafe fn get_channel_unchecked(&self, offset: usize) -> Self::Channel {
+        unsafe { *self.0.get_unchecked(offset) }
+    }
+
+    unsafe fn set_channel_unchecked(&mut self, offset: usize, value: Self::Channel) -> &mut Self {
+        *unsafe { self.0.get_unchecked_mut(offset) } = value;
+        self
+    }
+
+    fn as_inner(&self) -> &Self::Storage {
+        &self.0
+    }
+
+    fn into_inner(self) -> Self::Storage {
+        self.0
+    }
+
+    array_bytes!(u8);
+}
+
+impl ChannelRef for U8x3 {
+    fn channel_ref(&self, offset: usize) -> &Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &self.0[offset]
+    }
+
+    fn channel_mut(&mut self, offset: usize) -> &mut Self::Channel {
+        assert!(offset < Self::CHANNELS, "Offset out of bounds");
+        &mut self.0[offset]
+    }
+}
+
+impl UpperHex for U8x3 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02X}")?;
+        }
+        Ok(())
+    }
+}
+
+impl LowerHex for U8x3 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for U8x3 {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for U8x3 {}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use super::*;
+    use alloc::format;
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_cast() {
+        let pixel = U8x3::from([0x01, 0x02, 0x03]);
+        let binding = [pixel];
+        let bytes: &[u8] = bytemuck::cast_slice(&binding);
+        assert_eq!(bytes, &[0x01, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn new_zero() {
+        let pixel = U8x3::new_zeroed();
+        assert_eq!(pixel.into_inner(), [0; 3]);
+    }
+
+    #[test]
+    fn from_channels() {
+        let pixel = U8x3::from_channels(0x01, 0x02, 0x03);
+        assert_eq!(pixel.as_inner(), &[0x01, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn get_and_set_channel() {
+        let mut pixel = U8x3::from_u8x3([0x01, 0x02, 0x03]);
+        assert_eq!(pixel.get_channel(0), 0x01);
+        assert_eq!(pixel.get_channel(2), 0x03);
+
+        pixel.set_channel(1, 0xFF);
+        assert_eq!(pixel.into_inner(), [0x01, 0xFF, 0x03]);
+    }
+
+    #[test]
+    fn hex_is_memory_order() {
+        let pixel = U8x3::from_channels(0xAB, 0x00, 0x12);
+        assert_eq!(format!("{pixel:X}"), "AB0012");
+        assert_eq!(format!("{pixel:x}"), "ab0012");
+    }
+}
@@ -0,0 +1,413 @@
+//! Bulk operations over slices of pixels.
+//!
+//! [`PixelSlice`] is a `#[repr(transparent)]` wrapper over `[Pixel<F>]`, which dereferences to the
+//! underlying slice and adds operations over every pixel in it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::rgba::{FloatRgba, Rgba, Rgba8888},
+//!     pixel::{Pixel, slice::PixelSlice},
+//! };
+//!
+//! let mut pixels = [Pixel::<Rgba8888>::zeroed(); 4];
+//! let slice = PixelSlice::new_mut(&mut pixels);
+//! slice.fill(Pixel::with_rgba(0xFF, 0x80, 0x00, 0x80));
+//! slice.premultiply_all();
+//!
+//! let mut alpha = [0; 4];
+//! slice.extract_channel(Rgba::Alpha, &mut alpha);
+//! assert_eq!(alpha, [0x80; 4]);
+//!
+//! let mut floats = [Pixel::<FloatRgba>::zeroed(); 4];
+//! slice.convert_into(&mut floats);
+//! ```
+
+use core::{
+    fmt::{Debug, Formatter},
+    ops::{Deref, DerefMut},
+};
+
+use crate::{
+    convert::{ConvertFrom, convert_slice},
+    formats::{
+        indexed::{ColorMetric, Indexed8, Palette},
+        rgba::{RgbaFormat, planar},
+    },
+    norm::Normalized,
+    pixel::{ChannelOf, Format, Pixel, raw::RawPixel},
+};
+
+/// A slice of pixels in the format `F`, with bulk operations.
+///
+/// ## Layout
+///
+/// This struct is identical to a `[Pixel<F>]` in memory (`#[repr(transparent)]`).
+#[repr(transparent)]
+pub struct PixelSlice<F: Format>([Pixel<F>]);
+
+impl<F: Format> PixelSlice<F> {
+    /// Wraps a slice of pixels.
+    #[must_use]
+    pub fn new(pixels: &[Pixel<F>]) -> &Self {
+        // SAFETY: `PixelSlice` is `#[repr(transparent)]` over `[Pixel<F>]`.
+        unsafe { &*(core::ptr::from_ref(pixels) as *const Self) }
+    }
+
+    /// Wraps a mutable slice of pixels.
+    #[must_use]
+    pub fn new_mut(pixels: &mut [Pixel<F>]) -> &mut Self {
+        // SAFETY: `PixelSlice` is `#[repr(transparent)]` over `[Pixel<F>]`.
+        unsafe { &mut *(core::ptr::from_mut(pixels) as *mut Self) }
+    }
+
+    /// Returns the underlying slice of pixels.
+    #[must_use]
+    pub const fn as_pixels(&self) -> &[Pixel<F>] {
+        &self.0
+    }
+
+    /// Returns the underlying mutable slice of pixels.
+    #[must_use]
+    pub fn as_pixels_mut(&mut self) -> &mut [Pixel<F>] {
+        &mut self.0
+    }
+
+    /// Sets every pixel to `pixel`.
+    pub fn fill(&mut self, pixel: Pixel<F>) {
+        self.0.fill(pixel);
+    }
+
+    /// Calls `f` with a mutable reference to every pixel, in order.
+    pub fn for_each_mut(&mut self, f: impl FnMut(&mut Pixel<F>)) {
+        self.0.iter_mut().for_each(f);
+    }
+
+    /// Converts every pixel to the format of `dst`.
+    ///
+    /// See [`convert_slice`].
+    ///
+    /// ## Panics
+    ///
+    /// If `dst` has a different length.
+    pub fn convert_into<G: Format>(&self, dst: &mut [Pixel<G>])
+    where
+        Pixel<G>: ConvertFrom<Pixel<F>>,
+    {
+        convert_slice(&self.0, dst);
+    }
+
+    /// Copies the value of `channel` of every pixel into `dst`, as a single channel plane.
+    ///
+    /// ## Panics
+    ///
+    /// If `dst` has a different length.
+    pub fn extract_channel(
+        &self,
+        channel: F::Channels,
+        dst: &mut [<F::RawPixel as RawPixel>::Channel],
+    ) where
+        F::Channels: ChannelOf<F>,
+    {
+        assert_eq!(self.0.len(), dst.len(), "Slices must have the same length");
+        for (dst, pixel) in dst.iter_mut().zip(&self.0) {
+            *dst = pixel.get(channel);
+        }
+    }
+
+    /// Sets `channel` of every pixel from `src`, a single channel plane.
+    ///
+    /// This is the inverse of [`extract_channel`](PixelSlice::extract_channel).
+    ///
+    /// ## Panics
+    ///
+    /// If `src` has a different length.
+    pub fn insert_channel(
+        &mut self,
+        channel: F::Channels,
+        src: &[<F::RawPixel as RawPixel>::Channel],
+    ) where
+        F::Channels: ChannelOf<F>,
+        <F::RawPixel as RawPixel>::Channel: Copy,
+    {
+        assert_eq!(self.0.len(), src.len(), "Slices must have the same length");
+        for (pixel, value) in self.0.iter_mut().zip(src) {
+            pixel.set(channel, *value);
+        }
+    }
+}
+
+impl<F: RgbaFormat> PixelSlice<F> {
+    /// Deinterleaves every pixel into four channel planes, in RGBA order.
+    ///
+    /// This is the inverse of [`merge_planes`](PixelSlice::merge_planes).
+    ///
+    /// ## Panics
+    ///
+    /// If any plane has a different length than the slice.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::rgba::Rgba8888, pixel::{Pixel, slice::PixelSlice}};
+    ///
+    /// let pixels = [
+    ///     Pixel::<Rgba8888>::with_rgba(1, 2, 3, 4),
+    ///     Pixel::<Rgba8888>::with_rgba(5, 6, 7, 8),
+    /// ];
+    /// let (mut r, mut g, mut b, mut a) = ([0; 2], [0; 2], [0; 2], [0; 2]);
+    /// PixelSlice::new(&pixels).split_planes([&mut r, &mut g, &mut b, &mut a]);
+    /// assert_eq!((r, g, b, a), ([1, 5], [2, 6], [3, 7], [4, 8]));
+    ///
+    /// let mut merged = [Pixel::zeroed(); 2];
+    /// PixelSlice::new_mut(&mut merged).merge_planes([&r, &g, &b, &a]);
+    /// assert_eq!(merged, pixels);
+    /// ```
+    pub fn split_planes(&self, planes: [&mut [<F::RawPixel as RawPixel>::Channel]; 4]) {
+        assert_planes_len(self.0.len(), planes.each_ref().map(|plane| plane.len()));
+        let [r, g, b, a] = planes;
+        planar::deinterleave_rgb(&self.0, r, g, b, Some(a));
+    }
+
+    /// Interleaves four channel planes, in RGBA order, into every pixel.
+    ///
+    /// ## Panics
+    ///
+    /// If any plane has a different length than the slice.
+    pub fn merge_planes(&mut self, planes: [&[<F::RawPixel as RawPixel>::Channel]; 4])
+    where
+        <F::RawPixel as RawPixel>::Channel: Copy,
+    {
+        assert_planes_len(self.0.len(), planes.map(<[_]>::len));
+        let [r, g, b, a] = planes;
+        planar::interleave_rgba(r, g, b, a, &mut self.0);
+    }
+}
+
+/// Asserts that every plane has the same length as the slice of pixels.
+fn assert_planes_len(len: usize, planes: [usize; 4]) {
+    assert!(
+        planes.iter().all(|&plane| plane == len),
+        "Planes must have the same length as the slice"
+    );
+}
+
+impl<F> PixelSlice<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    /// Premultiplies every pixel by its alpha channel.
+    ///
+    /// See [`Pixel::premultiply`].
+    pub fn premultiply_all(&mut self) {
+        self.for_each_mut(|pixel| *pixel = pixel.premultiply());
+    }
+
+    /// Divides every pixel by its alpha channel.
+    ///
+    /// See [`Pixel::unpremultiply`].
+    pub fn unpremultiply_all(&mut self) {
+        self.for_each_mut(|pixel| *pixel = pixel.unpremultiply());
+    }
+
+    /// Composites every pixel of `src` over the corresponding pixel of this slice, in place.
+    ///
+    /// This is the bulk counterpart of [`Pixel::over`]; both slices must have premultiplied alpha.
+    ///
+    /// ## Panics
+    ///
+    /// If `src` has a different length.
+    pub fn composite_over(&mut self, src: &PixelSlice<F>) {
+        assert_eq!(
+            self.0.len(),
+            src.0.len(),
+            "Slices must have the same length"
+        );
+        for (dst, src) in self.0.iter_mut().zip(&src.0) {
+            *dst = src.over(dst);
+        }
+    }
+
+    /// Writes the index of the color in `palette` nearest to every pixel into `dst`.
+    ///
+    /// See [`Pixel::nearest_in`].
+    ///
+    /// ## Panics
+    ///
+    /// If the palette is empty, or `dst` has a different length.
+    pub fn quantize<P>(&self, palette: &Palette<P>, dst: &mut [Pixel<Indexed8>])
+    where
+        P: RgbaFormat,
+        P::RawPixel: RawPixel<Channel: Normalized>,
+    {
+        self.quantize_with(palette, ColorMetric::Rgb, dst);
+    }
+
+    /// Writes the index of the color in `palette` nearest to every pixel by the given metric into
+    /// `dst`.
+    ///
+    /// See [`Pixel::nearest_in_with`].
+    ///
+    /// ## Panics
+    ///
+    /// If the palette is empty, or `dst` has a different length.
+    pub fn quantize_with<P>(
+        &self,
+        palette: &Palette<P>,
+        metric: ColorMetric,
+        dst: &mut [Pixel<Indexed8>],
+    ) where
+        P: RgbaFormat,
+        P::RawPixel: RawPixel<Channel: Normalized>,
+    {
+        assert_eq!(self.0.len(), dst.len(), "Slices must have the same length");
+        for (dst, pixel) in dst.iter_mut().zip(&self.0) {
+            *dst = pixel.nearest_in_with(palette, metric);
+        }
+    }
+}
+
+impl<F: Format> Deref for PixelSlice<F> {
+    type Target = [Pixel<F>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<F: Format> DerefMut for PixelSlice<F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<F: Format> AsRef<[Pixel<F>]> for PixelSlice<F> {
+    fn as_ref(&self) -> &[Pixel<F>] {
+        &self.0
+    }
+}
+
+impl<F: Format> AsMut<[Pixel<F>]> for PixelSlice<F> {
+    fn as_mut(&mut self) -> &mut [Pixel<F>] {
+        &mut self.0
+    }
+}
+
+impl<'a, F: Format> From<&'a [Pixel<F>]> for &'a PixelSlice<F> {
+    fn from(pixels: &'a [Pixel<F>]) -> Self {
+        PixelSlice::new(pixels)
+    }
+}
+
+impl<'a, F: Format> From<&'a mut [Pixel<F>]> for &'a mut PixelSlice<F> {
+    fn from(pixels: &'a mut [Pixel<F>]) -> Self {
+        PixelSlice::new_mut(pixels)
+    }
+}
+
+impl<F: Format> Debug for PixelSlice<F>
+where
+    Pixel<F>: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(&self.0).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{Abgr8888, Rgba, Rgba8888};
+
+    use super::*;
+
+    #[test]
+    fn fill_and_for_each_mut() {
+        let mut pixels = [Pixel::<Rgba8888>::zeroed(); 3];
+        let slice = PixelSlice::new_mut(&mut pixels);
+        slice.fill(Pixel::with_rgba(1, 2, 3, 4));
+        slice.for_each_mut(|pixel| {
+            pixel.set_red(0xFF);
+        });
+        assert_eq!(pixels, [Pixel::with_rgba(0xFF, 2, 3, 4); 3]);
+    }
+
+    #[test]
+    fn convert_into() {
+        let pixels = [Pixel::<Rgba8888>::with_rgba(1, 2, 3, 4); 2];
+        let mut abgr = [Pixel::<Abgr8888>::zeroed(); 2];
+        PixelSlice::new(&pixels).convert_into(&mut abgr);
+        assert_eq!(abgr[1].channels(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn premultiply_all() {
+        let mut pixels = [Pixel::<Rgba8888>::with_rgba(0xFF, 0x80, 0x00, 0x80); 2];
+        PixelSlice::new_mut(&mut pixels).premultiply_all();
+        assert_eq!(pixels[0].channels(), [0x80, 0x40, 0x00, 0x80]);
+    }
+
+    #[test]
+    fn composite_over() {
+        let mut dst = [Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0xFF, 0xFF); 2];
+        let src = [
+            Pixel::with_rgba(0x80, 0x00, 0x00, 0x80),
+            Pixel::with_rgba(0x00, 0xFF, 0x00, 0xFF),
+        ];
+        PixelSlice::new_mut(&mut dst).composite_over(PixelSlice::new(&src));
+        assert_eq!(dst[0], Pixel::with_rgba(0x80, 0x00, 0x7F, 0xFF));
+        assert_eq!(dst[1], src[1]);
+    }
+
+    #[test]
+    fn quantize() {
+        let black = Pixel::<Rgba8888>::with_rgba(0x00, 0x00, 0x00, 0xFF);
+        let white = Pixel::<Rgba8888>::with_rgba(0xFF, 0xFF, 0xFF, 0xFF);
+        let palette = Palette::from_slice(&[black, white]);
+        let pixels = [white, Pixel::with_rgba(0x20, 0x20, 0x20, 0xFF), black];
+        let mut indexed = [Pixel::zeroed(); 3];
+        PixelSlice::new(&pixels).quantize(&palette, &mut indexed);
+        assert_eq!(indexed, [1, 0, 0].map(Pixel::with_index));
+    }
+
+    #[test]
+    #[should_panic = "Planes must have the same length as the slice"]
+    fn split_planes_wrong_length() {
+        let pixels = [Pixel::<Rgba8888>::zeroed(); 2];
+        let (mut r, mut g, mut b, mut a) = ([0; 2], [0; 2], [0; 1], [0; 2]);
+        PixelSlice::new(&pixels).split_planes([&mut r, &mut g, &mut b, &mut a]);
+    }
+
+    #[test]
+    fn split_and_merge_planes() {
+        let pixels = [
+            Pixel::<Abgr8888>::with_rgba(1, 2, 3, 4),
+            Pixel::<Abgr8888>::with_rgba(5, 6, 7, 8),
+        ];
+        let mut planes = [[0; 2]; 4];
+        let [r, g, b, a] = &mut planes;
+        PixelSlice::new(&pixels).split_planes([r, g, b, a]);
+        assert_eq!(planes, [[1, 5], [2, 6], [3, 7], [4, 8]]);
+
+        let mut merged = [Pixel::<Abgr8888>::zeroed(); 2];
+        PixelSlice::new_mut(&mut merged).merge_planes(planes.each_ref().map(<[u8; 2]>::as_slice));
+        assert_eq!(merged, pixels);
+    }
+
+    #[test]
+    fn channel_planes() {
+        let mut pixels = [
+            Pixel::<Abgr8888>::with_rgba(1, 2, 3, 4),
+            Pixel::<Abgr8888>::with_rgba(5, 6, 7, 8),
+        ];
+        let slice = PixelSlice::new_mut(&mut pixels);
+
+        let mut green = [0; 2];
+        slice.extract_channel(Rgba::Green, &mut green);
+        assert_eq!(green, [2, 6]);
+
+        slice.insert_channel(Rgba::Blue, &green);
+        assert_eq!(slice[1].channels(), [5, 6, 6, 8]);
+    }
+}
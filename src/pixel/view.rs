@@ -0,0 +1,1267 @@
+//! Borrowed, two-dimensional views of pixels with a row stride.
+//!
+//! GPU readbacks and OS framebuffers often pad each row, so that the distance between the start of
+//! two rows (the _stride_) is larger than the width of the image. [`ImageView`] and
+//! [`ImageViewMut`] view such a buffer as `width * height` pixels, skipping the padding.
+//!
+//! The stride is measured in pixels; views of raw bytes with a row pitch in bytes can be created
+//! with [`ImageView::from_bytes`] for formats of [plain bytes](PlainBytes).
+//!
+//! # Example
+//!
+//! ```rust
+//! use pxlfmt::{formats::rgba::Rgba8888, pixel::{Pixel, view::ImageViewMut}};
+//!
+//! // A 2x2 image, with a stride of 3 pixels.
+//! let mut pixels = [Pixel::<Rgba8888>::zeroed(); 5];
+//! let mut view = ImageViewMut::with_stride(&mut pixels, 2, 2, 3).unwrap();
+//! view.row_mut(1)[1] = Pixel::with_rgba(0xFF, 0x00, 0x00, 0xFF);
+//!
+//! assert_eq!(view.get(1, 1).map(|p| p.red()), Some(0xFF));
+//! assert_eq!(view.rows().count(), 2);
+//! assert_eq!(pixels[4].red(), 0xFF);
+//! ```
+
+use core::{error::Error, fmt::Display, ops::Range};
+
+use crate::{
+    convert::{ConvertFrom, convert_slice},
+    orient::Orientation,
+    pixel::{Format, Pixel, raw::PlainBytes},
+};
+
+/// An error returned when a view cannot be created over a buffer.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ViewError {
+    /// The stride is smaller than the width, so rows would overlap.
+    StrideTooSmall {
+        /// The stride, in pixels.
+        stride: usize,
+
+        /// The width, in pixels.
+        width: usize,
+    },
+
+    /// The buffer is too short for the dimensions and stride of the view.
+    TooFewPixels {
+        /// The number of pixels required.
+        expected: usize,
+
+        /// The number of pixels in the buffer.
+        actual: usize,
+    },
+
+    /// The row pitch, in bytes, is not a whole number of pixels.
+    UnalignedPitch {
+        /// The row pitch, in bytes.
+        pitch: usize,
+
+        /// The size of a pixel, in bytes.
+        pixel_size: usize,
+    },
+}
+
+impl Display for ViewError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::StrideTooSmall { stride, width } => {
+                write!(f, "Stride {stride} is smaller than width {width}")
+            }
+            Self::TooFewPixels { expected, actual } => {
+                write!(
+                    f,
+                    "Buffer has {actual} pixels, expected at least {expected}"
+                )
+            }
+            Self::UnalignedPitch { pitch, pixel_size } => {
+                write!(
+                    f,
+                    "Pitch {pitch} is not a multiple of the pixel size {pixel_size}"
+                )
+            }
+        }
+    }
+}
+
+impl Error for ViewError {}
+
+/// Checks that a buffer of `len` pixels can be viewed with the given dimensions and stride.
+fn validate(len: usize, width: usize, height: usize, stride: usize) -> Result<(), ViewError> {
+    if stride < width {
+        return Err(ViewError::StrideTooSmall { stride, width });
+    }
+    // The last row does not need to be padded; a view with no rows needs no pixels.
+    let expected = match height {
+        0 => 0,
+        _ => (height - 1)
+            .checked_mul(stride)
+            .and_then(|n| n.checked_add(width))
+            .unwrap_or(usize::MAX),
+    };
+    if len < expected {
+        return Err(ViewError::TooFewPixels {
+            expected,
+            actual: len,
+        });
+    }
+    Ok(())
+}
+
+/// A rectangle of a view, as the range of its pixels in the view's buffer and its stride.
+struct SubRect {
+    range: Range<usize>,
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+impl SubRect {
+    /// Returns the rectangle of a view with the given dimensions and stride, or `None` if the
+    /// rectangle is not within the view.
+    fn new(
+        (view_width, view_height, stride): (usize, usize, usize),
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<Self> {
+        if x.checked_add(width)? > view_width || y.checked_add(height)? > view_height {
+            return None;
+        }
+        // An empty rectangle may start past the end of the buffer, so needs no pixels at all.
+        if width == 0 || height == 0 {
+            return Some(Self {
+                range: 0..0,
+                width,
+                height,
+                stride: width,
+            });
+        }
+        let start = y * stride + x;
+        Some(Self {
+            range: start..start + (height - 1) * stride + width,
+            width,
+            height,
+            stride,
+        })
+    }
+}
+
+/// Asserts that two views have the same width and height.
+pub(crate) fn assert_same_size(dst: (usize, usize), src: (usize, usize)) {
+    assert_eq!(dst, src, "Views must have the same size");
+}
+
+/// Returns the stride, in pixels, of a row pitch in bytes.
+fn pitch_to_stride<F: Format>(pitch: usize) -> Result<usize, ViewError> {
+    let pixel_size = size_of::<Pixel<F>>();
+    if pitch.is_multiple_of(pixel_size) {
+        Ok(pitch / pixel_size)
+    } else {
+        Err(ViewError::UnalignedPitch { pitch, pixel_size })
+    }
+}
+
+/// A borrowed view of `width * height` pixels in the format `F`, with a row stride.
+#[derive(Copy, Clone)]
+pub struct ImageView<'a, F: Format> {
+    pixels: &'a [Pixel<F>],
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+impl<F: Format> core::fmt::Debug for ImageView<'_, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ImageView")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("stride", &self.stride)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, F: Format> ImageView<'a, F> {
+    /// Creates a view of tightly packed rows, with a stride equal to the width.
+    ///
+    /// ## Errors
+    ///
+    /// If `pixels` has fewer than `width * height` pixels.
+    pub fn new(pixels: &'a [Pixel<F>], width: usize, height: usize) -> Result<Self, ViewError> {
+        Self::with_stride(pixels, width, height, width)
+    }
+
+    /// Creates a view where each row starts `stride` pixels after the previous one.
+    ///
+    /// ## Errors
+    ///
+    /// If `stride` is smaller than `width`, or `pixels` is too short for the view.
+    pub fn with_stride(
+        pixels: &'a [Pixel<F>],
+        width: usize,
+        height: usize,
+        stride: usize,
+    ) -> Result<Self, ViewError> {
+        validate(pixels.len(), width, height, stride)?;
+        Ok(Self::new_unchecked(pixels, width, height, stride))
+    }
+
+    /// Creates a view without checking the dimensions, which the caller has already validated.
+    pub(crate) fn new_unchecked(
+        pixels: &'a [Pixel<F>],
+        width: usize,
+        height: usize,
+        stride: usize,
+    ) -> Self {
+        debug_assert_eq!(validate(pixels.len(), width, height, stride), Ok(()));
+        Self {
+            pixels,
+            width,
+            height,
+            stride,
+        }
+    }
+
+    /// Returns the width of the view, in pixels.
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the view, in pixels.
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the distance between the start of two rows, in pixels.
+    #[must_use]
+    pub const fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Returns the pixel at `(x, y)`, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a Pixel<F>> {
+        (x < self.width && y < self.height).then(|| &self.pixels[y * self.stride + x])
+    }
+
+    /// Returns the pixels of row `y`, without padding.
+    ///
+    /// ## Panics
+    ///
+    /// If `y` is out of bounds.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &'a [Pixel<F>] {
+        assert!(y < self.height, "Row out of bounds");
+        &self.pixels[y * self.stride..][..self.width]
+    }
+
+    /// Returns an iterator over the rows of the view, from top to bottom, without padding.
+    #[must_use]
+    pub fn rows(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &'a [Pixel<F>]> + ExactSizeIterator + use<'a, F> {
+        let view = *self;
+        (0..self.height).map(move |y| view.row(y))
+    }
+
+    /// Returns an iterator over the pixels of the view, in row-major order, skipping padding.
+    pub fn pixels(&self) -> impl Iterator<Item = &'a Pixel<F>> + use<'a, F> {
+        self.rows().flatten()
+    }
+
+    /// Returns an iterator over the pixels of the view and their `(x, y)` coordinates, in
+    /// row-major order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::gray::Gray8, pixel::{Pixel, view::ImageView}};
+    ///
+    /// let pixels = [0, 1, 2, 3].map(Pixel::<Gray8>::with_gray);
+    /// let view = ImageView::new(&pixels, 2, 2).unwrap();
+    ///
+    /// let (x, y, _) = view.enumerate_pixels().find(|(_, _, p)| p.gray() == 2).unwrap();
+    /// assert_eq!((x, y), (0, 1));
+    /// ```
+    pub fn enumerate_pixels(
+        &self,
+    ) -> impl Iterator<Item = (usize, usize, &'a Pixel<F>)> + use<'a, F> {
+        self.rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, pixel)| (x, y, pixel)))
+    }
+
+    /// Returns a view of the `width * height` rectangle whose top-left pixel is at `(x, y)`.
+    ///
+    /// Returns `None` if the rectangle is not within the view.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::gray::Gray8, pixel::{Pixel, view::ImageView}};
+    ///
+    /// let pixels = [0, 1, 2, 3, 4, 5, 6, 7, 8].map(Pixel::<Gray8>::with_gray);
+    /// let view = ImageView::new(&pixels, 3, 3).unwrap();
+    ///
+    /// let sub = view.sub_view(1, 1, 2, 2).unwrap();
+    /// assert_eq!(sub.row(1), &pixels[7..9]);
+    /// assert!(view.sub_view(2, 2, 2, 1).is_none());
+    /// ```
+    #[must_use]
+    pub fn sub_view(&self, x: usize, y: usize, width: usize, height: usize) -> Option<Self> {
+        let rect = SubRect::new((self.width, self.height, self.stride), x, y, width, height)?;
+        Some(Self::new_unchecked(
+            &self.pixels[rect.range],
+            rect.width,
+            rect.height,
+            rect.stride,
+        ))
+    }
+
+    /// Splits the view into the rows above `y`, and the rows from `y` onwards.
+    ///
+    /// ## Panics
+    ///
+    /// If `y` is greater than the height.
+    #[must_use]
+    pub fn split_at_row(self, y: usize) -> (Self, Self) {
+        assert!(y <= self.height, "Row out of bounds");
+        let mid = (y * self.stride).min(self.pixels.len());
+        let (top, bottom) = self.pixels.split_at(mid);
+        (
+            Self::new_unchecked(top, self.width, y, self.stride),
+            Self::new_unchecked(bottom, self.width, self.height - y, self.stride),
+        )
+    }
+
+    /// Copies the pixels of the view into `dst`, tightly packed without padding.
+    ///
+    /// Each row is copied with a single `copy_from_slice`, e.g. before handing the pixels to an
+    /// encoder that rejects padded rows.
+    ///
+    /// ## Panics
+    ///
+    /// If `dst` does not have exactly `width * height` pixels.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::gray::Gray8, pixel::{Pixel, view::ImageView}};
+    ///
+    /// let pixels = [1, 2, 0, 3, 4].map(Pixel::<Gray8>::with_gray);
+    /// let view = ImageView::with_stride(&pixels, 2, 2, 3).unwrap();
+    ///
+    /// let mut dst = [Pixel::zeroed(); 4];
+    /// view.compact_into(&mut dst);
+    /// assert_eq!(dst.map(|p| p.gray()), [1, 2, 3, 4]);
+    /// ```
+    pub fn compact_into(&self, dst: &mut [Pixel<F>]) {
+        assert_eq!(
+            dst.len(),
+            self.width * self.height,
+            "Slice must have width * height pixels"
+        );
+        if self.width == 0 {
+            return;
+        }
+        for (dst, row) in dst.chunks_exact_mut(self.width).zip(self.rows()) {
+            dst.copy_from_slice(row);
+        }
+    }
+
+    /// Copies the view into `dst`, in the given orientation.
+    ///
+    /// See [`Orientation::apply`].
+    ///
+    /// ## Panics
+    ///
+    /// If `dst` does not have the [oriented size](Orientation::oriented_size) of the view.
+    pub fn orient_into(&self, orientation: Orientation, dst: &mut ImageViewMut<'_, F>) {
+        assert_same_size(
+            (dst.width, dst.height),
+            orientation.oriented_size(self.width, self.height),
+        );
+        for (x, y, pixel) in dst.enumerate_pixels_mut() {
+            let (x, y) = orientation.source_position(x, y, self.width, self.height);
+            *pixel = self.pixels[y * self.stride + x];
+        }
+    }
+
+    /// Copies the view into `dst`, rotated 90 degrees clockwise.
+    ///
+    /// ## Panics
+    ///
+    /// If `dst` is not `height * width` pixels.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::gray::Gray8, pixel::{Pixel, view::{ImageView, ImageViewMut}}};
+    ///
+    /// // 1 2 3
+    /// // 4 5 6
+    /// let src = [1, 2, 3, 4, 5, 6].map(Pixel::<Gray8>::with_gray);
+    /// let mut dst = [Pixel::zeroed(); 6];
+    ///
+    /// let src = ImageView::new(&src, 3, 2).unwrap();
+    /// src.rotate90(&mut ImageViewMut::new(&mut dst, 2, 3).unwrap());
+    /// assert_eq!(dst.map(|p| p.gray()), [4, 1, 5, 2, 6, 3]);
+    /// ```
+    pub fn rotate90(&self, dst: &mut ImageViewMut<'_, F>) {
+        self.orient_into(Orientation::Rotate90, dst);
+    }
+
+    /// Copies the view into `dst`, rotated 180 degrees.
+    ///
+    /// ## Panics
+    ///
+    /// If `dst` is not `width * height` pixels.
+    pub fn rotate180(&self, dst: &mut ImageViewMut<'_, F>) {
+        self.orient_into(Orientation::Rotate180, dst);
+    }
+
+    /// Copies the view into `dst`, rotated 270 degrees clockwise (90 degrees counter-clockwise).
+    ///
+    /// ## Panics
+    ///
+    /// If `dst` is not `height * width` pixels.
+    pub fn rotate270(&self, dst: &mut ImageViewMut<'_, F>) {
+        self.orient_into(Orientation::Rotate270, dst);
+    }
+
+    /// Returns an adapter that reads the view in the given orientation, without copying pixels.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::gray::Gray8, orient::Orientation, pixel::{Pixel, view::ImageView}};
+    ///
+    /// // 1 2 3
+    /// // 4 5 6
+    /// let pixels = [1, 2, 3, 4, 5, 6].map(Pixel::<Gray8>::with_gray);
+    /// let view = ImageView::new(&pixels, 3, 2).unwrap().oriented(Orientation::Rotate90);
+    ///
+    /// assert_eq!((view.width(), view.height()), (2, 3));
+    /// assert_eq!(view.get(1, 0).map(|p| p.gray()), Some(1));
+    /// ```
+    #[must_use]
+    pub const fn oriented(self, orientation: Orientation) -> OrientedView<'a, F> {
+        OrientedView {
+            view: self,
+            orientation,
+        }
+    }
+}
+
+impl<'a, F> ImageView<'a, F>
+where
+    F: Format,
+    F::RawPixel: PlainBytes,
+{
+    /// Creates a view of raw bytes, where each row starts `pitch` bytes after the previous one.
+    ///
+    /// ## Errors
+    ///
+    /// If `pitch` is not a whole number of pixels or is smaller than a row, or `bytes` is too short
+    /// for the view.
+    pub fn from_bytes(
+        bytes: &'a [u8],
+        width: usize,
+        height: usize,
+        pitch: usize,
+    ) -> Result<Self, ViewError> {
+        let stride = pitch_to_stride::<F>(pitch)?;
+        let whole = bytes.len() - bytes.len() % size_of::<Pixel<F>>();
+        let pixels = Pixel::slice_from_bytes(&bytes[..whole]).unwrap_or_default();
+        Self::with_stride(pixels, width, height, stride)
+    }
+}
+
+/// A borrowed view that reads an [`ImageView`] in an [`Orientation`], without copying pixels.
+///
+/// Coordinates, the width, and the height are in displayed orientation; each access maps back to
+/// the stored view with [`Orientation::source_position`].
+#[derive(Copy, Clone)]
+pub struct OrientedView<'a, F: Format> {
+    view: ImageView<'a, F>,
+    orientation: Orientation,
+}
+
+impl<F: Format> core::fmt::Debug for OrientedView<'_, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OrientedView")
+            .field("view", &self.view)
+            .field("orientation", &self.orientation)
+            .finish()
+    }
+}
+
+impl<'a, F: Format> OrientedView<'a, F> {
+    /// Returns the stored view.
+    #[must_use]
+    pub const fn view(&self) -> ImageView<'a, F> {
+        self.view
+    }
+
+    /// Returns the orientation the view is read in.
+    #[must_use]
+    pub const fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Returns the displayed width, in pixels.
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.size().0
+    }
+
+    /// Returns the displayed height, in pixels.
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.size().1
+    }
+
+    /// Returns the displayed `(width, height)`.
+    const fn size(&self) -> (usize, usize) {
+        self.orientation
+            .oriented_size(self.view.width, self.view.height)
+    }
+
+    /// Returns the pixel displayed at `(x, y)`, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a Pixel<F>> {
+        let (width, height) = self.size();
+        if x >= width || y >= height {
+            return None;
+        }
+        let (x, y) = self
+            .orientation
+            .source_position(x, y, self.view.width, self.view.height);
+        self.view.get(x, y)
+    }
+
+    /// Returns an adapter that reads this view in `then`, after its current orientation.
+    ///
+    /// See [`Orientation::compose`].
+    #[must_use]
+    pub const fn oriented(self, then: Orientation) -> Self {
+        Self {
+            view: self.view,
+            orientation: self.orientation.compose(then),
+        }
+    }
+
+    /// Returns an iterator over the displayed pixels and their `(x, y)` coordinates, in row-major
+    /// order.
+    pub fn enumerate_pixels(
+        &self,
+    ) -> impl Iterator<Item = (usize, usize, &'a Pixel<F>)> + use<'a, F> {
+        let this = *self;
+        let (width, height) = self.size();
+        (0..height).flat_map(move |y| {
+            (0..width).map(move |x| {
+                let (sx, sy) =
+                    this.orientation
+                        .source_position(x, y, this.view.width, this.view.height);
+                (x, y, &this.view.pixels[sy * this.view.stride + sx])
+            })
+        })
+    }
+
+    /// Copies the displayed pixels into `dst`.
+    ///
+    /// See [`ImageView::orient_into`].
+    ///
+    /// ## Panics
+    ///
+    /// If `dst` is not `width * height` pixels.
+    pub fn copy_into(&self, dst: &mut ImageViewMut<'_, F>) {
+        self.view.orient_into(self.orientation, dst);
+    }
+}
+
+/// A borrowed, mutable view of `width * height` pixels in the format `F`, with a row stride.
+///
+/// See [`ImageView`] for the read-only counterpart.
+pub struct ImageViewMut<'a, F: Format> {
+    pixels: &'a mut [Pixel<F>],
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+impl<F: Format> core::fmt::Debug for ImageViewMut<'_, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ImageViewMut")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("stride", &self.stride)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, F: Format> ImageViewMut<'a, F> {
+    /// Creates a view of tightly packed rows, with a stride equal to the width.
+    ///
+    /// ## Errors
+    ///
+    /// If `pixels` has fewer than `width * height` pixels.
+    pub fn new(pixels: &'a mut [Pixel<F>], width: usize, height: usize) -> Result<Self, ViewError> {
+        Self::with_stride(pixels, width, height, width)
+    }
+
+    /// Creates a view where each row starts `stride` pixels after the previous one.
+    ///
+    /// ## Errors
+    ///
+    /// If `stride` is smaller than `width`, or `pixels` is too short for the view.
+    pub fn with_stride(
+        pixels: &'a mut [Pixel<F>],
+        width: usize,
+        height: usize,
+        stride: usize,
+    ) -> Result<Self, ViewError> {
+        validate(pixels.len(), width, height, stride)?;
+        Ok(Self::new_unchecked(pixels, width, height, stride))
+    }
+
+    /// Creates a view without checking the dimensions, which the caller has already validated.
+    pub(crate) fn new_unchecked(
+        pixels: &'a mut [Pixel<F>],
+        width: usize,
+        height: usize,
+        stride: usize,
+    ) -> Self {
+        debug_assert_eq!(validate(pixels.len(), width, height, stride), Ok(()));
+        Self {
+            pixels,
+            width,
+            height,
+            stride,
+        }
+    }
+
+    /// Returns the width of the view, in pixels.
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the view, in pixels.
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the distance between the start of two rows, in pixels.
+    #[must_use]
+    pub const fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Returns a read-only view of the same pixels.
+    #[must_use]
+    pub fn as_view(&self) -> ImageView<'_, F> {
+        ImageView::new_unchecked(self.pixels, self.width, self.height, self.stride)
+    }
+
+    /// Returns the pixel at `(x, y)`, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, x: usize, y: usize) -> Option<&Pixel<F>> {
+        self.as_view().get(x, y)
+    }
+
+    /// Returns the mutable pixel at `(x, y)`, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut Pixel<F>> {
+        (x < self.width && y < self.height).then(|| &mut self.pixels[y * self.stride + x])
+    }
+
+    /// Returns the pixels of row `y`, without padding.
+    ///
+    /// ## Panics
+    ///
+    /// If `y` is out of bounds.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[Pixel<F>] {
+        self.as_view().row(y)
+    }
+
+    /// Returns the mutable pixels of row `y`, without padding.
+    ///
+    /// ## Panics
+    ///
+    /// If `y` is out of bounds.
+    #[must_use]
+    pub fn row_mut(&mut self, y: usize) -> &mut [Pixel<F>] {
+        assert!(y < self.height, "Row out of bounds");
+        &mut self.pixels[y * self.stride..][..self.width]
+    }
+
+    /// Returns an iterator over the rows of the view, from top to bottom, without padding.
+    #[must_use]
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[Pixel<F>]> + ExactSizeIterator {
+        self.as_view().rows()
+    }
+
+    /// Returns an iterator over the mutable rows of the view, from top to bottom, without padding.
+    pub fn rows_mut(&mut self) -> RowsMut<'_, F> {
+        RowsMut {
+            pixels: self.pixels,
+            width: self.width,
+            stride: self.stride,
+            remaining: self.height,
+        }
+    }
+
+    /// Returns an iterator over the pixels of the view, in row-major order, skipping padding.
+    pub fn pixels(&self) -> impl Iterator<Item = &Pixel<F>> {
+        self.rows().flatten()
+    }
+
+    /// Returns an iterator over the mutable pixels of the view, in row-major order, skipping
+    /// padding.
+    pub fn pixels_mut(&mut self) -> impl Iterator<Item = &mut Pixel<F>> {
+        self.rows_mut().flatten()
+    }
+
+    /// Returns an iterator over the pixels of the view and their `(x, y)` coordinates, in
+    /// row-major order.
+    pub fn enumerate_pixels(&self) -> impl Iterator<Item = (usize, usize, &Pixel<F>)> {
+        self.as_view().enumerate_pixels()
+    }
+
+    /// Returns an iterator over the mutable pixels of the view and their `(x, y)` coordinates, in
+    /// row-major order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::gray::Gray8, pixel::{Pixel, view::ImageViewMut}};
+    ///
+    /// let mut pixels = [Pixel::<Gray8>::zeroed(); 6];
+    /// let mut view = ImageViewMut::new(&mut pixels, 3, 2).unwrap();
+    /// for (x, y, pixel) in view.enumerate_pixels_mut() {
+    ///     pixel.set_gray((x + y * 10) as u8);
+    /// }
+    /// assert_eq!(pixels[4].gray(), 11);
+    /// ```
+    pub fn enumerate_pixels_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut Pixel<F>)> {
+        self.rows_mut().enumerate().flat_map(|(y, row)| {
+            row.iter_mut()
+                .enumerate()
+                .map(move |(x, pixel)| (x, y, pixel))
+        })
+    }
+
+    /// Copies every pixel of `src`, a view of the same size and format.
+    ///
+    /// Each row is copied with [`copy_from_slice`](slice::copy_from_slice); to copy from a view
+    /// in a different format, use [`convert_from`](ImageViewMut::convert_from).
+    ///
+    /// ## Panics
+    ///
+    /// If the views have different sizes.
+    pub fn copy_from(&mut self, src: ImageView<'_, F>) {
+        assert_same_size((self.width, self.height), (src.width, src.height));
+        for y in 0..self.height {
+            self.row_mut(y).copy_from_slice(src.row(y));
+        }
+    }
+
+    /// Converts every pixel of `src`, a view of the same size, to the format of this view.
+    ///
+    /// See [`convert_slice`].
+    ///
+    /// ## Panics
+    ///
+    /// If the views have different sizes.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{
+    ///     formats::rgba::{FloatRgba, Rgba8888},
+    ///     pixel::{Pixel, view::{ImageView, ImageViewMut}},
+    /// };
+    ///
+    /// let src = [Pixel::<Rgba8888>::with_rgba(0xFF, 0x00, 0x00, 0xFF); 4];
+    /// let mut dst = [Pixel::<FloatRgba>::zeroed(); 9];
+    ///
+    /// // Blit the 2x2 source into the bottom-right corner of the 3x3 destination.
+    /// let mut view = ImageViewMut::new(&mut dst, 3, 3).unwrap();
+    /// let mut corner = view.sub_view_mut(1, 1, 2, 2).unwrap();
+    /// corner.convert_from(ImageView::new(&src, 2, 2).unwrap());
+    /// assert_eq!(dst[8].red(), 1.0);
+    /// assert_eq!(dst[0].red(), 0.0);
+    /// ```
+    pub fn convert_from<S: Format>(&mut self, src: ImageView<'_, S>)
+    where
+        Pixel<F>: ConvertFrom<Pixel<S>>,
+    {
+        assert_same_size((self.width, self.height), (src.width, src.height));
+        for y in 0..self.height {
+            convert_slice(src.row(y), self.row_mut(y));
+        }
+    }
+
+    /// Mirrors the view horizontally, in place.
+    pub fn flip_horizontal(&mut self) {
+        self.rows_mut().for_each(<[Pixel<F>]>::reverse);
+    }
+
+    /// Mirrors the view vertically, in place.
+    ///
+    /// This converts between top-down and bottom-up row order, such as that of BMP files.
+    pub fn flip_vertical(&mut self) {
+        let half = self.height / 2;
+        let (mut top, mut bottom) = self.reborrow().split_at_row(half);
+        for (a, b) in top.rows_mut().zip(bottom.rows_mut().rev()) {
+            a.swap_with_slice(b);
+        }
+    }
+
+    /// Returns a mutable view of the same pixels, borrowing this view.
+    ///
+    /// This allows the consuming methods, such as [`split_at_row`](ImageViewMut::split_at_row), to
+    /// be used without giving up the view.
+    #[must_use]
+    pub fn reborrow(&mut self) -> ImageViewMut<'_, F> {
+        ImageViewMut::new_unchecked(self.pixels, self.width, self.height, self.stride)
+    }
+
+    /// Returns a mutable view of the `width * height` rectangle whose top-left pixel is at `(x, y)`.
+    ///
+    /// Returns `None` if the rectangle is not within the view.
+    #[must_use]
+    pub fn sub_view_mut(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> Option<ImageViewMut<'_, F>> {
+        self.reborrow().into_sub_view(x, y, width, height)
+    }
+
+    /// Converts the view into a view of the `width * height` rectangle whose top-left pixel is at
+    /// `(x, y)`.
+    ///
+    /// Returns `None` if the rectangle is not within the view.
+    #[must_use]
+    pub fn into_sub_view(self, x: usize, y: usize, width: usize, height: usize) -> Option<Self> {
+        let rect = SubRect::new((self.width, self.height, self.stride), x, y, width, height)?;
+        Some(Self::new_unchecked(
+            &mut self.pixels[rect.range],
+            rect.width,
+            rect.height,
+            rect.stride,
+        ))
+    }
+
+    /// Splits the view into two disjoint mutable views: the rows above `y`, and the rows from `y`
+    /// onwards.
+    ///
+    /// ## Panics
+    ///
+    /// If `y` is greater than the height.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use pxlfmt::{formats::gray::Gray8, pixel::{Pixel, view::ImageViewMut}};
+    ///
+    /// let mut pixels = [Pixel::<Gray8>::zeroed(); 6];
+    /// let mut view = ImageViewMut::new(&mut pixels, 2, 3).unwrap();
+    ///
+    /// let (mut top, mut bottom) = view.reborrow().split_at_row(1);
+    /// top.row_mut(0).fill(Pixel::with_gray(1));
+    /// bottom.row_mut(1).copy_from_slice(top.row(0));
+    /// assert_eq!(view.row(2), view.row(0));
+    /// ```
+    #[must_use]
+    pub fn split_at_row(self, y: usize) -> (Self, Self) {
+        assert!(y <= self.height, "Row out of bounds");
+        let mid = (y * self.stride).min(self.pixels.len());
+        let (top, bottom) = self.pixels.split_at_mut(mid);
+        (
+            Self::new_unchecked(top, self.width, y, self.stride),
+            Self::new_unchecked(bottom, self.width, self.height - y, self.stride),
+        )
+    }
+}
+
+impl<'a, F> ImageViewMut<'a, F>
+where
+    F: Format,
+    F::RawPixel: PlainBytes,
+{
+    /// Creates a view of mutable raw bytes, where each row starts `pitch` bytes after the previous
+    /// one.
+    ///
+    /// ## Errors
+    ///
+    /// If `pitch` is not a whole number of pixels or is smaller than a row, or `bytes` is too short
+    /// for the view.
+    pub fn from_bytes(
+        bytes: &'a mut [u8],
+        width: usize,
+        height: usize,
+        pitch: usize,
+    ) -> Result<Self, ViewError> {
+        let stride = pitch_to_stride::<F>(pitch)?;
+        let whole = bytes.len() - bytes.len() % size_of::<Pixel<F>>();
+        let pixels = Pixel::slice_from_bytes_mut(&mut bytes[..whole]).unwrap_or_default();
+        Self::with_stride(pixels, width, height, stride)
+    }
+}
+
+/// An iterator over the mutable rows of an [`ImageViewMut`], without padding.
+///
+/// Created by [`ImageViewMut::rows_mut`].
+pub struct RowsMut<'a, F: Format> {
+    pixels: &'a mut [Pixel<F>],
+    width: usize,
+    stride: usize,
+    remaining: usize,
+}
+
+impl<'a, F: Format> Iterator for RowsMut<'a, F> {
+    type Item = &'a mut [Pixel<F>];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        let pixels = core::mem::take(&mut self.pixels);
+        // The last row is not necessarily padded to the stride.
+        if self.remaining == 0 {
+            return Some(&mut pixels[..self.width]);
+        }
+        let (row, rest) = pixels.split_at_mut(self.stride);
+        self.pixels = rest;
+        Some(&mut row[..self.width])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<F: Format> DoubleEndedIterator for RowsMut<'_, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        let pixels = core::mem::take(&mut self.pixels);
+        let (rest, row) = pixels.split_at_mut(self.remaining * self.stride);
+        self.pixels = rest;
+        Some(&mut row[..self.width])
+    }
+}
+
+impl<F: Format> ExactSizeIterator for RowsMut<'_, F> {}
+
+impl<F: Format> core::fmt::Debug for RowsMut<'_, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RowsMut")
+            .field("remaining", &self.remaining)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, F: Format> From<ImageViewMut<'a, F>> for ImageView<'a, F> {
+    fn from(view: ImageViewMut<'a, F>) -> Self {
+        Self::new_unchecked(view.pixels, view.width, view.height, view.stride)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use crate::{
+        formats::rgba::{Rgba8888, Rgba8888Be},
+        pixel::raw::RawPixel,
+    };
+
+    use super::*;
+
+    #[test]
+    fn validates_dimensions() {
+        let pixels = [Pixel::<Rgba8888>::zeroed(); 5];
+        assert_eq!(
+            ImageView::with_stride(&pixels, 3, 2, 2).unwrap_err(),
+            ViewError::StrideTooSmall {
+                stride: 2,
+                width: 3
+            }
+        );
+        assert_eq!(
+            ImageView::new(&pixels, 3, 2).unwrap_err(),
+            ViewError::TooFewPixels {
+                expected: 6,
+                actual: 5
+            }
+        );
+        assert!(ImageView::with_stride(&pixels, 2, 2, 3).is_ok());
+        assert!(ImageView::new(&pixels, 0, 0).is_ok());
+    }
+
+    #[test]
+    fn compact_into_removes_padding() {
+        let pixels = [0, 1, 2, 97, 98, 3, 4, 5, 99].map(Pixel::<Rgba8888>::new);
+        let view = ImageView::with_stride(&pixels, 3, 2, 5).unwrap();
+        let mut dst = [Pixel::zeroed(); 6];
+        view.compact_into(&mut dst);
+        assert_eq!(dst, [0, 1, 2, 3, 4, 5].map(Pixel::new));
+
+        let empty = ImageView::with_stride(&pixels, 0, 2, 5).unwrap();
+        empty.compact_into(&mut []);
+    }
+
+    #[test]
+    #[should_panic = "Slice must have width * height pixels"]
+    fn compact_into_wrong_length() {
+        let pixels = [Pixel::<Rgba8888>::zeroed(); 5];
+        let view = ImageView::with_stride(&pixels, 2, 2, 3).unwrap();
+        view.compact_into(&mut [Pixel::zeroed(); 5]);
+    }
+
+    #[test]
+    fn skips_padding() {
+        let pixels = [0, 1, 99, 2, 3].map(Pixel::<Rgba8888>::new);
+        let view = ImageView::with_stride(&pixels, 2, 2, 3).unwrap();
+        assert_eq!(view.get(0, 1), Some(&Pixel::new(2)));
+        assert_eq!(view.get(2, 0), None);
+
+        let mut rows = view.rows();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows.next(), Some(&pixels[0..2]));
+        assert_eq!(rows.next(), Some(&pixels[3..5]));
+        assert_eq!(rows.next(), None);
+    }
+
+    #[test]
+    fn view_mut() {
+        let mut pixels = [Pixel::<Rgba8888>::zeroed(); 5];
+        let mut view = ImageViewMut::with_stride(&mut pixels, 2, 2, 3).unwrap();
+        *view.get_mut(1, 0).unwrap() = Pixel::new(1);
+        view.row_mut(1).fill(Pixel::new(2));
+        assert_eq!(view.row(0), &[Pixel::new(0), Pixel::new(1)]);
+        assert_eq!(ImageView::from(view).rows().count(), 2);
+        assert_eq!(pixels.map(|p| p.into_raw().into_inner()), [0, 1, 0, 2, 2]);
+    }
+
+    #[test]
+    fn sub_view() {
+        let pixels = (0..12)
+            .map(Pixel::<Rgba8888>::new)
+            .collect::<alloc::vec::Vec<_>>();
+        let view = ImageView::with_stride(&pixels, 3, 3, 4).unwrap();
+
+        let sub = view.sub_view(1, 1, 2, 2).unwrap();
+        assert_eq!(sub.stride(), 4);
+        assert_eq!(sub.row(0), &pixels[5..7]);
+        assert_eq!(sub.get(1, 1), Some(&pixels[10]));
+        assert_eq!(sub.get(2, 0), None);
+
+        assert!(view.sub_view(0, 0, 3, 3).is_some());
+        assert!(view.sub_view(1, 0, 3, 1).is_none());
+        assert!(view.sub_view(0, usize::MAX, 1, 1).is_none());
+
+        let empty = view.sub_view(3, 3, 0, 0).unwrap();
+        assert_eq!(empty.rows().count(), 0);
+    }
+
+    #[test]
+    fn split_mut() {
+        let mut pixels = [Pixel::<Rgba8888>::zeroed(); 9];
+        let mut view = ImageViewMut::new(&mut pixels, 3, 3).unwrap();
+        view.sub_view_mut(1, 1, 2, 2)
+            .unwrap()
+            .row_mut(1)
+            .fill(Pixel::new(1));
+
+        let (top, bottom) = view.reborrow().split_at_row(3);
+        assert_eq!((top.height(), bottom.height()), (3, 0));
+
+        let (mut top, bottom) = view.split_at_row(2);
+        top.row_mut(0).copy_from_slice(bottom.row(0));
+        assert_eq!(
+            pixels.map(|p| p.into_raw().into_inner()),
+            [0, 1, 1, 0, 0, 0, 0, 1, 1]
+        );
+    }
+
+    #[test]
+    fn copy_and_convert_from() {
+        use crate::formats::rgba::Abgr8888;
+
+        let src = (0..4)
+            .map(Pixel::<Rgba8888>::new)
+            .collect::<alloc::vec::Vec<_>>();
+        let src = ImageView::new(&src, 2, 2).unwrap();
+
+        let mut dst = [Pixel::<Rgba8888>::zeroed(); 6];
+        let mut view = ImageViewMut::with_stride(&mut dst, 2, 2, 3).unwrap();
+        view.copy_from(src);
+        assert_eq!(dst.map(|p| p.into_raw().into_inner()), [0, 1, 0, 2, 3, 0]);
+
+        let mut dst = [Pixel::<Abgr8888>::zeroed(); 4];
+        ImageViewMut::new(&mut dst, 2, 2).unwrap().convert_from(src);
+        assert_eq!(dst[3].channels(), src.row(1)[1].channels());
+    }
+
+    #[test]
+    #[should_panic = "Views must have the same size"]
+    fn copy_from_different_size() {
+        let src = [Pixel::<Rgba8888>::zeroed(); 4];
+        let mut dst = [Pixel::<Rgba8888>::zeroed(); 4];
+        let mut view = ImageViewMut::new(&mut dst, 4, 1).unwrap();
+        view.copy_from(ImageView::new(&src, 2, 2).unwrap());
+    }
+
+    #[test]
+    fn rows_mut() {
+        let mut pixels = [Pixel::<Rgba8888>::zeroed(); 8];
+        let mut view = ImageViewMut::with_stride(&mut pixels, 2, 3, 3).unwrap();
+
+        let mut rows = view.rows_mut();
+        assert_eq!(rows.len(), 3);
+        rows.next_back().unwrap().fill(Pixel::new(3));
+        rows.next().unwrap().fill(Pixel::new(1));
+        rows.next().unwrap().fill(Pixel::new(2));
+        assert!(rows.next().is_none());
+        assert!(rows.next_back().is_none());
+
+        assert_eq!(
+            pixels.map(|p| p.into_raw().into_inner()),
+            [1, 1, 0, 2, 2, 0, 3, 3]
+        );
+    }
+
+    #[test]
+    fn rows_mut_empty() {
+        let mut view = ImageViewMut::<Rgba8888>::new(&mut [], 0, 2).unwrap();
+        assert_eq!(view.rows_mut().map(|row| row.len()).sum::<usize>(), 0);
+        assert_eq!(view.rows_mut().count(), 2);
+    }
+
+    #[test]
+    fn pixels_skip_padding() {
+        let mut pixels = [0, 1, 99, 2, 3].map(Pixel::<Rgba8888>::new);
+        let mut view = ImageViewMut::with_stride(&mut pixels, 2, 2, 3).unwrap();
+        view.pixels_mut()
+            .for_each(|p| *p = Pixel::new(p.into_raw().into_inner() * 2));
+
+        let values = view
+            .enumerate_pixels()
+            .map(|(x, y, p)| (x, y, p.into_raw().into_inner()))
+            .collect::<alloc::vec::Vec<_>>();
+        assert_eq!(values, [(0, 0, 0), (1, 0, 2), (0, 1, 4), (1, 1, 6)]);
+        assert_eq!(view.pixels().count(), 4);
+        assert_eq!(pixels[2], Pixel::new(99));
+    }
+
+    #[test]
+    fn flips() {
+        let mut pixels = [1, 2, 3, 99, 4, 5, 6, 99, 7, 8, 9].map(Pixel::<Rgba8888>::new);
+        let mut view = ImageViewMut::with_stride(&mut pixels, 3, 3, 4).unwrap();
+        view.flip_horizontal();
+        view.flip_vertical();
+        assert_eq!(
+            pixels.map(|p| p.into_raw().into_inner()),
+            [9, 8, 7, 99, 6, 5, 4, 99, 3, 2, 1]
+        );
+    }
+
+    #[test]
+    fn rotations() {
+        // 1 2 3
+        // 4 5 6
+        let src = [1, 2, 3, 99, 4, 5, 6].map(Pixel::<Rgba8888>::new);
+        let src = ImageView::with_stride(&src, 3, 2, 4).unwrap();
+        let values = |dst: [Pixel<Rgba8888>; 6]| dst.map(|p| p.into_raw().into_inner());
+
+        let mut dst = [Pixel::zeroed(); 6];
+        src.rotate90(&mut ImageViewMut::new(&mut dst, 2, 3).unwrap());
+        assert_eq!(values(dst), [4, 1, 5, 2, 6, 3]);
+        src.rotate180(&mut ImageViewMut::new(&mut dst, 3, 2).unwrap());
+        assert_eq!(values(dst), [6, 5, 4, 3, 2, 1]);
+        src.rotate270(&mut ImageViewMut::new(&mut dst, 2, 3).unwrap());
+        assert_eq!(values(dst), [3, 6, 2, 5, 1, 4]);
+    }
+
+    #[test]
+    #[should_panic = "Views must have the same size"]
+    fn rotate_wrong_size() {
+        let src = [Pixel::<Rgba8888>::zeroed(); 6];
+        let mut dst = [Pixel::<Rgba8888>::zeroed(); 6];
+        let src = ImageView::new(&src, 3, 2).unwrap();
+        src.rotate90(&mut ImageViewMut::new(&mut dst, 3, 2).unwrap());
+    }
+
+    #[test]
+    fn oriented_matches_orient_into() {
+        // 1 2 3
+        // 4 5 6
+        let src = [1, 2, 3, 99, 4, 5, 6].map(Pixel::<Rgba8888>::new);
+        let src = ImageView::with_stride(&src, 3, 2, 4).unwrap();
+
+        for orientation in Orientation::ALL {
+            let oriented = src.oriented(orientation);
+            let (width, height) = (oriented.width(), oriented.height());
+            let mut dst = [Pixel::zeroed(); 6];
+            src.orient_into(
+                orientation,
+                &mut ImageViewMut::new(&mut dst, width, height).unwrap(),
+            );
+
+            let pixels = oriented
+                .enumerate_pixels()
+                .map(|(x, y, p)| {
+                    assert_eq!(oriented.get(x, y), Some(p));
+                    *p
+                })
+                .collect::<alloc::vec::Vec<_>>();
+            assert_eq!(pixels, dst, "{orientation:?}");
+            assert_eq!(oriented.get(width, 0), None);
+            assert_eq!(oriented.get(0, height), None);
+
+            let mut copied = [Pixel::zeroed(); 6];
+            oriented.copy_into(&mut ImageViewMut::new(&mut copied, width, height).unwrap());
+            assert_eq!(copied, dst);
+        }
+    }
+
+    #[test]
+    fn oriented_composes() {
+        let src = [1, 2, 3, 4, 5, 6].map(Pixel::<Rgba8888>::new);
+        let src = ImageView::new(&src, 3, 2).unwrap();
+        let twice = src
+            .oriented(Orientation::Rotate90)
+            .oriented(Orientation::FlipHorizontal);
+        assert_eq!(
+            twice.orientation(),
+            Orientation::Rotate90.compose(Orientation::FlipHorizontal)
+        );
+        assert_eq!(twice.get(0, 0), src.get(0, 0));
+    }
+
+    #[test]
+    fn from_bytes() {
+        // Two rows of one pixel, each padded to 6 bytes, with a truncated last row.
+        let bytes = [1, 2, 3, 4, 0, 0, 5, 6, 7, 8, 0];
+        assert_eq!(
+            ImageView::<Rgba8888Be>::from_bytes(&bytes, 1, 2, 6).unwrap_err(),
+            ViewError::UnalignedPitch {
+                pitch: 6,
+                pixel_size: 4
+            }
+        );
+
+        let bytes = [1, 2, 3, 4, 0, 0, 0, 0, 5, 6, 7, 8, 0];
+        let view = ImageView::<Rgba8888Be>::from_bytes(&bytes, 1, 2, 8).unwrap();
+        assert_eq!(view.stride(), 2);
+        assert_eq!(view.row(1)[0].as_bytes(), &[5, 6, 7, 8]);
+    }
+}
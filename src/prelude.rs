@@ -10,5 +10,6 @@
 //! assert_eq!(pixel.alpha(), 0xFF);
 //! ```
 
+pub use crate::convert::ConvertIterExt as _;
 pub use crate::formats::rgba::{Rgba, Rgba8888, RgbaFormat};
 pub use crate::pixel::{Format, Pixel, raw::RawPixel as _};
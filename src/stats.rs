@@ -0,0 +1,244 @@
+//! Statistics over slices of pixels.
+//!
+//! ## Histograms
+//!
+//! [`PixelSlice::histogram`] counts the values of each channel of RGBA pixels with 8-bit channels,
+//! in 256 bins. Pixels of any other depth can be counted into a chosen number of bins with
+//! [`PixelSlice::histogram_bucketed`]:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::rgba::{FloatRgba, Rgba8888},
+//!     pixel::{Pixel, slice::PixelSlice},
+//! };
+//!
+//! let pixels = [
+//!     Pixel::<Rgba8888>::with_rgba(0x00, 0x80, 0xFF, 0xFF),
+//!     Pixel::<Rgba8888>::with_rgba(0x00, 0x40, 0xFF, 0xFF),
+//! ];
+//! let [red, green, _, alpha] = PixelSlice::new(&pixels).histogram();
+//! assert_eq!(red[0x00], 2);
+//! assert_eq!((green[0x40], green[0x80]), (1, 1));
+//! assert_eq!(alpha[0xFF], 2);
+//!
+//! let pixels = [Pixel::<FloatRgba>::with_rgba(0.0, 0.3, 0.9, 1.0)];
+//! let [red, green, blue, _] = PixelSlice::new(&pixels).histogram_bucketed::<4>();
+//! assert_eq!((red, green, blue), ([1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 0, 1]));
+//! ```
+//!
+//! ## Channel statistics
+//!
+//! [`PixelSlice::channel_stats`] computes the [`ChannelStats`] of RGBA pixels in one pass, e.g. to
+//! stretch the range of an image or estimate the exposure of an HDR image before tone-mapping:
+//!
+//! ```rust
+//! use pxlfmt::{
+//!     formats::rgba::FloatRgba,
+//!     pixel::{Pixel, slice::PixelSlice},
+//! };
+//!
+//! let pixels = [
+//!     Pixel::<FloatRgba>::with_rgba(0.0, 0.5, 4.0, 1.0),
+//!     Pixel::<FloatRgba>::with_rgba(1.0, 0.5, 2.0, 1.0),
+//! ];
+//! let stats = PixelSlice::new(&pixels).channel_stats().unwrap();
+//! assert_eq!(stats.min, [0.0, 0.5, 2.0, 1.0]);
+//! assert_eq!(stats.max, [1.0, 0.5, 4.0, 1.0]);
+//! assert_eq!(stats.mean, [0.5, 0.5, 3.0, 1.0]);
+//! ```
+
+use crate::{
+    formats::rgba::RgbaFormat,
+    norm::Normalized,
+    pixel::{ChannelOf, Format, raw::RawPixel, slice::PixelSlice},
+};
+
+impl<F> PixelSlice<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = u8>,
+{
+    /// Returns the number of pixels with each value of each channel, in RGBA order.
+    #[must_use]
+    pub fn histogram(&self) -> [[usize; 256]; 4] {
+        let mut bins = [[0; 256]; 4];
+        for pixel in self.iter() {
+            for (bins, value) in bins.iter_mut().zip(pixel.channels()) {
+                bins[usize::from(value)] += 1;
+            }
+        }
+        bins
+    }
+}
+
+impl<F> PixelSlice<F>
+where
+    F: Format,
+    F::Channels: ChannelOf<F>,
+    F::RawPixel: RawPixel<Channel = u8>,
+{
+    /// Returns the number of pixels with each value of the given channel.
+    ///
+    /// Unlike [`histogram`](PixelSlice::histogram), this is available for any format with 8-bit
+    /// channels, such as grayscale or CMYK.
+    #[must_use]
+    pub fn channel_histogram(&self, channel: F::Channels) -> [usize; 256] {
+        let mut bins = [0; 256];
+        for pixel in self.iter() {
+            bins[usize::from(pixel.get(channel))] += 1;
+        }
+        bins
+    }
+}
+
+impl<F> PixelSlice<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    /// Returns the number of pixels with each channel in each of `N` equal ranges of `0.0..=1.0`,
+    /// in RGBA order.
+    ///
+    /// Values are [normalized](Normalized) and clamped to `0.0..=1.0`; a value of `1.0` is counted
+    /// in the last bin.
+    ///
+    /// `N` must be non-zero, which is checked at compile time.
+    #[must_use]
+    pub fn histogram_bucketed<const N: usize>(&self) -> [[usize; N]; 4] {
+        const { assert!(N > 0, "Histogram must have at least one bin") };
+        let mut bins = [[0; N]; 4];
+        for pixel in self.iter() {
+            for (bins, value) in bins.iter_mut().zip(pixel.channels()) {
+                bins[bucket::<N>(value.to_f32())] += 1;
+            }
+        }
+        bins
+    }
+}
+
+/// The minimum, maximum, and mean of each channel of a slice of pixels.
+///
+/// Channels are in `R, G, B, A` order, and [normalized](Normalized) to `f32`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ChannelStats {
+    /// The smallest value of each channel.
+    pub min: [f32; 4],
+
+    /// The largest value of each channel.
+    pub max: [f32; 4],
+
+    /// The average value of each channel.
+    pub mean: [f32; 4],
+}
+
+impl<F> PixelSlice<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    /// Returns the minimum, maximum, and mean of each channel, or `None` if the slice is empty.
+    ///
+    /// Channels are summed as `f64`, which keeps the mean of millions of pixels precise. `NaN`
+    /// values are ignored by the minimum and maximum, but propagate to the mean.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn channel_stats(&self) -> Option<ChannelStats> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut min = [f32::INFINITY; 4];
+        let mut max = [f32::NEG_INFINITY; 4];
+        let mut sums = [0.0_f64; 4];
+        for pixel in self.iter() {
+            for (i, value) in pixel.channels().into_iter().enumerate() {
+                let value = value.to_f32();
+                min[i] = min[i].min(value);
+                max[i] = max[i].max(value);
+                sums[i] += f64::from(value);
+            }
+        }
+        let len = self.len() as f64;
+        Some(ChannelStats {
+            min,
+            max,
+            mean: sums.map(|sum| (sum / len) as f32),
+        })
+    }
+}
+
+/// Returns the bin of `N` bins which a normalized value is counted in.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn bucket<const N: usize>(value: f32) -> usize {
+    let bin = (value.clamp(0.0, 1.0) * N as f32) as usize;
+    bin.min(N - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        formats::{
+            gray::Gray8,
+            rgba::{Abgr8888, FloatRgba, Rgba16161616},
+        },
+        pixel::Pixel,
+    };
+
+    use super::*;
+
+    #[test]
+    fn histogram_is_rgba_order() {
+        let pixels = [
+            Pixel::<Abgr8888>::with_rgba(1, 2, 3, 4),
+            Pixel::<Abgr8888>::with_rgba(1, 5, 6, 7),
+        ];
+        let [r, g, b, a] = PixelSlice::new(&pixels).histogram();
+        assert_eq!((r[1], g[2], g[5], b[3], a[7]), (2, 1, 1, 1, 1));
+        assert_eq!(r.iter().sum::<usize>(), 2);
+    }
+
+    #[test]
+    fn channel_histogram() {
+        use crate::formats::gray::Gray;
+
+        let pixels = [0, 0, 9].map(Pixel::<Gray8>::with_gray);
+        let bins = PixelSlice::new(&pixels).channel_histogram(Gray::Gray);
+        assert_eq!((bins[0], bins[9]), (2, 1));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn channel_stats_normalizes() {
+        let pixels = [
+            Pixel::<Abgr8888>::with_rgba(0x00, 0xFF, 0x00, 0xFF),
+            Pixel::<Abgr8888>::with_rgba(0xFF, 0xFF, 0x00, 0xFF),
+        ];
+        let stats = PixelSlice::new(&pixels).channel_stats().unwrap();
+        assert_eq!(stats.min, [0.0, 1.0, 0.0, 1.0]);
+        assert_eq!(stats.max, [1.0, 1.0, 0.0, 1.0]);
+        assert_eq!(stats.mean, [0.5, 1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn channel_stats_empty() {
+        let pixels: [Pixel<FloatRgba>; 0] = [];
+        assert_eq!(PixelSlice::new(&pixels).channel_stats(), None);
+    }
+
+    #[test]
+    fn histogram_bucketed() {
+        let pixels = [
+            Pixel::<Rgba16161616>::with_rgba(0, 0x7FFF, 0x8000, 0xFFFF),
+            Pixel::<Rgba16161616>::with_rgba(0, 0, 0, 0),
+        ];
+        let [r, g, b, a] = PixelSlice::new(&pixels).histogram_bucketed::<2>();
+        assert_eq!((r, g, b, a), ([2, 0], [2, 0], [1, 1], [1, 1]));
+
+        let pixels = [Pixel::<FloatRgba>::with_rgba(-1.0, 2.0, f32::NAN, 0.5)];
+        let [r, g, _, a] = PixelSlice::new(&pixels).histogram_bucketed::<10>();
+        assert_eq!((r[0], g[9], a[5]), (1, 1, 1));
+    }
+}
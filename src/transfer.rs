@@ -0,0 +1,410 @@
+//! Transfer functions, which convert between encoded (non-linear) and linear light values.
+//!
+//! Most 8-bit images are encoded with the sRGB transfer function, so that the limited precision is
+//! spent where the eye is most sensitive. Blending, filtering, and lighting should be done on
+//! linear values instead, which [`Pixel::to_linear`] and [`Pixel::to_srgb`] convert to and from:
+//!
+//! ```rust
+//! use pxlfmt::{formats::rgba::Rgba8888, pixel::Pixel};
+//!
+//! let pixel = Pixel::<Rgba8888>::with_rgba(0xFF, 0x80, 0x00, 0xFF);
+//! let linear = pixel.to_linear();
+//! assert!((linear.green() - 0.2158605).abs() < 1e-6);
+//!
+//! assert_eq!(linear.to_srgb::<Rgba8888>(), pixel);
+//! ```
+//!
+//! Decoding 8-bit channels uses a lookup table instead of evaluating the curve, both for single
+//! pixels and for whole slices with [`to_linear_slice`].
+//!
+//! Other encodings, such as the PQ and HLG curves used by HDR video, are selected with a
+//! [`TransferFunction`], and work with any RGBA format with [`Normalized`] channels:
+//!
+//! ```rust
+//! use pxlfmt::{formats::rgba::Rgba16161616, pixel::Pixel, transfer::TransferFunction};
+//!
+//! // A PQ-encoded HDR pixel; PQ code value 0.5 is about 92 cd/m².
+//! let pixel = Pixel::<Rgba16161616>::with_rgba(0x8000, 0x8000, 0x8000, 0xFFFF);
+//! let linear = pixel.to_linear_with(TransferFunction::Pq);
+//! assert!((linear.red() * 10_000.0 - 92.2).abs() < 0.1);
+//!
+//! let back = linear.to_encoded_with::<Rgba16161616>(TransferFunction::Pq);
+//! assert_eq!(back, pixel);
+//! ```
+
+use crate::{
+    formats::rgba::{FloatRgba, RgbaFormat},
+    norm::Normalized,
+    pixel::{Pixel, raw::RawPixel},
+};
+
+/// Converts an sRGB-encoded value in `0.0..=1.0` to linear light, using the exact piecewise curve.
+#[must_use]
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.040_45 {
+        value / 12.92
+    } else {
+        libm::powf((value + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Converts a linear light value in `0.0..=1.0` to sRGB encoding, using the exact piecewise curve.
+#[must_use]
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * libm::powf(value, 1.0 / 2.4) - 0.055
+    }
+}
+
+/// A transfer function, which maps between encoded values and linear light.
+///
+/// Encoded values are always in `0.0..=1.0`. What `1.0` means in linear light depends on the
+/// function, as described on each variant.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub enum TransferFunction {
+    /// Values are already linear, and are passed through as-is.
+    Linear,
+
+    /// The sRGB curve (IEC 61966-2-1), where `1.0` is the display's reference white.
+    #[default]
+    Srgb,
+
+    /// SMPTE ST 2084 perceptual quantizer (PQ), used by HDR10.
+    ///
+    /// Linear values are absolute display light, normalized so that `1.0` is 10,000 cd/m².
+    Pq,
+
+    /// ITU-R BT.2100 hybrid log-gamma (HLG).
+    ///
+    /// Linear values are relative scene light, as produced by the inverse OETF; no system gamma
+    /// (OOTF) is applied.
+    Hlg,
+}
+
+/// Constants of the PQ curve, as defined by SMPTE ST 2084.
+mod pq {
+    pub const M1: f32 = 2610.0 / 16384.0;
+    pub const M2: f32 = 2523.0 / 4096.0 * 128.0;
+    pub const C1: f32 = 3424.0 / 4096.0;
+    pub const C2: f32 = 2413.0 / 4096.0 * 32.0;
+    pub const C3: f32 = 2392.0 / 4096.0 * 32.0;
+}
+
+/// Constants of the HLG curve, as defined by ITU-R BT.2100.
+mod hlg {
+    pub const A: f32 = 0.178_832_77;
+    pub const B: f32 = 1.0 - 4.0 * A;
+    pub const C: f32 = 0.559_910_7;
+}
+
+impl TransferFunction {
+    /// Converts an encoded value to linear light.
+    ///
+    /// Values outside of `0.0..=1.0` are clamped, except for [`TransferFunction::Linear`].
+    #[must_use]
+    pub fn to_linear(self, value: f32) -> f32 {
+        match self {
+            Self::Linear => value,
+            Self::Srgb => srgb_to_linear(value.clamp(0.0, 1.0)),
+            Self::Pq => {
+                let p = libm::powf(value.clamp(0.0, 1.0), 1.0 / pq::M2);
+                let numerator = (p - pq::C1).max(0.0);
+                libm::powf(numerator / (pq::C2 - pq::C3 * p), 1.0 / pq::M1)
+            }
+            Self::Hlg => {
+                let value = value.clamp(0.0, 1.0);
+                if value <= 0.5 {
+                    value * value / 3.0
+                } else {
+                    (libm::expf((value - hlg::C) / hlg::A) + hlg::B) / 12.0
+                }
+            }
+        }
+    }
+
+    /// Converts a linear light value to its encoding.
+    ///
+    /// Values outside of `0.0..=1.0` are clamped, except for [`TransferFunction::Linear`].
+    #[must_use]
+    pub fn to_encoded(self, value: f32) -> f32 {
+        match self {
+            Self::Linear => value,
+            Self::Srgb => linear_to_srgb(value.clamp(0.0, 1.0)),
+            Self::Pq => {
+                let y = libm::powf(value.clamp(0.0, 1.0), pq::M1);
+                libm::powf((pq::C1 + pq::C2 * y) / (1.0 + pq::C3 * y), pq::M2)
+            }
+            Self::Hlg => {
+                let value = value.clamp(0.0, 1.0);
+                if value <= 1.0 / 12.0 {
+                    libm::sqrtf(3.0 * value)
+                } else {
+                    hlg::A * libm::logf(12.0 * value - hlg::B) + hlg::C
+                }
+            }
+        }
+    }
+}
+
+/// The linear light value of every 8-bit sRGB-encoded value.
+///
+/// Each entry is the exact curve evaluated in double precision, rounded to the nearest `f32`.
+#[allow(clippy::unreadable_literal)]
+#[rustfmt::skip]
+const SRGB_U8_TO_LINEAR: [f32; 256] = [
+    0.0, 0.000303527, 0.000607054, 0.000910581, 0.001214108, 0.001517635,
+    0.001821162, 0.0021246888, 0.002428216, 0.0027317428, 0.00303527, 0.0033465358,
+    0.0036765074, 0.004024717, 0.004391442, 0.0047769533, 0.0051815165, 0.0056053917,
+    0.006048833, 0.0065120906, 0.00699541, 0.007499032, 0.008023193, 0.008568126,
+    0.009134059, 0.009721218, 0.010329823, 0.010960094, 0.011612245, 0.012286488,
+    0.0129830325, 0.013702083, 0.014443844, 0.015208514, 0.015996294, 0.016807375,
+    0.017641954, 0.01850022, 0.019382361, 0.020288562, 0.02121901, 0.022173885,
+    0.023153367, 0.024157632, 0.02518686, 0.026241222, 0.027320892, 0.02842604,
+    0.029556835, 0.030713445, 0.031896032, 0.033104766, 0.034339808, 0.035601314,
+    0.03688945, 0.038204372, 0.039546236, 0.0409152, 0.04231141, 0.04373503,
+    0.045186203, 0.046665087, 0.048171826, 0.049706567, 0.051269457, 0.052860647,
+    0.054480277, 0.05612849, 0.05780543, 0.059511237, 0.061246052, 0.063010015,
+    0.064803265, 0.06662594, 0.06847817, 0.070360094, 0.07227185, 0.07421357,
+    0.07618538, 0.07818742, 0.08021982, 0.08228271, 0.08437621, 0.08650046,
+    0.08865558, 0.09084171, 0.093058966, 0.09530747, 0.09758735, 0.099898726,
+    0.10224173, 0.104616486, 0.107023105, 0.10946171, 0.11193243, 0.114435375,
+    0.116970666, 0.11953843, 0.122138776, 0.12477182, 0.12743768, 0.13013647,
+    0.13286832, 0.13563333, 0.13843161, 0.14126329, 0.14412847, 0.14702727,
+    0.14995979, 0.15292615, 0.15592647, 0.15896083, 0.16202937, 0.1651322,
+    0.1682694, 0.17144111, 0.1746474, 0.17788842, 0.18116425, 0.18447499,
+    0.18782078, 0.19120169, 0.19461784, 0.19806932, 0.20155625, 0.20507874,
+    0.20863687, 0.21223076, 0.2158605, 0.2195262, 0.22322796, 0.22696587,
+    0.23074006, 0.23455058, 0.23839757, 0.24228112, 0.24620132, 0.25015828,
+    0.2541521, 0.25818285, 0.26225066, 0.2663556, 0.2704978, 0.2746773,
+    0.27889428, 0.28314874, 0.28744084, 0.29177064, 0.29613826, 0.30054379,
+    0.3049873, 0.30946892, 0.31398872, 0.31854677, 0.3231432, 0.3277781,
+    0.33245152, 0.33716363, 0.34191442, 0.34670407, 0.3515326, 0.35640013,
+    0.3613068, 0.3662526, 0.3712377, 0.37626213, 0.38132602, 0.38642943,
+    0.39157248, 0.39675522, 0.40197778, 0.4072402, 0.4125426, 0.41788507,
+    0.42326766, 0.4286905, 0.43415365, 0.43965718, 0.4452012, 0.4507858,
+    0.45641103, 0.462077, 0.4677838, 0.47353148, 0.47932017, 0.48514995,
+    0.49102086, 0.49693298, 0.5028865, 0.50888133, 0.5149177, 0.52099556,
+    0.5271151, 0.5332764, 0.5394795, 0.54572445, 0.55201143, 0.5583404,
+    0.5647115, 0.57112485, 0.57758045, 0.58407843, 0.59061885, 0.59720176,
+    0.60382736, 0.61049557, 0.6172066, 0.6239604, 0.63075715, 0.63759685,
+    0.6444797, 0.65140563, 0.65837485, 0.6653873, 0.67244315, 0.6795425,
+    0.6866853, 0.69387174, 0.7011019, 0.70837575, 0.7156935, 0.7230551,
+    0.73046076, 0.7379104, 0.7454042, 0.7529422, 0.7605245, 0.76815116,
+    0.7758222, 0.7835378, 0.7912979, 0.7991027, 0.80695224, 0.8148466,
+    0.82278574, 0.8307699, 0.838799, 0.8468732, 0.8549926, 0.8631572,
+    0.8713671, 0.8796224, 0.8879231, 0.8962694, 0.9046612, 0.91309863,
+    0.92158186, 0.9301109, 0.9386857, 0.9473065, 0.9559733, 0.9646863,
+    0.9734453, 0.9822506, 0.9911021, 1.0,
+];
+
+/// Converts an 8-bit sRGB-encoded value to linear light, using a lookup table.
+///
+/// This is equivalent to `srgb_to_linear(f32::from(value) / 255.0)`, but faster, and more precise.
+#[must_use]
+pub const fn srgb_u8_to_linear(value: u8) -> f32 {
+    SRGB_U8_TO_LINEAR[value as usize]
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = u8>,
+{
+    /// Decodes the sRGB-encoded pixel to linear light.
+    ///
+    /// The alpha channel is already linear, and is only normalized.
+    #[must_use]
+    pub fn to_linear(&self) -> Pixel<FloatRgba> {
+        Pixel::with_rgba(
+            srgb_u8_to_linear(self.red()),
+            srgb_u8_to_linear(self.green()),
+            srgb_u8_to_linear(self.blue()),
+            self.alpha().to_f32(),
+        )
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = f32>,
+{
+    /// Encodes the linear light pixel with sRGB, rounding to the nearest 8-bit value.
+    ///
+    /// Channels outside of `0.0..=1.0` are clamped. The alpha channel is not encoded.
+    #[must_use]
+    pub fn to_srgb<T>(&self) -> Pixel<T>
+    where
+        T: RgbaFormat,
+        T::RawPixel: RawPixel<Channel = u8>,
+    {
+        let encode = |value: f32| u8::from_f32(linear_to_srgb(value.clamp(0.0, 1.0)));
+        Pixel::with_rgba(
+            encode(self.red()),
+            encode(self.green()),
+            encode(self.blue()),
+            u8::from_f32(self.alpha()),
+        )
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel: Normalized>,
+{
+    /// Decodes the pixel to linear light using the given transfer function.
+    ///
+    /// The alpha channel is already linear, and is only normalized.
+    #[must_use]
+    pub fn to_linear_with(&self, transfer: TransferFunction) -> Pixel<FloatRgba> {
+        let decode = |value: <F::RawPixel as RawPixel>::Channel| transfer.to_linear(value.to_f32());
+        Pixel::with_rgba(
+            decode(self.red()),
+            decode(self.green()),
+            decode(self.blue()),
+            self.alpha().to_f32(),
+        )
+    }
+}
+
+impl<F> Pixel<F>
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = f32>,
+{
+    /// Encodes the linear light pixel using the given transfer function.
+    ///
+    /// The alpha channel is not encoded.
+    #[must_use]
+    pub fn to_encoded_with<T>(&self, transfer: TransferFunction) -> Pixel<T>
+    where
+        T: RgbaFormat,
+        T::RawPixel: RawPixel<Channel: Normalized>,
+    {
+        let encode = |value: f32| Normalized::from_f32(transfer.to_encoded(value));
+        Pixel::with_rgba(
+            encode(self.red()),
+            encode(self.green()),
+            encode(self.blue()),
+            Normalized::from_f32(self.alpha()),
+        )
+    }
+}
+
+/// Decodes every sRGB-encoded pixel in `src` to linear light, as with [`Pixel::to_linear`].
+///
+/// ## Panics
+///
+/// If the slices have different lengths.
+pub fn to_linear_slice<F>(src: &[Pixel<F>], dst: &mut [Pixel<FloatRgba>])
+where
+    F: RgbaFormat,
+    F::RawPixel: RawPixel<Channel = u8>,
+{
+    assert_eq!(src.len(), dst.len(), "Slices must have the same length");
+    for (dst, src) in dst.iter_mut().zip(src) {
+        *dst = src.to_linear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formats::rgba::{Abgr8888, Rgba8888, Rgba16161616};
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn curve_endpoints() {
+        assert_eq!(srgb_to_linear(0.0), 0.0);
+        assert!((srgb_to_linear(1.0) - 1.0).abs() < 1e-6);
+        assert_eq!(linear_to_srgb(0.0), 0.0);
+        assert!((linear_to_srgb(1.0) - 1.0).abs() < 1e-6);
+
+        // The linear segment and the power segment meet at the threshold.
+        assert!((srgb_to_linear(0.040_45) - 0.003_130_8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn transfer_functions_round_trip() {
+        for transfer in [
+            TransferFunction::Linear,
+            TransferFunction::Srgb,
+            TransferFunction::Pq,
+            TransferFunction::Hlg,
+        ] {
+            for i in 0..=100 {
+                #[allow(clippy::cast_precision_loss)]
+                let value = i as f32 / 100.0;
+                let back = transfer.to_encoded(transfer.to_linear(value));
+                assert!((back - value).abs() < 1e-4, "{transfer:?} {value} {back}");
+            }
+        }
+    }
+
+    #[test]
+    fn pq_and_hlg_reference_points() {
+        // PQ encodes 100 cd/m² (SDR reference white) at about 0.508.
+        assert!((TransferFunction::Pq.to_encoded(0.01) - 0.508).abs() < 1e-3);
+        assert!((TransferFunction::Pq.to_linear(1.0) - 1.0).abs() < 1e-6);
+
+        // HLG switches from the square root to the log segment at 1/12, encoded as 0.5.
+        assert!((TransferFunction::Hlg.to_encoded(1.0 / 12.0) - 0.5).abs() < 1e-6);
+        assert!((TransferFunction::Hlg.to_linear(1.0) - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn u16_hlg_round_trip() {
+        let pixel = Pixel::<Rgba16161616>::with_rgba(0, 0x4000, 0xC000, 0x8000);
+        let linear = pixel.to_linear_with(TransferFunction::Hlg);
+        assert!((linear.green() - 0.0208).abs() < 1e-3);
+        assert_eq!(
+            linear.to_encoded_with::<Rgba16161616>(TransferFunction::Hlg),
+            pixel
+        );
+    }
+
+    #[test]
+    fn lookup_table_matches_curve() {
+        for value in 0..=u8::MAX {
+            let expected = srgb_to_linear(f32::from(value) / 255.0);
+            assert!(
+                (srgb_u8_to_linear(value) - expected).abs() < 1e-6,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn slice() {
+        let src = [Pixel::<Rgba8888>::with_rgba(0xFF, 0x80, 0x00, 0x80); 3];
+        let mut dst = [Pixel::<FloatRgba>::zeroed(); 3];
+        to_linear_slice(&src, &mut dst);
+        assert!(dst.iter().all(|pixel| *pixel == src[0].to_linear()));
+        assert_eq!(dst[2].red(), 1.0);
+    }
+
+    #[test]
+    fn u8_round_trip() {
+        for value in 0..=u8::MAX {
+            let pixel = Pixel::<Abgr8888>::with_rgba(value, value, value, value);
+            assert_eq!(pixel.to_linear().to_srgb::<Abgr8888>(), pixel);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn alpha_is_not_encoded() {
+        let pixel = Pixel::<Rgba8888>::with_rgba(0x80, 0x80, 0x80, 0x80);
+        let linear = pixel.to_linear();
+        assert!(linear.red() < 0.25);
+        assert_eq!(linear.alpha(), 128.0 / 255.0);
+
+        let srgb = Pixel::<FloatRgba>::with_rgba(2.0, -1.0, 0.5, 0.5).to_srgb::<Rgba8888>();
+        assert_eq!(srgb.as_raw().into_inner(), 0x80BC_00FF);
+    }
+}